@@ -0,0 +1,214 @@
+// Generated from word_hunt.udl by `word-hunter bindings kotlin`. Don't edit it by hand.
+// Calls the C API through JNA, so the library must be built with the capi feature.
+
+package moe.seikimo.wordhunt
+
+import com.sun.jna.Library
+import com.sun.jna.Memory
+import com.sun.jna.Native
+import com.sun.jna.NativeLong
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+
+/** The errors raised by the native library. */
+sealed class WordHuntException(message: String) : Exception(message) {
+    /** The board is empty, its rows aren't all the same length, or it is too large. */
+    class InvalidBoard : WordHuntException("The board is empty, its rows aren't all the same length, or it is too large.")
+
+    /** The words couldn't be added to the dictionary. */
+    class LoadFailed : WordHuntException("The words couldn't be added to the dictionary.")
+}
+
+/** A word found on a board. */
+data class FoundWord(
+    /** The word. */
+    val word: String,
+
+    /** The points the word is worth, with Word Hunt scoring. */
+    val score: UInt,
+)
+
+/** Statistics about a board. */
+data class Analysis(
+    /** The number of words on the board. */
+    val wordCount: ULong,
+
+    /** The points earned by finding every word, with Word Hunt scoring. */
+    val totalScore: UInt,
+
+    /** The difficulty of the board, from 0 (easiest) to 1 (hardest). */
+    val difficulty: Double,
+
+    /** The bucket the difficulty falls in: 0 for easy, 1 for medium, 2 for hard. */
+    val difficultyLevel: UByte,
+
+    /** The average length of the words, in letters. */
+    val averageLength: Double,
+
+    /** The share of playable tiles holding a rare letter, from 0 to 1. */
+    val rareLetters: Double,
+
+    /** The share of the total score earned by the best few words, from 0 to 1. */
+    val scoreConcentration: Double,
+)
+
+/** A dictionary of words, separate from every other dictionary. */
+class Dictionary : AutoCloseable {
+    private var handle: Pointer?
+
+    /** Creates an empty dictionary. */
+    constructor() {
+        handle = library.wh_dictionary_new()
+    }
+
+    /** Adds the words of a dictionary file, one per line. */
+    @Throws(WordHuntException::class)
+    fun load(path: String) {
+        if (library.wh_dictionary_load(pointer(), path) == FALSE) {
+            throw WordHuntException.LoadFailed()
+        }
+    }
+
+    /** Adds words to the dictionary. */
+    @Throws(WordHuntException::class)
+    fun loadWords(words: List<String>) {
+        if (library.wh_dictionary_load_contents(pointer(), words.joinToString("\n")) == FALSE) {
+            throw WordHuntException.LoadFailed()
+        }
+    }
+
+    /** Finds every word on a board, longest first. */
+    @Throws(WordHuntException::class)
+    fun solve(board: List<String>): List<FoundWord> {
+        val flat = flattenBoard(board) ?: throw WordHuntException.InvalidBoard()
+        val results = library.wh_solve(pointer(), flat.letters, flat.nativeRows, flat.nativeCols)
+            ?.let(::WhResults)
+            ?: throw WordHuntException.InvalidBoard()
+        try {
+            val count = results.count.toInt()
+            val words = results.words!!.getPointerArray(0, count)
+            val scores = results.scores!!.getIntArray(0, count)
+            return List(count) { index ->
+                FoundWord(
+                    word = words[index].getString(0, "UTF-8"),
+                    score = scores[index].toUInt(),
+                )
+            }
+        } finally {
+            library.wh_results_free(results.pointer)
+        }
+    }
+
+    /** Measures a board, such as how hard it is. */
+    @Throws(WordHuntException::class)
+    fun analyze(board: List<String>): Analysis {
+        val flat = flattenBoard(board) ?: throw WordHuntException.InvalidBoard()
+        val analysis = WhAnalysis()
+        if (library.wh_analyze(pointer(), flat.letters, flat.nativeRows, flat.nativeCols, analysis) == FALSE) {
+            throw WordHuntException.InvalidBoard()
+        }
+
+        return Analysis(
+            wordCount = analysis.word_count.toLong().toULong(),
+            totalScore = analysis.total_score.toUInt(),
+            difficulty = analysis.difficulty,
+            difficultyLevel = analysis.difficulty_level.toUByte(),
+            averageLength = analysis.average_length,
+            rareLetters = analysis.rare_letters,
+            scoreConcentration = analysis.score_concentration,
+        )
+    }
+
+    /** Counts how many words pass through each tile of a board, by row. */
+    @Throws(WordHuntException::class)
+    fun heatmap(board: List<String>): List<List<ULong>> {
+        val flat = flattenBoard(board) ?: throw WordHuntException.InvalidBoard()
+        val counts = Memory(Native.SIZE_T_SIZE.toLong() * flat.rows * flat.cols)
+        if (library.wh_heatmap(pointer(), flat.letters, flat.nativeRows, flat.nativeCols, counts) == FALSE) {
+            throw WordHuntException.InvalidBoard()
+        }
+
+        return List(flat.rows) { row ->
+            List(flat.cols) { col -> counts.getSize(row.toLong() * flat.cols + col) }
+        }
+    }
+
+    /** Frees the native dictionary. It can't be used afterwards. */
+    override fun close() {
+        handle?.let { library.wh_dictionary_free(it) }
+        handle = null
+    }
+
+    private fun pointer(): Pointer = checkNotNull(handle) { "The Dictionary was closed." }
+}
+
+/**
+ * The C API of the native library.
+ * `size_t` is passed as a `NativeLong`, which is as wide everywhere but 64-bit Windows.
+ */
+internal interface NativeLibrary : Library {
+    fun wh_dictionary_new(): Pointer
+    fun wh_dictionary_free(dictionary: Pointer)
+    fun wh_dictionary_load(dictionary: Pointer, path: String): Byte
+    fun wh_dictionary_load_contents(dictionary: Pointer, words: String): Byte
+    fun wh_solve(dictionary: Pointer, letters: String, rows: NativeLong, cols: NativeLong): Pointer?
+    fun wh_analyze(dictionary: Pointer, letters: String, rows: NativeLong, cols: NativeLong, analysis: WhAnalysis): Byte
+    fun wh_heatmap(dictionary: Pointer, letters: String, rows: NativeLong, cols: NativeLong, counts: Pointer): Byte
+    fun wh_results_free(results: Pointer)
+}
+
+/** The `false` returned by C functions which fail. */
+private const val FALSE: Byte = 0
+
+private val library: NativeLibrary = Native.load(
+    "word_hunt",
+    NativeLibrary::class.java,
+    mapOf(Library.OPTION_STRING_ENCODING to "UTF-8"),
+)
+
+/** The FoundWords returned by a C function, along with how many there are. */
+@Structure.FieldOrder("count", "words", "scores")
+internal class WhResults(pointer: Pointer) : Structure(pointer) {
+    @JvmField var count: NativeLong = NativeLong()
+    @JvmField var words: Pointer? = null
+    @JvmField var scores: Pointer? = null
+
+    init { read() }
+}
+
+/** The Analysis written by a C function. */
+@Structure.FieldOrder("word_count", "total_score", "difficulty", "difficulty_level", "average_length", "rare_letters", "score_concentration")
+internal class WhAnalysis : Structure() {
+    @JvmField var word_count: NativeLong = NativeLong()
+    @JvmField var total_score: Int = 0
+    @JvmField var difficulty: Double = 0.0
+    @JvmField var difficulty_level: Byte = 0
+    @JvmField var average_length: Double = 0.0
+    @JvmField var rare_letters: Double = 0.0
+    @JvmField var score_concentration: Double = 0.0
+}
+
+/** A board, flattened into the letters and size the C API takes. */
+private class FlatBoard(val letters: String, val rows: Int, val cols: Int) {
+    val nativeRows get() = NativeLong(rows.toLong())
+    val nativeCols get() = NativeLong(cols.toLong())
+}
+
+/**
+ * Flattens a board, given as rows of single-letter tiles.
+ * Returns null if the board is empty or its rows aren't all the same length.
+ * Tiles are counted as code points, as the C API counts them.
+ */
+private fun flattenBoard(board: List<String>): FlatBoard? {
+    val rows = board.map { it.lowercase() }
+    val cols = rows.firstOrNull()?.let { it.codePointCount(0, it.length) } ?: 0
+    if (cols == 0 || rows.any { it.codePointCount(0, it.length) != cols }) {
+        return null
+    }
+
+    return FlatBoard(rows.joinToString(""), rows.size, cols)
+}
+
+/** Reads the `size_t` at an index of an array. */
+private fun Pointer.getSize(index: Long): ULong =
+    if (Native.SIZE_T_SIZE == 8) getLong(index * 8).toULong() else getInt(index * 4).toUInt().toULong()
@@ -0,0 +1,150 @@
+// Generated from word_hunt.udl by `word-hunter bindings swift`. Don't edit it by hand.
+// Calls the C API through the WordHuntC module, so the library must be built with the capi feature.
+
+import WordHuntC
+
+/// The errors raised by the native library.
+public enum WordHuntError: Error {
+    /// The board is empty, its rows aren't all the same length, or it is too large.
+    case invalidBoard
+
+    /// The words couldn't be added to the dictionary.
+    case loadFailed
+}
+
+/// A word found on a board.
+public struct FoundWord {
+    /// The word.
+    public let word: String
+
+    /// The points the word is worth, with Word Hunt scoring.
+    public let score: UInt32
+}
+
+/// Statistics about a board.
+public struct Analysis {
+    /// The number of words on the board.
+    public let wordCount: UInt
+
+    /// The points earned by finding every word, with Word Hunt scoring.
+    public let totalScore: UInt32
+
+    /// The difficulty of the board, from 0 (easiest) to 1 (hardest).
+    public let difficulty: Double
+
+    /// The bucket the difficulty falls in: 0 for easy, 1 for medium, 2 for hard.
+    public let difficultyLevel: UInt8
+
+    /// The average length of the words, in letters.
+    public let averageLength: Double
+
+    /// The share of playable tiles holding a rare letter, from 0 to 1.
+    public let rareLetters: Double
+
+    /// The share of the total score earned by the best few words, from 0 to 1.
+    public let scoreConcentration: Double
+}
+
+/// A dictionary of words, separate from every other dictionary.
+public final class Dictionary {
+    private let handle: OpaquePointer
+
+    /// Creates an empty dictionary.
+    public init() {
+        handle = wh_dictionary_new()
+    }
+
+    deinit {
+        wh_dictionary_free(handle)
+    }
+
+    /// Adds the words of a dictionary file, one per line.
+    public func load(path: String) throws {
+        guard wh_dictionary_load(handle, path) else {
+            throw WordHuntError.loadFailed
+        }
+    }
+
+    /// Adds words to the dictionary.
+    public func loadWords(words: [String]) throws {
+        guard wh_dictionary_load_contents(handle, words.joined(separator: "\n")) else {
+            throw WordHuntError.loadFailed
+        }
+    }
+
+    /// Finds every word on a board, longest first.
+    public func solve(board: [String]) throws -> [FoundWord] {
+        guard let flat = flattenBoard(board) else {
+            throw WordHuntError.invalidBoard
+        }
+        guard let results = wh_solve(handle, flat.letters, flat.rows, flat.cols) else {
+            throw WordHuntError.invalidBoard
+        }
+        defer { wh_results_free(results) }
+
+        let found = results.pointee
+        return (0..<Int(found.count)).map { index in
+            FoundWord(
+                word: String(cString: found.words[index]!),
+                score: found.scores[index]
+            )
+        }
+    }
+
+    /// Measures a board, such as how hard it is.
+    public func analyze(board: [String]) throws -> Analysis {
+        guard let flat = flattenBoard(board) else {
+            throw WordHuntError.invalidBoard
+        }
+        var analysis = WhAnalysis()
+        guard wh_analyze(handle, flat.letters, flat.rows, flat.cols, &analysis) else {
+            throw WordHuntError.invalidBoard
+        }
+
+        return Analysis(
+            wordCount: analysis.word_count,
+            totalScore: analysis.total_score,
+            difficulty: analysis.difficulty,
+            difficultyLevel: analysis.difficulty_level,
+            averageLength: analysis.average_length,
+            rareLetters: analysis.rare_letters,
+            scoreConcentration: analysis.score_concentration
+        )
+    }
+
+    /// Counts how many words pass through each tile of a board, by row.
+    public func heatmap(board: [String]) throws -> [[UInt]] {
+        guard let flat = flattenBoard(board) else {
+            throw WordHuntError.invalidBoard
+        }
+        var counts = [UInt](repeating: 0, count: Int(flat.rows * flat.cols))
+        guard wh_heatmap(handle, flat.letters, flat.rows, flat.cols, &counts) else {
+            throw WordHuntError.invalidBoard
+        }
+
+        let cols = Int(flat.cols)
+        return (0..<Int(flat.rows)).map { row in
+            Array(counts[row * cols..<(row + 1) * cols])
+        }
+    }
+}
+
+/// A board, flattened into the letters and size the C API takes.
+private struct FlatBoard {
+    let letters: String
+    let rows: UInt
+    let cols: UInt
+}
+
+/// Flattens a board, given as rows of single-letter tiles.
+/// Returns nil if the board is empty or its rows aren't all the same length.
+/// Tiles are counted as Unicode scalars, as the C API counts them, rather than as Characters.
+private func flattenBoard(_ board: [String]) -> FlatBoard? {
+    let rows = board.map { $0.lowercased() }
+    let cols = rows.first?.unicodeScalars.count ?? 0
+    guard cols > 0, rows.allSatisfy({ $0.unicodeScalars.count == cols }) else {
+        return nil
+    }
+
+    return FlatBoard(letters: rows.joined(), rows: UInt(rows.count), cols: UInt(cols))
+}
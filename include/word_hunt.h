@@ -0,0 +1,140 @@
+#ifndef WORD_HUNT_H
+#define WORD_HUNT_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// A dictionary owned by C.
+// Only ever handled through a pointer, so C can't see its fields.
+typedef struct WhDictionary WhDictionary;
+
+// The words found on a board, longest first.
+// Must be freed with `wh_results_free`.
+typedef struct WhResults {
+  // The number of words.
+  uintptr_t count;
+  // The words, as null-terminated UTF-8 strings.
+  char **words;
+  // The score of each word, in the same order.
+  uint32_t *scores;
+} WhResults;
+
+// Statistics about a board.
+typedef struct WhAnalysis {
+  // The number of words on the board.
+  uintptr_t word_count;
+  // The points earned by finding every word, with Word Hunt scoring.
+  uint32_t total_score;
+  // The difficulty of the board, from 0 (easiest) to 1 (hardest).
+  double difficulty;
+  // The bucket the difficulty falls in: 0 for easy, 1 for medium, 2 for hard.
+  uint8_t difficulty_level;
+  // The average length of the words, in letters.
+  double average_length;
+  // The share of playable tiles holding a rare letter, from 0 to 1.
+  double rare_letters;
+  // The share of the total score earned by the best few words, from 0 to 1.
+  double score_concentration;
+} WhAnalysis;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Creates an empty dictionary.
+// Must be freed with `wh_dictionary_free`.
+struct WhDictionary *wh_dictionary_new(void);
+
+// Adds the words of a dictionary file to a dictionary.
+// Each line holds a word, optionally followed by how often it is used.
+// Returns whether the file could be read.
+// dictionary: The dictionary to add the words to.
+// path: The path to the dictionary file.
+//
+// # Safety
+// The dictionary must come from `wh_dictionary_new`, and the path must be a null-terminated string.
+bool wh_dictionary_load(struct WhDictionary *dictionary, const char *path);
+
+// Adds the words of a dictionary file which is already in memory to a dictionary.
+// Each line holds a word, optionally followed by how often it is used.
+// Returns whether the contents were valid UTF-8.
+// dictionary: The dictionary to add the words to.
+// contents: The contents of the dictionary file.
+//
+// # Safety
+// The dictionary must come from `wh_dictionary_new`, and the contents must be a null-terminated string.
+bool wh_dictionary_load_contents(struct WhDictionary *dictionary, const char *contents);
+
+// Frees a dictionary.
+// Results found with the dictionary stay valid.
+// dictionary: The dictionary, which may be null.
+//
+// # Safety
+// The dictionary must be null or come from `wh_dictionary_new`, and mustn't be used afterwards.
+void wh_dictionary_free(struct WhDictionary *dictionary);
+
+// Finds every word on a board of single-letter tiles, with Word Hunt scoring.
+// Returns null if the dictionary or letters are null, or the letters don't fill the board.
+// dictionary: The dictionary to resolve words from.
+// letters: The tiles of the board, row after row, such as "oetwiars".
+// rows: The number of rows.
+// cols: The number of columns.
+//
+// # Safety
+// The dictionary must be null or come from `wh_dictionary_new`, and the letters must be
+// null or a null-terminated string.
+struct WhResults *wh_solve(const struct WhDictionary *dictionary,
+                           const char *letters,
+                           uintptr_t rows,
+                           uintptr_t cols);
+
+// Measures a board, such as how hard it is.
+// Returns false, leaving the analysis unchanged, if the dictionary or letters are null,
+// or the letters don't fill the board.
+// dictionary: The dictionary to resolve words from.
+// letters: The tiles of the board, row after row, such as "oetwiars".
+// rows: The number of rows.
+// cols: The number of columns.
+// analysis: Where to write the statistics.
+//
+// # Safety
+// The dictionary must be null or come from `wh_dictionary_new`, the letters must be
+// null or a null-terminated string, and the analysis must be writable.
+bool wh_analyze(const struct WhDictionary *dictionary,
+                const char *letters,
+                uintptr_t rows,
+                uintptr_t cols,
+                struct WhAnalysis *analysis);
+
+// Counts how many words pass through each tile of a board.
+// Returns false, leaving the counts unchanged, if the dictionary or letters are null,
+// or the letters don't fill the board.
+// dictionary: The dictionary to resolve words from.
+// letters: The tiles of the board, row after row, such as "oetwiars".
+// rows: The number of rows.
+// cols: The number of columns.
+// counts: Where to write the count of each tile, row after row; `rows * cols` long.
+//
+// # Safety
+// The dictionary must be null or come from `wh_dictionary_new`, the letters must be
+// null or a null-terminated string, and the counts must have room for every tile.
+bool wh_heatmap(const struct WhDictionary *dictionary,
+                const char *letters,
+                uintptr_t rows,
+                uintptr_t cols,
+                uintptr_t *counts);
+
+// Frees the words found on a board.
+// results: The results, which may be null.
+//
+// # Safety
+// The results must be null or come from `wh_solve`, and mustn't be used afterwards.
+void wh_results_free(struct WhResults *results);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* WORD_HUNT_H */
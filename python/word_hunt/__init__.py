@@ -0,0 +1,159 @@
+"""Python bindings for the Word Hunt solver.
+
+Wraps the C API of the native library, which `pip install .` builds with the python
+feature and bundles into this package. The WORD_HUNT_LIBRARY environment variable can
+point to another build of the library instead.
+
+    import word_hunt
+    word_hunt.load_dictionary("words.txt")
+    word_hunt.solve(["oetw", "rsae", "tnrh", "iuob"])
+"""
+
+import ctypes
+import os
+import sys
+from ctypes import POINTER, Structure, c_bool, c_char_p, c_double, c_size_t, c_uint8, c_uint32, c_void_p
+
+__all__ = ["Dictionary", "load_dictionary", "solve", "analyze", "heatmap"]
+
+DIFFICULTY_LEVELS = ("easy", "medium", "hard")
+
+
+class _Results(Structure):
+    _fields_ = [
+        ("count", c_size_t),
+        ("words", POINTER(c_char_p)),
+        ("scores", POINTER(c_uint32)),
+    ]
+
+
+class _Analysis(Structure):
+    _fields_ = [
+        ("word_count", c_size_t),
+        ("total_score", c_uint32),
+        ("difficulty", c_double),
+        ("difficulty_level", c_uint8),
+        ("average_length", c_double),
+        ("rare_letters", c_double),
+        ("score_concentration", c_double),
+    ]
+
+
+def _library_path():
+    if "WORD_HUNT_LIBRARY" in os.environ:
+        return os.environ["WORD_HUNT_LIBRARY"]
+
+    name = {"win32": "word_hunt.dll", "darwin": "libword_hunt.dylib"}.get(sys.platform, "libword_hunt.so")
+    return os.path.join(os.path.dirname(__file__), name)
+
+
+_library = ctypes.CDLL(_library_path())
+_library.wh_dictionary_new.restype = c_void_p
+_library.wh_dictionary_load.argtypes = [c_void_p, c_char_p]
+_library.wh_dictionary_load.restype = c_bool
+_library.wh_dictionary_load_contents.argtypes = [c_void_p, c_char_p]
+_library.wh_dictionary_load_contents.restype = c_bool
+_library.wh_dictionary_free.argtypes = [c_void_p]
+_library.wh_solve.argtypes = [c_void_p, c_char_p, c_size_t, c_size_t]
+_library.wh_solve.restype = POINTER(_Results)
+_library.wh_analyze.argtypes = [c_void_p, c_char_p, c_size_t, c_size_t, POINTER(_Analysis)]
+_library.wh_analyze.restype = c_bool
+_library.wh_heatmap.argtypes = [c_void_p, c_char_p, c_size_t, c_size_t, POINTER(c_size_t)]
+_library.wh_heatmap.restype = c_bool
+_library.wh_results_free.argtypes = [POINTER(_Results)]
+
+
+def _flatten(board):
+    """Turns a board, given as rows of single-letter tiles, into its letters and size."""
+    rows = ["".join(row) for row in board]
+    cols = len(rows[0]) if rows else 0
+    if any(len(row) != cols for row in rows):
+        raise ValueError("Every row of the board must be the same length.")
+
+    return "".join(rows).lower().encode("utf-8"), len(rows), cols
+
+
+class Dictionary:
+    """A dictionary of words, separate from every other dictionary."""
+
+    def __init__(self, path=None):
+        self._dictionary = _library.wh_dictionary_new()
+        if path is not None:
+            self.load(path)
+
+    def __del__(self):
+        if getattr(self, "_dictionary", None):
+            _library.wh_dictionary_free(self._dictionary)
+            self._dictionary = None
+
+    def load(self, path):
+        """Adds the words of a dictionary file, one per line."""
+        if not _library.wh_dictionary_load(self._dictionary, os.fsencode(path)):
+            raise OSError(f"Couldn't read the dictionary file {path}.")
+
+    def load_words(self, words):
+        """Adds words to the dictionary."""
+        if not _library.wh_dictionary_load_contents(self._dictionary, "\n".join(words).encode("utf-8")):
+            raise ValueError("The words couldn't be added to the dictionary.")
+
+    def solve(self, board):
+        """Finds every word on a board, longest first, as (word, score) pairs."""
+        letters, rows, cols = _flatten(board)
+        results = _library.wh_solve(self._dictionary, letters, rows, cols)
+        if not results:
+            raise ValueError("The board isn't valid.")
+
+        try:
+            found = results.contents
+            return [(found.words[i].decode("utf-8"), found.scores[i]) for i in range(found.count)]
+        finally:
+            _library.wh_results_free(results)
+
+    def analyze(self, board):
+        """Measures a board, such as how hard it is."""
+        letters, rows, cols = _flatten(board)
+        analysis = _Analysis()
+        if not _library.wh_analyze(self._dictionary, letters, rows, cols, ctypes.byref(analysis)):
+            raise ValueError("The board isn't valid.")
+
+        return {
+            "word_count": analysis.word_count,
+            "total_score": analysis.total_score,
+            "difficulty": analysis.difficulty,
+            "difficulty_level": DIFFICULTY_LEVELS[analysis.difficulty_level],
+            "average_length": analysis.average_length,
+            "rare_letters": analysis.rare_letters,
+            "score_concentration": analysis.score_concentration,
+        }
+
+    def heatmap(self, board):
+        """Counts how many words pass through each tile of a board, by row."""
+        letters, rows, cols = _flatten(board)
+        counts = (c_size_t * (rows * cols))()
+        if not _library.wh_heatmap(self._dictionary, letters, rows, cols, counts):
+            raise ValueError("The board isn't valid.")
+
+        return [list(counts[row * cols:(row + 1) * cols]) for row in range(rows)]
+
+
+_default = Dictionary()
+
+
+def load_dictionary(path):
+    """Adds the words of a dictionary file to the default dictionary."""
+    _default.load(path)
+
+
+def solve(board):
+    """Finds every word on a board with the default dictionary, as (word, score) pairs."""
+    return _default.solve(board)
+
+
+def analyze(board):
+    """Measures a board with the default dictionary."""
+    return _default.analyze(board)
+
+
+def heatmap(board):
+    """Counts how many words pass through each tile of a board with the default dictionary."""
+    return _default.heatmap(board)
@@ -0,0 +1,39 @@
+"""Builds the native library with Cargo and bundles it into the word_hunt package.
+
+Everything else about the package is described by pyproject.toml.
+"""
+
+import os
+import subprocess
+import sys
+
+from setuptools import setup
+from setuptools.command.build_py import build_py
+from setuptools.dist import Distribution
+
+LIBRARY = {"win32": "word_hunt.dll", "darwin": "libword_hunt.dylib"}.get(sys.platform, "libword_hunt.so")
+
+
+class BuildWithLibrary(build_py):
+    """Builds the native library with the python feature, and copies it into the package."""
+
+    def run(self):
+        super().run()
+
+        root = os.path.dirname(os.path.abspath(__file__))
+        subprocess.run(["cargo", "build", "--release", "--lib", "--features", "python"], cwd=root, check=True)
+
+        target = os.environ.get("CARGO_TARGET_DIR", os.path.join(root, "target"))
+        package = os.path.join(self.build_lib, "word_hunt")
+        self.mkpath(package)
+        self.copy_file(os.path.join(target, "release", LIBRARY), os.path.join(package, LIBRARY))
+
+
+class NativeDistribution(Distribution):
+    """Marks wheels as specific to a platform, since they bundle a native library."""
+
+    def has_ext_modules(self):
+        return True
+
+
+setup(cmdclass={"build_py": BuildWithLibrary}, distclass=NativeDistribution)
@@ -0,0 +1,350 @@
+use crate::board::{Board, BLOCKED_CELL};
+use crate::options::SolveOptions;
+use crate::solver::Solver;
+
+/// A set of words whose paths cover a board.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Coverage {
+    /// The chosen words, along with the path used for each.
+    pub words: Vec<(String, Vec<usize>)>,
+
+    /// The playable tiles which no word could cover.
+    pub uncovered: Vec<usize>,
+}
+
+impl Coverage {
+    /// Checks if every playable tile is covered.
+    pub fn is_complete(&self) -> bool {
+        self.uncovered.is_empty()
+    }
+}
+
+/// The effect of replacing the letters of a single tile.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TileSwap {
+    /// The index of the tile.
+    pub index: usize,
+
+    /// The letters the tile would hold instead.
+    pub letters: String,
+
+    /// The total score of the board after the swap.
+    pub total_score: u32,
+
+    /// How much the total score of the board would change by.
+    pub score_change: i64,
+}
+
+/// Letters which make a board harder to play.
+const RARE_LETTERS: [char; 6] = ['j', 'k', 'q', 'v', 'x', 'z'];
+
+/// Boards with at least this many words are as easy as they get, by word count.
+const PLENTIFUL_WORDS: usize = 200;
+
+/// The number of best words used to measure how concentrated a board's score is.
+const TOP_WORDS: usize = 5;
+
+/// How hard a board is, bucketed for puzzle listings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DifficultyLevel {
+    Easy,
+    Medium,
+    Hard,
+}
+
+/// How hard a board is, along with the measurements behind the rating.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Difficulty {
+    /// The difficulty of the board, from 0 (easiest) to 1 (hardest).
+    pub rating: f64,
+
+    /// The bucket the rating falls in.
+    pub level: DifficultyLevel,
+
+    /// The number of words on the board.
+    pub word_count: usize,
+
+    /// The average length of the words, in letters.
+    pub average_length: f64,
+
+    /// The share of playable tiles holding a rare letter, from 0 to 1.
+    pub rare_letters: f64,
+
+    /// The share of the total score earned by the best few words, from 0 to 1.
+    pub score_concentration: f64,
+}
+
+impl Solver {
+    /// Counts how many words pass through each tile of a board.
+    /// A word counts once per tile, even if several of its paths use the tile.
+    /// board: The game board.
+    /// options: The options to solve with.
+    pub fn heatmap(&self, board: &Board, options: &SolveOptions) -> Vec<usize> {
+        let options = SolveOptions { all_paths: true, max_results: None, ..options.clone() };
+
+        let mut counts = vec![0; board.len()];
+        let mut used = vec![false; board.len()];
+        for found in self.find_word_paths(board, &options) {
+            used.fill(false);
+            for &index in found.paths.iter().flatten() {
+                used[index] = true;
+            }
+
+            for (count, &used) in counts.iter_mut().zip(&used) {
+                *count += used as usize;
+            }
+        }
+
+        counts
+    }
+
+    /// Estimates how hard a board is from its solve profile.
+    /// Boards with few, short words, many rare letters, and most of their points in a
+    /// handful of words are rated as harder.
+    /// board: The game board.
+    /// options: The options to solve with.
+    pub fn difficulty(&self, board: &Board, options: &SolveOptions) -> Difficulty {
+        let options = SolveOptions { max_results: None, ..options.clone() };
+        let words: Vec<(usize, u32)> = self.score_words(board, &options).words.iter()
+            .map(|found| (found.word.chars().count(), found.score))
+            .collect();
+
+        Self::rate_difficulty(board, &words)
+    }
+
+    /// Rates how hard a board is from the words already found on it, so a board
+    /// which was just solved doesn't have to be solved again.
+    /// board: The game board.
+    /// words: The length of every word on the board, in letters, along with its score.
+    pub(crate) fn rate_difficulty(board: &Board, words: &[(usize, u32)]) -> Difficulty {
+        let word_count = words.len();
+        let average_length = if word_count == 0 {
+            0.0
+        } else {
+            words.iter().map(|&(length, _)| length).sum::<usize>() as f64 / word_count as f64
+        };
+
+        let playable: Vec<usize> = (0..board.len())
+            .filter(|&index| !board.is_blocked(index))
+            .collect();
+        let rare_letters = if playable.is_empty() {
+            0.0
+        } else {
+            playable.iter()
+                .filter(|&&index| board.cell(index).chars().any(|letter| RARE_LETTERS.contains(&letter)))
+                .count() as f64 / playable.len() as f64
+        };
+
+        let mut scores: Vec<u32> = words.iter().map(|&(_, score)| score).collect();
+        scores.sort_unstable_by(|a, b| b.cmp(a));
+        let total_score = scores.iter().copied().fold(0, u32::saturating_add);
+        let score_concentration = if total_score == 0 {
+            1.0
+        } else {
+            let top = scores.iter().copied().take(TOP_WORDS).fold(0, u32::saturating_add);
+            f64::from(top) / f64::from(total_score)
+        };
+
+        let scarcity = 1.0 - word_count.min(PLENTIFUL_WORDS) as f64 / PLENTIFUL_WORDS as f64;
+        let shortness = 1.0 - ((average_length - 3.0) / 3.0).clamp(0.0, 1.0);
+        let rating = 0.4 * scarcity + 0.2 * shortness + 0.2 * rare_letters + 0.2 * score_concentration;
+
+        let level = if rating < 1.0 / 3.0 {
+            DifficultyLevel::Easy
+        } else if rating < 2.0 / 3.0 {
+            DifficultyLevel::Medium
+        } else {
+            DifficultyLevel::Hard
+        };
+
+        Difficulty { rating, level, word_count, average_length, rare_letters, score_concentration }
+    }
+
+    /// Finds a small set of words whose paths cover every playable tile of a board.
+    /// Words are picked greedily by how many uncovered tiles they add, which
+    /// keeps the set close to the smallest possible.
+    /// board: The game board.
+    /// options: The options to solve with.
+    pub fn cover_board(&self, board: &Board, options: &SolveOptions) -> Coverage {
+        let options = SolveOptions { all_paths: true, max_results: None, ..options.clone() };
+        let candidates: Vec<(String, Vec<usize>)> = self.find_word_paths(board, &options)
+            .into_iter()
+            .flat_map(|found| {
+                let word = found.word;
+                found.paths.into_iter().map(move |path| (word.clone(), path))
+            })
+            .collect();
+
+        let mut covered: Vec<bool> = (0..board.len())
+            .map(|index| board.is_blocked(index))
+            .collect();
+        let mut words = Vec::new();
+
+        loop {
+            // Pick the path which covers the most new tiles.
+            let best = candidates.iter()
+                .map(|(word, path)| {
+                    let mut new_tiles = path.clone();
+                    new_tiles.retain(|&index| !covered[index]);
+                    new_tiles.sort_unstable();
+                    new_tiles.dedup();
+                    (new_tiles.len(), word, path)
+                })
+                .filter(|&(count, _, _)| count > 0)
+                .max_by_key(|&(count, _, path)| (count, std::cmp::Reverse(path.len())));
+
+            let Some((_, word, path)) = best else {
+                break;
+            };
+
+            for &index in path {
+                covered[index] = true;
+            }
+            words.push((word.clone(), path.clone()));
+        }
+
+        let uncovered = (0..board.len())
+            .filter(|&index| !covered[index])
+            .collect();
+
+        Coverage { words, uncovered }
+    }
+
+    /// Works out how the total score of a board changes when any single tile is replaced.
+    /// Every playable tile is tried with every candidate that differs from its letters.
+    /// Swaps are returned from most to least helpful.
+    /// board: The game board.
+    /// options: The options to solve with.
+    /// candidates: The letters a tile may be replaced with.
+    pub fn tile_swaps(&self, board: &Board, options: &SolveOptions, candidates: &[&str]) -> Vec<TileSwap> {
+        let options = SolveOptions { max_results: None, ..options.clone() };
+        let base_score = self.score_words(board, &options).total_score;
+
+        let candidates: Vec<String> = candidates.iter()
+            .map(|letters| letters.trim().to_lowercase())
+            .filter(|letters| !letters.is_empty() && letters != BLOCKED_CELL)
+            .collect();
+
+        let mut swapped = board.clone();
+        let mut swaps = Vec::new();
+        for index in 0..board.len() {
+            if board.is_blocked(index) {
+                continue;
+            }
+
+            for letters in &candidates {
+                if letters == board.cell(index) {
+                    continue;
+                }
+
+                swapped.set_cell(index, letters.clone());
+                let total_score = self.score_words(&swapped, &options).total_score;
+                swaps.push(TileSwap {
+                    index,
+                    letters: letters.clone(),
+                    total_score,
+                    score_change: i64::from(total_score) - i64::from(base_score),
+                });
+            }
+
+            swapped.set_cell(index, board.cell(index).to_string());
+        }
+
+        swaps.sort_by(|a, b| b.score_change.cmp(&a.score_change)
+            .then(a.index.cmp(&b.index))
+            .then(a.letters.cmp(&b.letters)));
+        swaps
+    }
+
+    /// Finds the single tile swap which raises the total score of a board the most.
+    /// Returns nothing if no swap is possible.
+    /// board: The game board.
+    /// options: The options to solve with.
+    /// candidates: The letters a tile may be replaced with.
+    pub fn best_tile_swap(&self, board: &Board, options: &SolveOptions, candidates: &[&str]) -> Option<TileSwap> {
+        self.tile_swaps(board, options, candidates).into_iter().next()
+    }
+
+    /// Finds words whose paths use every playable tile of a board exactly once, like NYT Strands.
+    /// The tile with the fewest candidate paths is always filled first, which keeps the
+    /// exact cover search small. Returns nothing if the board can't be tiled.
+    /// board: The game board.
+    /// options: The options to solve with.
+    pub fn tile_board(&self, board: &Board, options: &SolveOptions) -> Option<Vec<(String, Vec<usize>)>> {
+        let options = SolveOptions { all_paths: true, allow_reuse: false, max_results: None, ..options.clone() };
+        let candidates: Vec<(String, Vec<usize>)> = self.find_word_paths(board, &options)
+            .into_iter()
+            .flat_map(|found| {
+                let word = found.word;
+                found.paths.into_iter().map(move |path| (word.clone(), path))
+            })
+            .collect();
+
+        // Index the candidate paths by the tiles they use.
+        let mut by_tile: Vec<Vec<usize>> = vec![Vec::new(); board.len()];
+        for (candidate, (_, path)) in candidates.iter().enumerate() {
+            for &index in path {
+                by_tile[index].push(candidate);
+            }
+        }
+
+        let mut covered: Vec<bool> = (0..board.len())
+            .map(|index| board.is_blocked(index))
+            .collect();
+        let mut chosen = Vec::new();
+
+        tile(&candidates, &by_tile, &mut covered, &mut chosen).then(|| chosen.into_iter()
+            .map(|candidate| candidates[candidate].clone())
+            .collect())
+    }
+}
+
+/// Covers the rest of a board with non-overlapping paths.
+/// Returns whether the board was covered, leaving the chosen paths in `chosen`.
+/// candidates: Every word path on the board.
+/// by_tile: The candidate paths using each tile.
+/// covered: The tiles which are already covered.
+/// chosen: The candidate paths chosen so far.
+fn tile(
+    candidates: &[(String, Vec<usize>)],
+    by_tile: &[Vec<usize>],
+    covered: &mut [bool],
+    chosen: &mut Vec<usize>
+) -> bool {
+    let fits = |candidate: &usize, covered: &[bool]| candidates[*candidate].1
+        .iter()
+        .all(|&index| !covered[index]);
+
+    // Fill the tile with the fewest ways to be covered first.
+    let tightest = (0..covered.len())
+        .filter(|&index| !covered[index])
+        .map(|index| (index, by_tile[index].iter().filter(|candidate| fits(candidate, covered)).count()))
+        .min_by_key(|&(_, count)| count);
+
+    let Some((index, count)) = tightest else {
+        return true;
+    };
+
+    if count == 0 {
+        return false;
+    }
+
+    for &candidate in &by_tile[index] {
+        if !fits(&candidate, covered) {
+            continue;
+        }
+
+        let path = &candidates[candidate].1;
+        path.iter().for_each(|&index| covered[index] = true);
+        chosen.push(candidate);
+
+        if tile(candidates, by_tile, covered, chosen) {
+            return true;
+        }
+
+        chosen.pop();
+        path.iter().for_each(|&index| covered[index] = false);
+    }
+
+    false
+}
\ No newline at end of file
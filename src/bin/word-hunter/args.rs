@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// The options and positional arguments given to a command.
+pub struct Args {
+    /// The arguments which aren't options, in order.
+    pub positional: Vec<String>,
+
+    options: HashMap<String, String>,
+}
+
+impl Args {
+    /// Parses the arguments of a command.
+    /// Options are given as `--name value` or `--name=value`, and switches as `--name`.
+    /// arguments: The arguments after the command.
+    /// options: The names of the options which take a value.
+    /// switches: The names of the options which don't.
+    pub fn parse(
+        arguments: impl IntoIterator<Item = String>,
+        options: &[&str],
+        switches: &[&str]
+    ) -> Result<Self, String> {
+        let mut args = Self { positional: Vec::new(), options: HashMap::new() };
+        let mut arguments = arguments.into_iter();
+
+        while let Some(argument) = arguments.next() {
+            let Some(option) = argument.strip_prefix("--") else {
+                args.positional.push(argument);
+                continue;
+            };
+
+            let (name, value) = match option.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_string())),
+                None => (option, None),
+            };
+
+            let value = if switches.contains(&name) {
+                if value.is_some() {
+                    return Err(format!("--{name} doesn't take a value."));
+                }
+                String::new()
+            } else if options.contains(&name) {
+                match value.or_else(|| arguments.next()) {
+                    Some(value) => value,
+                    None => return Err(format!("--{name} needs a value.")),
+                }
+            } else {
+                return Err(format!("Unknown option --{name}."));
+            };
+
+            args.options.insert(name.to_string(), value);
+        }
+
+        Ok(args)
+    }
+
+    /// Returns the value of an option, if it was given.
+    /// name: The name of the option, without the dashes.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.options.get(name).map(String::as_str)
+    }
+
+    /// Returns whether a switch was given.
+    /// name: The name of the switch, without the dashes.
+    pub fn is_set(&self, name: &str) -> bool {
+        self.options.contains_key(name)
+    }
+
+    /// Parses the value of an option, if it was given.
+    /// name: The name of the option, without the dashes.
+    pub fn parse_value<T: FromStr>(&self, name: &str) -> Result<Option<T>, String> {
+        self.get(name)
+            .map(|value| value.parse().map_err(|_| format!("Invalid value {value:?} for --{name}.")))
+            .transpose()
+    }
+}
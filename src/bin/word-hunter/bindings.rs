@@ -0,0 +1,604 @@
+mod kotlin;
+mod swift;
+
+use std::path::Path;
+use crate::args::Args;
+
+/// Generates the Kotlin or Swift bindings of an interface definition.
+/// arguments: The arguments after the command.
+pub fn run(mut arguments: impl Iterator<Item = String>) -> Result<(), String> {
+    let generate = match arguments.next().as_deref() {
+        Some("kotlin") => kotlin::generate,
+        Some("swift") => swift::generate,
+        Some(language) => return Err(format!("Unknown language {language}; expected kotlin or swift.")),
+        None => return Err("Missing a language: kotlin or swift.".to_string()),
+    };
+    let args = Args::parse(arguments, &[], &[])?;
+    let [input, output] = args.positional.as_slice() else {
+        return Err("Usage: word-hunter bindings <kotlin|swift> <interface.udl> <output>".to_string());
+    };
+
+    let source = std::fs::read_to_string(input).map_err(|error| format!("Couldn't read {input}: {error}"))?;
+    let interface = Interface::parse(&source).map_err(|error| format!("{input}: {error}"))?;
+    let source_name = Path::new(input).file_name().map_or(input.clone(), |name| name.to_string_lossy().into_owned());
+
+    std::fs::write(output, generate(&interface, &source_name)).map_err(|error| format!("Couldn't write {output}: {error}"))?;
+    println!("Generated {output} from {input}.");
+    Ok(())
+}
+
+/// An interface definition, read from a subset of UniFFI's UDL.
+pub struct Interface {
+    /// The errors methods raise.
+    pub error: ErrorEnum,
+
+    /// The records passed by value.
+    pub records: Vec<Record>,
+
+    /// The objects held by a handle.
+    pub objects: Vec<Object>,
+}
+
+/// The errors methods raise, declared as an `[Error] enum`.
+pub struct ErrorEnum {
+    pub docs: Vec<String>,
+    pub name: String,
+    pub variants: Vec<Variant>,
+}
+
+/// A kind of error.
+pub struct Variant {
+    pub docs: Vec<String>,
+    pub name: String,
+}
+
+/// A record passed by value, declared as a `dictionary`.
+pub struct Record {
+    pub docs: Vec<String>,
+    pub name: String,
+    pub fields: Vec<Field>,
+}
+
+/// A field of a record, or an argument of a method.
+pub struct Field {
+    pub docs: Vec<String>,
+    pub name: String,
+    pub kind: Type,
+}
+
+/// An object held by a handle, declared as an `interface`.
+pub struct Object {
+    pub docs: Vec<String>,
+    pub name: String,
+
+    /// The docs of the constructor, which takes no arguments.
+    pub constructor: Vec<String>,
+
+    pub methods: Vec<Method>,
+}
+
+/// A method of an object.
+pub struct Method {
+    pub docs: Vec<String>,
+    pub name: String,
+    pub args: Vec<Field>,
+    pub returns: Type,
+
+    /// How the C function returns its result.
+    pub convention: Convention,
+
+    /// The C function the method calls.
+    pub symbol: String,
+
+    /// The error variant raised when the C function fails.
+    pub throws: String,
+}
+
+/// The types of the interface.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Type {
+    Void,
+    String,
+    U8,
+    U32,
+    Usize,
+    F64,
+
+    /// A board, given as its rows with one letter for each tile.
+    /// C takes it as the letters of every row, then the number of rows and columns.
+    Board,
+
+    Sequence(Box<Type>),
+    Record(String),
+}
+
+/// How a C function returns its result, decided by the method's return type.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Convention {
+    /// Returns whether it succeeded, for methods which return nothing.
+    Status,
+
+    /// Returns a `WhResults` pointer, or null if it failed, for methods which return a
+    /// sequence of records. The results hold an array for each field of the record, named
+    /// after the field with an "s", and are freed with `wh_results_free`.
+    Results(String),
+
+    /// Writes the record into a `Wh<record>` given by pointer, returning whether it succeeded.
+    OutRecord(String),
+
+    /// Writes a count for each tile of the board into an array given by pointer, returning
+    /// whether it succeeded, for methods which return a `sequence<sequence<usize>>`.
+    TileCounts,
+}
+
+impl Interface {
+    /// Parses an interface definition.
+    /// source: The contents of the UDL file.
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let mut parser = Parser { tokens: tokenize(source)?, position: 0 };
+        let mut error = None;
+        let mut records = Vec::new();
+        let mut objects = Vec::new();
+
+        while parser.peek().is_some() {
+            let docs = parser.docs();
+            let attributes = parser.attributes()?;
+            match parser.word()?.as_str() {
+                "namespace" => {
+                    parser.word()?;
+                    parser.expect('{')?;
+                    parser.expect('}')?;
+                }
+                "enum" if attributes.iter().any(|(name, _)| name == "Error") => {
+                    error = Some(parser.error_enum(docs)?);
+                }
+                "enum" => return parser.fail("Only [Error] enums are supported."),
+                "dictionary" => records.push(parser.record(docs)?),
+                "interface" => objects.push(parser.object(docs)?),
+                keyword => return parser.fail(format!("Unexpected {keyword}.")),
+            }
+            parser.expect(';')?;
+        }
+
+        let error = error.ok_or("The interface doesn't declare an [Error] enum.")?;
+        let interface = Self { error, records, objects };
+        interface.check()?;
+        Ok(interface)
+    }
+
+    /// Finds a record by name.
+    /// name: The name of the record, which was checked to exist when parsing.
+    pub fn record(&self, name: &str) -> &Record {
+        self.records.iter()
+            .find(|record| record.name == name)
+            .expect("Records are checked to exist when parsing.")
+    }
+
+    /// Finds the record C returns as `WhResults`, if any method returns a sequence of records.
+    pub fn results_record(&self) -> Option<&Record> {
+        self.methods().find_map(|method| match &method.convention {
+            Convention::Results(record) => Some(self.record(record)),
+            _ => None,
+        })
+    }
+
+    /// Finds every record C writes through a pointer, once each.
+    pub fn out_records(&self) -> Vec<&Record> {
+        let mut records: Vec<&Record> = Vec::new();
+        for method in self.methods() {
+            if let Convention::OutRecord(name) = &method.convention {
+                if !records.iter().any(|record| record.name == *name) {
+                    records.push(self.record(name));
+                }
+            }
+        }
+
+        records
+    }
+
+    /// Returns every method of every object.
+    pub fn methods(&self) -> impl Iterator<Item = &Method> {
+        self.objects.iter().flat_map(|object| &object.methods)
+    }
+
+    /// Checks that C has a way to pass every type the interface uses.
+    fn check(&self) -> Result<(), String> {
+        for record in &self.records {
+            for field in &record.fields {
+                if !matches!(field.kind, Type::String | Type::U8 | Type::U32 | Type::Usize | Type::F64) {
+                    return Err(format!("{}.{} must be a string or a number.", record.name, field.name));
+                }
+            }
+        }
+
+        let mut results = None;
+        for method in self.methods() {
+            if !self.error.variants.iter().any(|variant| variant.name == method.throws) {
+                return Err(format!("{} throws {}, which {} doesn't declare.", method.name, method.throws, self.error.name));
+            }
+
+            let boards = method.args.iter().filter(|arg| arg.kind == Type::Board).count();
+            for arg in &method.args {
+                let supported = match &arg.kind {
+                    Type::String | Type::Board => true,
+                    Type::Sequence(inner) => **inner == Type::String,
+                    _ => false,
+                };
+                if !supported {
+                    return Err(format!("{}'s {} must be a string, a sequence of strings or a Board.", method.name, arg.name));
+                }
+            }
+            if boards > 1 {
+                return Err(format!("{} takes more than one Board.", method.name));
+            }
+
+            match &method.convention {
+                Convention::Results(name) => {
+                    if !self.records.iter().any(|record| record.name == *name) {
+                        return Err(format!("{} returns {name}, which isn't declared.", method.name));
+                    }
+                    if results.is_some_and(|results| results != name) {
+                        return Err(format!("{} returns a sequence of another record than WhResults holds.", method.name));
+                    }
+                    if !self.record(name).fields.iter().all(|field| matches!(field.kind, Type::String | Type::U32)) {
+                        return Err(format!("The fields of {name} must be strings or u32s to be returned in a sequence."));
+                    }
+                    results = Some(name);
+                }
+                Convention::OutRecord(name) => {
+                    if !self.records.iter().any(|record| record.name == *name) {
+                        return Err(format!("{} returns {name}, which isn't declared.", method.name));
+                    }
+                    if self.record(name).fields.iter().any(|field| field.kind == Type::String) {
+                        return Err(format!("The fields of {name} must be numbers to be returned on their own."));
+                    }
+                }
+                Convention::TileCounts if boards == 0 => {
+                    return Err(format!("{} counts tiles without taking a Board.", method.name));
+                }
+                Convention::Status | Convention::TileCounts => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Convention {
+    /// Decides how a C function returns a type.
+    /// returns: The return type of the method.
+    fn of(returns: &Type) -> Option<Self> {
+        match returns {
+            Type::Void => Some(Convention::Status),
+            Type::Record(name) => Some(Convention::OutRecord(name.clone())),
+            Type::Sequence(inner) => match inner.as_ref() {
+                Type::Record(name) => Some(Convention::Results(name.clone())),
+                Type::Sequence(inner) if **inner == Type::Usize => Some(Convention::TileCounts),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// A token of an interface definition.
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    /// A line of a doc comment, without the slashes.
+    Doc(String),
+
+    /// A keyword, name or type.
+    Word(String),
+
+    /// A quoted string, without the quotes.
+    Text(String),
+
+    /// A punctuation character.
+    Symbol(char),
+}
+
+/// Splits an interface definition into tokens, each along with its line number.
+/// Comments are dropped, other than doc comments.
+/// source: The contents of the UDL file.
+fn tokenize(source: &str) -> Result<Vec<(Token, usize)>, String> {
+    let mut tokens = Vec::new();
+    for (number, line) in source.lines().enumerate() {
+        let number = number + 1;
+        if let Some(doc) = line.trim().strip_prefix("///") {
+            tokens.push((Token::Doc(doc.trim().to_string()), number));
+            continue;
+        }
+
+        let code = line.split("//").next().unwrap_or_default();
+        let mut chars = code.chars().peekable();
+        while let Some(ch) = chars.next() {
+            let token = match ch {
+                ch if ch.is_whitespace() => continue,
+                '"' => {
+                    let mut text = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('"') => break,
+                            Some(ch) => text.push(ch),
+                            None => return Err(format!("line {number}: A string isn't terminated.")),
+                        }
+                    }
+                    Token::Text(text)
+                }
+                ch if ch.is_ascii_alphanumeric() || ch == '_' => {
+                    let mut word = ch.to_string();
+                    while let Some(ch) = chars.next_if(|ch| ch.is_ascii_alphanumeric() || *ch == '_') {
+                        word.push(ch);
+                    }
+                    Token::Word(word)
+                }
+                '{' | '}' | '(' | ')' | '[' | ']' | '<' | '>' | ';' | ',' | '=' => Token::Symbol(ch),
+                ch => return Err(format!("line {number}: Unexpected '{ch}'.")),
+            };
+            tokens.push((token, number));
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Reads the tokens of an interface definition.
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|(token, _)| token)
+    }
+
+    fn fail<T>(&self, message: impl std::fmt::Display) -> Result<T, String> {
+        let line = self.tokens.get(self.position).or(self.tokens.last()).map_or(0, |&(_, line)| line);
+        Err(format!("line {line}: {message}"))
+    }
+
+    fn eat(&mut self, symbol: char) -> bool {
+        let found = self.peek() == Some(&Token::Symbol(symbol));
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    fn expect(&mut self, symbol: char) -> Result<(), String> {
+        if self.eat(symbol) {
+            Ok(())
+        } else {
+            self.fail(format!("Expected '{symbol}'."))
+        }
+    }
+
+    fn word(&mut self) -> Result<String, String> {
+        let Some(Token::Word(word)) = self.peek() else {
+            return self.fail("Expected a name.");
+        };
+
+        let word = word.clone();
+        self.position += 1;
+        Ok(word)
+    }
+
+    fn docs(&mut self) -> Vec<String> {
+        let mut docs = Vec::new();
+        while let Some(Token::Doc(line)) = self.peek() {
+            docs.push(line.clone());
+            self.position += 1;
+        }
+        docs
+    }
+
+    /// Reads the attributes in square brackets before a declaration, if there are any.
+    fn attributes(&mut self) -> Result<Vec<(String, Option<String>)>, String> {
+        let mut attributes = Vec::new();
+        if !self.eat('[') {
+            return Ok(attributes);
+        }
+
+        loop {
+            let name = self.word()?;
+            let value = if self.eat('=') { Some(self.word()?) } else { None };
+            attributes.push((name, value));
+            if self.eat(']') {
+                return Ok(attributes);
+            }
+            self.expect(',')?;
+        }
+    }
+
+    fn kind(&mut self) -> Result<Type, String> {
+        let name = self.word()?;
+        Ok(match name.as_str() {
+            "void" => Type::Void,
+            "string" => Type::String,
+            "u8" => Type::U8,
+            "u32" => Type::U32,
+            "usize" => Type::Usize,
+            "f64" => Type::F64,
+            "Board" => Type::Board,
+            "sequence" => {
+                self.expect('<')?;
+                let inner = self.kind()?;
+                self.expect('>')?;
+                Type::Sequence(Box::new(inner))
+            }
+            _ => Type::Record(name),
+        })
+    }
+
+    fn error_enum(&mut self, docs: Vec<String>) -> Result<ErrorEnum, String> {
+        let name = self.word()?;
+        let mut variants = Vec::new();
+        self.expect('{')?;
+        while !self.eat('}') {
+            let docs = self.docs();
+            let Some(Token::Text(variant)) = self.peek() else {
+                return self.fail("Expected a quoted variant.");
+            };
+
+            variants.push(Variant { docs, name: variant.clone() });
+            self.position += 1;
+            if !self.eat(',') {
+                self.expect('}')?;
+                break;
+            }
+        }
+
+        Ok(ErrorEnum { docs, name, variants })
+    }
+
+    fn record(&mut self, docs: Vec<String>) -> Result<Record, String> {
+        let name = self.word()?;
+        let mut fields = Vec::new();
+        self.expect('{')?;
+        while !self.eat('}') {
+            let docs = self.docs();
+            let kind = self.kind()?;
+            fields.push(Field { docs, name: self.word()?, kind });
+            self.expect(';')?;
+        }
+
+        Ok(Record { docs, name, fields })
+    }
+
+    fn object(&mut self, docs: Vec<String>) -> Result<Object, String> {
+        let name = self.word()?;
+        let mut constructor = None;
+        let mut methods = Vec::new();
+        self.expect('{')?;
+        while !self.eat('}') {
+            let docs = self.docs();
+            let attributes = self.attributes()?;
+            if self.peek() == Some(&Token::Word("constructor".to_string())) {
+                self.position += 1;
+                self.expect('(')?;
+                self.expect(')')?;
+                self.expect(';')?;
+                constructor = Some(docs);
+                continue;
+            }
+
+            let returns = self.kind()?;
+            let method = self.word()?;
+            let Some(convention) = Convention::of(&returns) else {
+                return self.fail(format!("C can't return {returns:?} from {method}."));
+            };
+
+            let mut args = Vec::new();
+            self.expect('(')?;
+            while !self.eat(')') {
+                let kind = self.kind()?;
+                args.push(Field { docs: Vec::new(), name: self.word()?, kind });
+                if !self.eat(',') {
+                    self.expect(')')?;
+                    break;
+                }
+            }
+            self.expect(';')?;
+
+            let attribute = |wanted: &str| attributes.iter()
+                .find(|(name, _)| name == wanted)
+                .and_then(|(_, value)| value.clone());
+            let Some(throws) = attribute("Throws") else {
+                return self.fail(format!("{method} doesn't say what it throws."));
+            };
+            let symbol = attribute("Symbol").unwrap_or_else(|| format!("wh_{}_{method}", snake_case(&name)));
+
+            methods.push(Method { docs, name: method, args, returns, convention, symbol, throws });
+        }
+
+        let Some(constructor) = constructor else {
+            return self.fail(format!("{name} doesn't have a constructor."));
+        };
+
+        Ok(Object { docs, name, constructor, methods })
+    }
+}
+
+/// Generated source code, built a line at a time.
+pub struct Code {
+    text: String,
+    indent: usize,
+}
+
+impl Code {
+    pub fn new() -> Self {
+        Self { text: String::new(), indent: 0 }
+    }
+
+    /// Adds a line at the current indentation.
+    /// line: The line, which isn't indented if it is empty.
+    pub fn line(&mut self, line: impl AsRef<str>) {
+        let line = line.as_ref().trim_end();
+        if !line.is_empty() {
+            self.text.push_str(&"    ".repeat(self.indent));
+            self.text.push_str(line);
+        }
+        self.text.push('\n');
+    }
+
+    /// Adds a line, then indents the lines after it.
+    /// line: The line opening a block.
+    pub fn open(&mut self, line: impl AsRef<str>) {
+        self.line(line);
+        self.indent += 1;
+    }
+
+    /// Unindents, then adds a line.
+    /// line: The line closing a block.
+    pub fn close(&mut self, line: impl AsRef<str>) {
+        self.indent -= 1;
+        self.line(line);
+    }
+
+    /// Unindents for a line which closes one block and opens another, such as `} else {`.
+    /// line: The line.
+    pub fn reopen(&mut self, line: impl AsRef<str>) {
+        self.close(line);
+        self.indent += 1;
+    }
+
+    pub fn finish(self) -> String {
+        self.text
+    }
+}
+
+/// Converts a name from snake case to camel case, such as "load_words" to "loadWords".
+/// name: The name.
+pub fn camel_case(name: &str) -> String {
+    let mut words = name.split('_');
+    let first = words.next().unwrap_or_default().to_string();
+    words.fold(first, |mut camel, word| {
+        let mut chars = word.chars();
+        if let Some(ch) = chars.next() {
+            camel.extend(ch.to_uppercase());
+            camel.push_str(chars.as_str());
+        }
+        camel
+    })
+}
+
+/// Converts a name from Pascal case to snake case, such as "FoundWord" to "found_word".
+/// name: The name.
+pub fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (index, ch) in name.chars().enumerate() {
+        if ch.is_uppercase() && index > 0 {
+            snake.push('_');
+        }
+        snake.extend(ch.to_lowercase());
+    }
+    snake
+}
+
+/// Lowercases the first letter of a name, such as "InvalidBoard" to "invalidBoard".
+/// name: The name.
+pub fn lower_first(name: &str) -> String {
+    let mut chars = name.chars();
+    chars.next().map_or(String::new(), |ch| ch.to_lowercase().chain(chars).collect())
+}
@@ -0,0 +1,355 @@
+use super::{camel_case, lower_first, snake_case, Code, Convention, Interface, Method, Object, Record, Type};
+
+/// The package the Kotlin bindings are generated in.
+const PACKAGE: &str = "moe.seikimo.wordhunt";
+
+/// Generates the Kotlin bindings, which call the C API through JNA.
+/// interface: The interface definition.
+/// source: The name of the file the interface was read from.
+pub fn generate(interface: &Interface, source: &str) -> String {
+    let mut code = Code::new();
+    code.line(format!("// Generated from {source} by `word-hunter bindings kotlin`. Don't edit it by hand."));
+    code.line("// Calls the C API through JNA, so the library must be built with the capi feature.");
+    code.line("");
+    code.line(format!("package {PACKAGE}"));
+    code.line("");
+    for import in ["Library", "Memory", "Native", "NativeLong", "Pointer", "Structure"] {
+        code.line(format!("import com.sun.jna.{import}"));
+    }
+
+    error_class(&mut code, interface);
+    for record in &interface.records {
+        record_class(&mut code, record);
+    }
+    for object in &interface.objects {
+        object_class(&mut code, interface, object);
+    }
+    native_library(&mut code, interface);
+    helpers(&mut code, interface);
+    code.finish()
+}
+
+fn error_class(code: &mut Code, interface: &Interface) {
+    let name = exception_name(&interface.error.name);
+    code.line("");
+    docs(code, &interface.error.docs);
+    code.open(format!("sealed class {name}(message: String) : Exception(message) {{"));
+    for (index, variant) in interface.error.variants.iter().enumerate() {
+        if index > 0 {
+            code.line("");
+        }
+        docs(code, &variant.docs);
+        let message = string(&variant.docs.join(" "));
+        code.line(format!("class {} : {name}({message})", variant.name));
+    }
+    code.close("}");
+}
+
+fn record_class(code: &mut Code, record: &Record) {
+    code.line("");
+    docs(code, &record.docs);
+    code.open(format!("data class {}(", record.name));
+    for (index, field) in record.fields.iter().enumerate() {
+        if index > 0 {
+            code.line("");
+        }
+        docs(code, &field.docs);
+        code.line(format!("val {}: {},", camel_case(&field.name), kotlin_type(&field.kind)));
+    }
+    code.close(")");
+}
+
+fn object_class(code: &mut Code, interface: &Interface, object: &Object) {
+    let snake = snake_case(&object.name);
+    code.line("");
+    docs(code, &object.docs);
+    code.open(format!("class {} : AutoCloseable {{", object.name));
+    code.line("private var handle: Pointer?");
+    code.line("");
+    docs(code, &object.constructor);
+    code.open("constructor() {");
+    code.line(format!("handle = library.wh_{snake}_new()"));
+    code.close("}");
+
+    for method in &object.methods {
+        code.line("");
+        method_body(code, interface, method);
+    }
+
+    code.line("");
+    code.line(format!("/** Frees the native {snake}. It can't be used afterwards. */"));
+    code.open("override fun close() {");
+    code.line(format!("handle?.let {{ library.wh_{snake}_free(it) }}"));
+    code.line("handle = null");
+    code.close("}");
+    code.line("");
+    code.line(format!("private fun pointer(): Pointer = checkNotNull(handle) {{ \"The {} was closed.\" }}", object.name));
+    code.close("}");
+}
+
+fn method_body(code: &mut Code, interface: &Interface, method: &Method) {
+    let error = format!("{}.{}()", exception_name(&interface.error.name), method.throws);
+    let params = method.args.iter()
+        .map(|arg| format!("{}: {}", camel_case(&arg.name), kotlin_type(&arg.kind)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let returns = match method.returns {
+        Type::Void => String::new(),
+        ref returns => format!(": {}", kotlin_type(returns)),
+    };
+
+    docs(code, &method.docs);
+    code.line(format!("@Throws({}::class)", exception_name(&interface.error.name)));
+    code.open(format!("fun {}({params}){returns} {{", camel_case(&method.name)));
+
+    let mut args = vec!["pointer()".to_string()];
+    for arg in &method.args {
+        let name = camel_case(&arg.name);
+        match arg.kind {
+            Type::Board => {
+                code.line(format!("val flat = flattenBoard({name}) ?: throw {error}"));
+                args.extend(["flat.letters", "flat.nativeRows", "flat.nativeCols"].map(String::from));
+            }
+            Type::Sequence(_) => args.push(format!("{name}.joinToString(\"\\n\")")),
+            _ => args.push(name),
+        }
+    }
+
+    let symbol = &method.symbol;
+    match &method.convention {
+        Convention::Status => {
+            code.open(format!("if (library.{symbol}({}) == FALSE) {{", args.join(", ")));
+            code.line(format!("throw {error}"));
+            code.close("}");
+        }
+        Convention::Results(name) => {
+            let record = interface.record(name);
+            code.line(format!("val results = library.{symbol}({})", args.join(", ")));
+            code.line("    ?.let(::WhResults)");
+            code.line(format!("    ?: throw {error}"));
+            code.open("try {");
+            code.line("val count = results.count.toInt()");
+            for field in &record.fields {
+                let array = format!("{}s", camel_case(&field.name));
+                let read = if field.kind == Type::String { "getPointerArray" } else { "getIntArray" };
+                code.line(format!("val {array} = results.{}s!!.{read}(0, count)", field.name));
+            }
+            code.open("return List(count) { index ->");
+            code.open(format!("{}(", record.name));
+            for field in &record.fields {
+                let element = format!("{}s[index]", camel_case(&field.name));
+                let value = if field.kind == Type::String {
+                    format!("{element}.getString(0, \"UTF-8\")")
+                } else {
+                    lift(&element, &field.kind)
+                };
+                code.line(format!("{} = {value},", camel_case(&field.name)));
+            }
+            code.close(")");
+            code.close("}");
+            code.reopen("} finally {");
+            code.line("library.wh_results_free(results.pointer)");
+            code.close("}");
+        }
+        Convention::OutRecord(name) => {
+            let record = interface.record(name);
+            let out = lower_first(name);
+            args.push(out.clone());
+            code.line(format!("val {out} = Wh{name}()"));
+            code.open(format!("if (library.{symbol}({}) == FALSE) {{", args.join(", ")));
+            code.line(format!("throw {error}"));
+            code.close("}");
+            code.line("");
+            code.open(format!("return {}(", record.name));
+            for field in &record.fields {
+                let value = lift(&format!("{out}.{}", field.name), &field.kind);
+                code.line(format!("{} = {value},", camel_case(&field.name)));
+            }
+            code.close(")");
+        }
+        Convention::TileCounts => {
+            args.push("counts".to_string());
+            code.line("val counts = Memory(Native.SIZE_T_SIZE.toLong() * flat.rows * flat.cols)");
+            code.open(format!("if (library.{symbol}({}) == FALSE) {{", args.join(", ")));
+            code.line(format!("throw {error}"));
+            code.close("}");
+            code.line("");
+            code.open("return List(flat.rows) { row ->");
+            code.line("List(flat.cols) { col -> counts.getSize(row.toLong() * flat.cols + col) }");
+            code.close("}");
+        }
+    }
+
+    code.close("}");
+}
+
+/// Declares the C functions the bindings call, and the structs they take.
+fn native_library(code: &mut Code, interface: &Interface) {
+    code.line("");
+    code.line("/**");
+    code.line(" * The C API of the native library.");
+    code.line(" * `size_t` is passed as a `NativeLong`, which is as wide everywhere but 64-bit Windows.");
+    code.line(" */");
+    code.open("internal interface NativeLibrary : Library {");
+    for object in &interface.objects {
+        let snake = snake_case(&object.name);
+        code.line(format!("fun wh_{snake}_new(): Pointer"));
+        code.line(format!("fun wh_{snake}_free({}: Pointer)", camel_case(&snake)));
+
+        for method in &object.methods {
+            let mut params = vec![format!("{}: Pointer", camel_case(&snake))];
+            for arg in &method.args {
+                match arg.kind {
+                    Type::Board => params.extend(["letters: String", "rows: NativeLong", "cols: NativeLong"].map(String::from)),
+                    _ => params.push(format!("{}: String", camel_case(&arg.name))),
+                }
+            }
+
+            let returns = match &method.convention {
+                Convention::Results(_) => "Pointer?",
+                Convention::OutRecord(name) => {
+                    params.push(format!("{}: Wh{name}", lower_first(name)));
+                    "Byte"
+                }
+                Convention::TileCounts => {
+                    params.push("counts: Pointer".to_string());
+                    "Byte"
+                }
+                Convention::Status => "Byte",
+            };
+            code.line(format!("fun {}({}): {returns}", method.symbol, params.join(", ")));
+        }
+    }
+    if interface.results_record().is_some() {
+        code.line("fun wh_results_free(results: Pointer)");
+    }
+    code.close("}");
+
+    code.line("");
+    code.line("/** The `false` returned by C functions which fail. */");
+    code.line("private const val FALSE: Byte = 0");
+    code.line("");
+    code.open("private val library: NativeLibrary = Native.load(");
+    code.line("\"word_hunt\",");
+    code.line("NativeLibrary::class.java,");
+    code.line("mapOf(Library.OPTION_STRING_ENCODING to \"UTF-8\"),");
+    code.close(")");
+
+    if let Some(record) = interface.results_record() {
+        code.line("");
+        code.line(format!("/** The {}s returned by a C function, along with how many there are. */", record.name));
+        let fields = record.fields.iter().map(|field| format!("\"{}s\"", field.name)).collect::<Vec<_>>();
+        code.line(format!("@Structure.FieldOrder(\"count\", {})", fields.join(", ")));
+        code.open("internal class WhResults(pointer: Pointer) : Structure(pointer) {");
+        code.line("@JvmField var count: NativeLong = NativeLong()");
+        for field in &record.fields {
+            code.line(format!("@JvmField var {}s: Pointer? = null", field.name));
+        }
+        code.line("");
+        code.line("init { read() }");
+        code.close("}");
+    }
+
+    for record in interface.out_records() {
+        code.line("");
+        code.line(format!("/** The {} written by a C function. */", record.name));
+        let fields = record.fields.iter().map(|field| format!("\"{}\"", field.name)).collect::<Vec<_>>();
+        code.line(format!("@Structure.FieldOrder({})", fields.join(", ")));
+        code.open(format!("internal class Wh{} : Structure() {{", record.name));
+        for field in &record.fields {
+            let (kind, default) = match field.kind {
+                Type::Usize => ("NativeLong", "NativeLong()"),
+                Type::U32 => ("Int", "0"),
+                Type::U8 => ("Byte", "0"),
+                _ => ("Double", "0.0"),
+            };
+            code.line(format!("@JvmField var {}: {kind} = {default}", field.name));
+        }
+        code.close("}");
+    }
+}
+
+/// Adds the functions the methods share, if any method needs them.
+fn helpers(code: &mut Code, interface: &Interface) {
+    if interface.methods().any(|method| method.args.iter().any(|arg| arg.kind == Type::Board)) {
+        code.line("");
+        code.line("/** A board, flattened into the letters and size the C API takes. */");
+        code.open("private class FlatBoard(val letters: String, val rows: Int, val cols: Int) {");
+        code.line("val nativeRows get() = NativeLong(rows.toLong())");
+        code.line("val nativeCols get() = NativeLong(cols.toLong())");
+        code.close("}");
+        code.line("");
+        code.line("/**");
+        code.line(" * Flattens a board, given as rows of single-letter tiles.");
+        code.line(" * Returns null if the board is empty or its rows aren't all the same length.");
+        code.line(" * Tiles are counted as code points, as the C API counts them.");
+        code.line(" */");
+        code.open("private fun flattenBoard(board: List<String>): FlatBoard? {");
+        code.line("val rows = board.map { it.lowercase() }");
+        code.line("val cols = rows.firstOrNull()?.let { it.codePointCount(0, it.length) } ?: 0");
+        code.open("if (cols == 0 || rows.any { it.codePointCount(0, it.length) != cols }) {");
+        code.line("return null");
+        code.close("}");
+        code.line("");
+        code.line("return FlatBoard(rows.joinToString(\"\"), rows.size, cols)");
+        code.close("}");
+    }
+
+    if interface.methods().any(|method| method.convention == Convention::TileCounts) {
+        code.line("");
+        code.line("/** Reads the `size_t` at an index of an array. */");
+        code.line("private fun Pointer.getSize(index: Long): ULong =");
+        code.line("    if (Native.SIZE_T_SIZE == 8) getLong(index * 8).toULong() else getInt(index * 4).toUInt().toULong()");
+    }
+}
+
+/// Converts a value C passes to the Kotlin type of the interface.
+/// value: The value, as JNA gives it.
+/// kind: The type of the value.
+fn lift(value: &str, kind: &Type) -> String {
+    match kind {
+        Type::Usize => format!("{value}.toLong().toULong()"),
+        Type::U32 => format!("{value}.toUInt()"),
+        Type::U8 => format!("{value}.toUByte()"),
+        _ => value.to_string(),
+    }
+}
+
+fn kotlin_type(kind: &Type) -> String {
+    match kind {
+        Type::Void => "Unit".to_string(),
+        Type::String => "String".to_string(),
+        Type::U8 => "UByte".to_string(),
+        Type::U32 => "UInt".to_string(),
+        Type::Usize => "ULong".to_string(),
+        Type::F64 => "Double".to_string(),
+        Type::Board => "List<String>".to_string(),
+        Type::Sequence(inner) => format!("List<{}>", kotlin_type(inner)),
+        Type::Record(name) => name.clone(),
+    }
+}
+
+/// Names the exception class after the error enum, such as "WordHuntError" to "WordHuntException".
+fn exception_name(error: &str) -> String {
+    format!("{}Exception", error.strip_suffix("Error").unwrap_or(error))
+}
+
+/// Quotes text as a Kotlin string.
+fn string(text: &str) -> String {
+    let escaped = text.replace('\\', "\\\\").replace('"', "\\\"").replace('$', "\\$");
+    format!("\"{escaped}\"")
+}
+
+fn docs(code: &mut Code, docs: &[String]) {
+    match docs {
+        [] => {}
+        [line] => code.line(format!("/** {line} */")),
+        lines => {
+            code.line("/**");
+            for line in lines {
+                code.line(format!(" * {line}"));
+            }
+            code.line(" */");
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use super::{camel_case, lower_first, snake_case, Code, Convention, Interface, Method, Object, Record, Type};
+
+/// Generates the Swift bindings, which call the C API through the `WordHuntC` module.
+/// interface: The interface definition.
+/// source: The name of the file the interface was read from.
+pub fn generate(interface: &Interface, source: &str) -> String {
+    let mut code = Code::new();
+    code.line(format!("// Generated from {source} by `word-hunter bindings swift`. Don't edit it by hand."));
+    code.line("// Calls the C API through the WordHuntC module, so the library must be built with the capi feature.");
+    code.line("");
+    code.line("import WordHuntC");
+
+    error_enum(&mut code, interface);
+    for record in &interface.records {
+        record_struct(&mut code, record);
+    }
+    for object in &interface.objects {
+        object_class(&mut code, interface, object);
+    }
+    helpers(&mut code, interface);
+    code.finish()
+}
+
+fn error_enum(code: &mut Code, interface: &Interface) {
+    code.line("");
+    docs(code, &interface.error.docs);
+    code.open(format!("public enum {}: Error {{", interface.error.name));
+    for (index, variant) in interface.error.variants.iter().enumerate() {
+        if index > 0 {
+            code.line("");
+        }
+        docs(code, &variant.docs);
+        code.line(format!("case {}", lower_first(&variant.name)));
+    }
+    code.close("}");
+}
+
+fn record_struct(code: &mut Code, record: &Record) {
+    code.line("");
+    docs(code, &record.docs);
+    code.open(format!("public struct {} {{", record.name));
+    for (index, field) in record.fields.iter().enumerate() {
+        if index > 0 {
+            code.line("");
+        }
+        docs(code, &field.docs);
+        code.line(format!("public let {}: {}", camel_case(&field.name), swift_type(&field.kind)));
+    }
+    code.close("}");
+}
+
+fn object_class(code: &mut Code, interface: &Interface, object: &Object) {
+    let snake = snake_case(&object.name);
+    code.line("");
+    docs(code, &object.docs);
+    code.open(format!("public final class {} {{", object.name));
+    code.line("private let handle: OpaquePointer");
+    code.line("");
+    docs(code, &object.constructor);
+    code.open("public init() {");
+    code.line(format!("handle = wh_{snake}_new()"));
+    code.close("}");
+    code.line("");
+    code.open("deinit {");
+    code.line(format!("wh_{snake}_free(handle)"));
+    code.close("}");
+
+    for method in &object.methods {
+        code.line("");
+        method_body(code, interface, method);
+    }
+    code.close("}");
+}
+
+fn method_body(code: &mut Code, interface: &Interface, method: &Method) {
+    let error = format!("{}.{}", interface.error.name, lower_first(&method.throws));
+    let params = method.args.iter()
+        .map(|arg| format!("{}: {}", camel_case(&arg.name), swift_type(&arg.kind)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let returns = match method.returns {
+        Type::Void => String::new(),
+        ref returns => format!(" -> {}", swift_type(returns)),
+    };
+
+    docs(code, &method.docs);
+    code.open(format!("public func {}({params}) throws{returns} {{", camel_case(&method.name)));
+
+    let mut args = vec!["handle".to_string()];
+    for arg in &method.args {
+        let name = camel_case(&arg.name);
+        match arg.kind {
+            Type::Board => {
+                code.open(format!("guard let flat = flattenBoard({name}) else {{"));
+                code.line(format!("throw {error}"));
+                code.close("}");
+                args.extend(["flat.letters", "flat.rows", "flat.cols"].map(String::from));
+            }
+            Type::Sequence(_) => args.push(format!("{name}.joined(separator: \"\\n\")")),
+            _ => args.push(name),
+        }
+    }
+
+    let symbol = &method.symbol;
+    match &method.convention {
+        Convention::Status => {
+            code.open(format!("guard {symbol}({}) else {{", args.join(", ")));
+            code.line(format!("throw {error}"));
+            code.close("}");
+        }
+        Convention::Results(name) => {
+            let record = interface.record(name);
+            code.open(format!("guard let results = {symbol}({}) else {{", args.join(", ")));
+            code.line(format!("throw {error}"));
+            code.close("}");
+            code.line("defer { wh_results_free(results) }");
+            code.line("");
+            code.line("let found = results.pointee");
+            code.open("return (0..<Int(found.count)).map { index in");
+            code.open(format!("{}(", record.name));
+            for (index, field) in record.fields.iter().enumerate() {
+                let element = format!("found.{}s[index]", field.name);
+                let value = if field.kind == Type::String { format!("String(cString: {element}!)") } else { element };
+                let separator = if index + 1 < record.fields.len() { "," } else { "" };
+                code.line(format!("{}: {value}{separator}", camel_case(&field.name)));
+            }
+            code.close(")");
+            code.close("}");
+        }
+        Convention::OutRecord(name) => {
+            let record = interface.record(name);
+            let out = lower_first(name);
+            args.push(format!("&{out}"));
+            code.line(format!("var {out} = Wh{name}()"));
+            code.open(format!("guard {symbol}({}) else {{", args.join(", ")));
+            code.line(format!("throw {error}"));
+            code.close("}");
+            code.line("");
+            code.open(format!("return {}(", record.name));
+            for (index, field) in record.fields.iter().enumerate() {
+                let separator = if index + 1 < record.fields.len() { "," } else { "" };
+                code.line(format!("{}: {out}.{}{separator}", camel_case(&field.name), field.name));
+            }
+            code.close(")");
+        }
+        Convention::TileCounts => {
+            args.push("&counts".to_string());
+            code.line("var counts = [UInt](repeating: 0, count: Int(flat.rows * flat.cols))");
+            code.open(format!("guard {symbol}({}) else {{", args.join(", ")));
+            code.line(format!("throw {error}"));
+            code.close("}");
+            code.line("");
+            code.line("let cols = Int(flat.cols)");
+            code.open("return (0..<Int(flat.rows)).map { row in");
+            code.line("Array(counts[row * cols..<(row + 1) * cols])");
+            code.close("}");
+        }
+    }
+
+    code.close("}");
+}
+
+/// Adds the functions the methods share, if any method needs them.
+fn helpers(code: &mut Code, interface: &Interface) {
+    if !interface.methods().any(|method| method.args.iter().any(|arg| arg.kind == Type::Board)) {
+        return;
+    }
+
+    code.line("");
+    code.line("/// A board, flattened into the letters and size the C API takes.");
+    code.open("private struct FlatBoard {");
+    code.line("let letters: String");
+    code.line("let rows: UInt");
+    code.line("let cols: UInt");
+    code.close("}");
+    code.line("");
+    code.line("/// Flattens a board, given as rows of single-letter tiles.");
+    code.line("/// Returns nil if the board is empty or its rows aren't all the same length.");
+    code.line("/// Tiles are counted as Unicode scalars, as the C API counts them, rather than as Characters.");
+    code.open("private func flattenBoard(_ board: [String]) -> FlatBoard? {");
+    code.line("let rows = board.map { $0.lowercased() }");
+    code.line("let cols = rows.first?.unicodeScalars.count ?? 0");
+    code.open("guard cols > 0, rows.allSatisfy({ $0.unicodeScalars.count == cols }) else {");
+    code.line("return nil");
+    code.close("}");
+    code.line("");
+    code.line("return FlatBoard(letters: rows.joined(), rows: UInt(rows.count), cols: UInt(cols))");
+    code.close("}");
+}
+
+fn swift_type(kind: &Type) -> String {
+    match kind {
+        Type::Void => "Void".to_string(),
+        Type::String => "String".to_string(),
+        Type::U8 => "UInt8".to_string(),
+        Type::U32 => "UInt32".to_string(),
+        Type::Usize => "UInt".to_string(),
+        Type::F64 => "Double".to_string(),
+        Type::Board => "[String]".to_string(),
+        Type::Sequence(inner) => format!("[{}]", swift_type(inner)),
+        Type::Record(name) => name.clone(),
+    }
+}
+
+fn docs(code: &mut Code, docs: &[String]) {
+    for line in docs {
+        code.line(format!("/// {line}"));
+    }
+}
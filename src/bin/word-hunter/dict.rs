@@ -0,0 +1,88 @@
+use std::collections::BTreeSet;
+use word_hunt::{clear_dictionary, compile_dictionary, dictionary_info, dictionary_words};
+use crate::args::Args;
+use crate::load_dictionary;
+
+/// Compiles, describes or compares dictionaries.
+/// arguments: The arguments after the command.
+pub fn run(mut arguments: impl Iterator<Item = String>) -> Result<(), String> {
+    let subcommand = arguments.next();
+    let args = Args::parse(arguments, &[], &["summary"])?;
+
+    match (subcommand.as_deref(), args.positional.as_slice()) {
+        (Some("compile"), [input, output]) => compile(input, output),
+        (Some("info"), [path]) => info(path),
+        (Some("diff"), [old, new]) => diff(old, new, args.is_set("summary")),
+        (Some("compile"), _) => Err("Usage: word-hunter dict compile <words.txt> <words.bin>".to_string()),
+        (Some("info"), _) => Err("Usage: word-hunter dict info <path>".to_string()),
+        (Some("diff"), _) => Err("Usage: word-hunter dict diff <old> <new>".to_string()),
+        (Some(subcommand), _) => Err(format!("Unknown dict command {subcommand}.")),
+        (None, _) => Err("Missing a dict command: compile, info or diff.".to_string()),
+    }
+}
+
+/// Compiles a dictionary file.
+/// input: The path to the dictionary file.
+/// output: The path to write the compiled dictionary to.
+fn compile(input: &str, output: &str) -> Result<(), String> {
+    let contents = std::fs::read_to_string(input)
+        .map_err(|error| format!("Couldn't read the dictionary {input}: {error}"))?;
+
+    let bytes = compile_dictionary(&contents);
+    std::fs::write(output, &bytes).map_err(|error| format!("Couldn't write {output}: {error}"))?;
+
+    println!("Compiled {input} into {output} ({} bytes).", bytes.len());
+    Ok(())
+}
+
+/// Describes a dictionary file or compiled dictionary.
+/// path: The path to the dictionary.
+fn info(path: &str) -> Result<(), String> {
+    let compiled = load_dictionary(Some(path))?;
+    let info = dictionary_info();
+    let size = std::fs::metadata(path).map_err(|error| error.to_string())?.len();
+    let longest = dictionary_words()
+        .into_iter()
+        .max_by_key(|word| word.chars().count())
+        .unwrap_or_default();
+
+    println!("Path:         {path}");
+    println!("Format:       {}", if compiled { "compiled" } else { "text" });
+    println!("Size:         {size} bytes");
+    println!("Words:        {}", info.word_count);
+    println!("Frequencies:  {}", if info.has_frequencies { "yes" } else { "no" });
+    println!("Longest word: {longest}");
+    Ok(())
+}
+
+/// Lists the words added and removed between two dictionaries.
+/// Removed words are prefixed with "-" and added words with "+".
+/// old: The path to the old dictionary.
+/// new: The path to the new dictionary.
+/// summary: Whether to only count the changes.
+fn diff(old: &str, new: &str, summary: bool) -> Result<(), String> {
+    let old = read_words(old)?;
+    let new = read_words(new)?;
+
+    let removed: Vec<&String> = old.difference(&new).collect();
+    let added: Vec<&String> = new.difference(&old).collect();
+    if !summary {
+        for word in &removed {
+            println!("- {word}");
+        }
+        for word in &added {
+            println!("+ {word}");
+        }
+    }
+
+    println!("{} removed, {} added, {} unchanged.", removed.len(), added.len(), old.len() - removed.len());
+    Ok(())
+}
+
+/// Reads every word of a dictionary, on its own.
+/// path: The path to the dictionary.
+fn read_words(path: &str) -> Result<BTreeSet<String>, String> {
+    clear_dictionary();
+    load_dictionary(Some(path))?;
+    Ok(dictionary_words().into_iter().collect())
+}
@@ -0,0 +1,94 @@
+use word_hunt::{BoardTargets, DiceSet, RandomRng, Rng, SeededRng, SolveOptions, Solver};
+use crate::args::Args;
+use crate::solve::to_text;
+use crate::load_dictionary;
+
+/// The most rows or columns a generated board may have.
+const MAX_SIDE: usize = 64;
+
+/// Generates a board which meets a set of targets.
+/// arguments: The arguments after the command.
+pub fn run(arguments: impl Iterator<Item = String>) -> Result<(), String> {
+    let args = Args::parse(
+        arguments,
+        &["dict", "rows", "cols", "seed", "min-words", "min-longest", "attempts", "min-len", "scoring", "sort"],
+        &["solution", "stats"]
+    )?;
+
+    load_dictionary(args.get("dict"))?;
+
+    let rows = args.parse_value("rows")?.unwrap_or(4);
+    let cols = args.parse_value("cols")?.unwrap_or(4);
+    let dice = dice_for(rows, cols)?;
+
+    // A random seed is still printed, so the board can be generated again.
+    let seed = args.parse_value("seed")?.unwrap_or_else(|| RandomRng::new().next_u64());
+
+    let targets = BoardTargets {
+        min_words: args.parse_value("min-words")?.unwrap_or(0),
+        min_longest: args.parse_value("min-longest")?.unwrap_or(0),
+        attempts: args.parse_value("attempts")?.unwrap_or(BoardTargets::default().attempts),
+        ..Default::default()
+    };
+
+    let options = SolveOptions {
+        min_length: args.parse_value("min-len")?.unwrap_or(3),
+        scoring: args.parse_value("scoring")?.unwrap_or_default(),
+        order: args.parse_value("sort")?.unwrap_or_default(),
+        ..Default::default()
+    };
+
+    let solver = Solver::new();
+    let board = solver.generate_with_targets(&dice, &targets, &options, &mut SeededRng::new(seed))
+        .ok_or_else(|| format!("No board met the targets in {} attempts.", targets.attempts))?;
+
+    println!("Seed: {seed}");
+    for row in 0..board.rows() {
+        let cells: Vec<&str> = (0..board.cols())
+            .map(|col| board.cell(row * board.cols() + col))
+            .collect();
+        println!("{}", cells.join(" "));
+    }
+
+    if args.is_set("stats") {
+        let scored = solver.score_words(&board, &options);
+        let difficulty = solver.difficulty(&board, &options);
+        let longest = scored.words.iter()
+            .map(|found| found.word.as_str())
+            .max_by_key(|word| word.chars().count())
+            .unwrap_or_default();
+
+        println!();
+        println!("Words:       {}", scored.words.len());
+        println!("Total score: {}", scored.total_score);
+        println!("Longest:     {longest}");
+        println!("Difficulty:  {:?} ({:.2})", difficulty.level, difficulty.rating);
+    }
+
+    if args.is_set("solution") {
+        println!();
+        print!("{}", to_text(&solver.find_matches(&board, &options)));
+    }
+
+    Ok(())
+}
+
+/// Picks the dice to roll for a board size.
+/// 4x4 boards use the classic dice and 5x5 boards the Big Boggle dice.
+/// Other sizes reuse the Big Boggle dice as many times as needed.
+/// rows: The number of rows of the board.
+/// cols: The number of columns of the board.
+fn dice_for(rows: usize, cols: usize) -> Result<DiceSet, String> {
+    if rows == 0 || cols == 0 || rows > MAX_SIDE || cols > MAX_SIDE {
+        return Err(format!("Boards must have between 1 and {MAX_SIDE} rows and columns."));
+    }
+
+    match (rows, cols) {
+        (4, 4) => Ok(DiceSet::classic()),
+        (5, 5) => Ok(DiceSet::big()),
+        (rows, cols) => {
+            let dice = DiceSet::big().dice.into_iter().cycle().take(rows * cols).collect();
+            DiceSet::new(dice, rows, cols).map_err(|error| error.to_string())
+        }
+    }
+}
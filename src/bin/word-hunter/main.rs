@@ -0,0 +1,118 @@
+mod args;
+mod bindings;
+mod dict;
+mod generate;
+mod repl;
+mod rpc;
+#[cfg(feature = "server")]
+mod serve;
+mod solve;
+
+use std::process::ExitCode;
+use word_hunt::{load_compiled_dictionary_bytes, load_dictionary_contents, Board, Topology};
+
+/// The dictionary loaded when `--dict` isn't given, unless `WORD_HUNTER_DICT` is set.
+const DEFAULT_DICTIONARY: &str = "words.txt";
+
+const USAGE: &str = "\
+Usage: word-hunter <command> [options]
+
+Commands:
+  solve     Finds every word on a board
+            --board oetw,iars,ytop,rwbs  The rows of the board
+            --dict <path>                A dictionary file, or a compiled dictionary
+            --min-len <letters>          The shortest words to find (default 3)
+            --max <count>                The most words to list
+            --scoring <mode>             wordhunt, boggle or scrabble (default wordhunt)
+            --topology <shape>           square, hex or knight (default square)
+            --sort <order>               length, score or rarity (default length)
+            --format <format>            text or json (default text)
+  repl      Keeps a dictionary loaded and answers queries interactively
+            --dict <path>                A dictionary file, or a compiled dictionary
+  dict compile <words.txt> <words.bin>
+            Compiles a dictionary file, so it loads faster
+  dict info <path>
+            Describes a dictionary file or compiled dictionary
+  dict diff <old> <new>
+            Lists the words added and removed between two dictionaries
+            --summary                    Only counts the changes
+  generate  Generates a board which meets a set of targets
+            --rows <rows>                The number of rows (default 4)
+            --cols <cols>                The number of columns (default 4)
+            --seed <seed>                The seed, such as the number of a daily puzzle
+            --min-words <count>          The fewest words the board may have
+            --min-longest <letters>      The length the longest word must reach
+            --attempts <count>           How many boards to roll before giving up (default 100)
+            --dict, --min-len, --scoring, --sort
+                                         As for solve
+            --solution                   Also lists every word on the board
+            --stats                      Also prints the word count, score and difficulty
+  rpc       Answers newline-delimited JSON-RPC requests on stdin: load, solve, validate and stats
+            --dict <path>                A dictionary to load before the first request
+  serve     Serves the solver over HTTP, when built with the server feature
+            --address <address>          The address to listen on (default 127.0.0.1:8080)
+            --dict <path>                A dictionary file, or a compiled dictionary
+  bindings <kotlin|swift> <interface.udl> <output>
+            Generates the Kotlin or Swift bindings of the C API from an interface definition
+  help      Shows this message
+";
+
+fn main() -> ExitCode {
+    let mut arguments = std::env::args().skip(1);
+    let result = match arguments.next().as_deref() {
+        Some("solve") => solve::run(arguments),
+        Some("repl") => repl::run(arguments),
+        Some("dict") => dict::run(arguments),
+        Some("generate") => generate::run(arguments),
+        Some("rpc") => rpc::run(arguments),
+        Some("bindings") => bindings::run(arguments),
+        #[cfg(feature = "server")]
+        Some("serve") => serve::run(arguments),
+        Some("help" | "--help" | "-h") | None => {
+            print!("{USAGE}");
+            Ok(())
+        }
+        Some(command) => Err(format!("Unknown command {command}.")),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            eprintln!("Run `word-hunter help` for usage.");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Loads a dictionary file, or a compiled dictionary.
+/// Returns whether the dictionary was compiled.
+/// path: The path to the dictionary, or `None` for the default.
+fn load_dictionary(path: Option<&str>) -> Result<bool, String> {
+    let path = match path {
+        Some(path) => path.to_string(),
+        None => std::env::var("WORD_HUNTER_DICT").unwrap_or_else(|_| DEFAULT_DICTIONARY.to_string()),
+    };
+
+    let bytes = std::fs::read(&path).map_err(|error| format!("Couldn't read the dictionary {path}: {error}"))?;
+    if load_compiled_dictionary_bytes(&bytes, path.clone()).is_ok() {
+        return Ok(true);
+    }
+
+    let contents = String::from_utf8(bytes).map_err(|_| format!("The dictionary {path} isn't UTF-8."))?;
+    load_dictionary_contents(&contents, path);
+    Ok(false)
+}
+
+/// Reads a board from the command line.
+/// Rows are separated by commas, slashes or spaces, and every letter is a tile.
+/// board: The rows of the board, such as "oetw,iars,ytop,rwbs".
+/// topology: How the tiles of the board connect.
+fn parse_board(board: &str, topology: Topology) -> Result<Board, String> {
+    let rows = board.split([',', '/', ' '])
+        .filter(|row| !row.is_empty())
+        .map(|row| row.chars().map(String::from).collect())
+        .collect();
+
+    Board::new(rows, topology).map_err(|error| error.to_string())
+}
@@ -0,0 +1,120 @@
+use std::io::{BufRead, Write};
+use word_hunt::{is_valid_word, match_pattern, solve_rack, SolveOptions, Solver, Topology};
+use crate::args::Args;
+use crate::solve::to_text;
+use crate::{load_dictionary, parse_board};
+
+const HELP: &str = "\
+Commands:
+  <rows>                 Solves a board, such as oetw,iars,ytop,rwbs
+  board <rows>           Solves a board
+  rack <letters>         Finds words spelled from a rack; * and ? are blanks
+  pattern <pattern>      Finds words matching a pattern, such as c_t_e__
+  check <word>           Checks if a word is in the dictionary
+  load <path>            Loads another dictionary on top of the current one
+  set <setting> <value>  Changes min-len, max, scoring, topology or sort
+  help                   Shows this message
+  quit                   Leaves the REPL
+";
+
+/// The settings queries are answered with, changed with `set`.
+struct Settings {
+    options: SolveOptions,
+    topology: Topology,
+}
+
+/// Keeps a dictionary loaded and answers queries read from stdin.
+/// arguments: The arguments after the command.
+pub fn run(arguments: impl Iterator<Item = String>) -> Result<(), String> {
+    let args = Args::parse(arguments, &["dict"], &[])?;
+    load_dictionary(args.get("dict"))?;
+
+    let mut settings = Settings {
+        options: SolveOptions { min_length: 3, ..Default::default() },
+        topology: Topology::Square,
+    };
+
+    let stdin = std::io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("> ");
+        std::io::stdout().flush().map_err(|error| error.to_string())?;
+
+        let Some(line) = lines.next() else {
+            println!();
+            return Ok(());
+        };
+        let line = line.map_err(|error| error.to_string())?;
+
+        let (command, rest) = line.trim().split_once(' ').unwrap_or((line.trim(), ""));
+        let rest = rest.trim();
+        let result = match command {
+            "" => Ok(()),
+            "quit" | "exit" => return Ok(()),
+            "help" => {
+                print!("{HELP}");
+                Ok(())
+            }
+            "board" => solve(rest, &settings),
+            "rack" => {
+                print!("{}", to_lines(solve_rack(rest, &settings.options)));
+                Ok(())
+            }
+            "pattern" => {
+                print!("{}", to_lines(match_pattern(rest)));
+                Ok(())
+            }
+            "check" => {
+                let valid = is_valid_word(&rest.to_lowercase());
+                println!("{rest} is {}a word", if valid { "" } else { "not " });
+                Ok(())
+            }
+            "load" => load_dictionary(Some(rest)).map(|_| ()),
+            "set" => set(rest, &mut settings),
+            rows if rows.contains([',', '/']) => solve(rows, &settings),
+            command => Err(format!("Unknown command {command}. Type help for a list.")),
+        };
+
+        if let Err(error) = result {
+            eprintln!("error: {error}");
+        }
+    }
+}
+
+/// Solves a board with the current settings.
+/// rows: The rows of the board.
+/// settings: The current settings.
+fn solve(rows: &str, settings: &Settings) -> Result<(), String> {
+    let board = parse_board(rows, settings.topology)?;
+    print!("{}", to_text(&Solver::new().find_matches(&board, &settings.options)));
+    Ok(())
+}
+
+/// Changes a setting.
+/// setting: The name of the setting, followed by its new value.
+/// settings: The current settings.
+fn set(setting: &str, settings: &mut Settings) -> Result<(), String> {
+    let (name, value) = setting.split_once(' ').ok_or("Usage: set <setting> <value>")?;
+    let value = value.trim();
+    let invalid = || format!("Invalid value {value:?} for {name}.");
+
+    match name {
+        "min-len" => settings.options.min_length = value.parse().map_err(|_| invalid())?,
+        "max" => settings.options.max_results = match value {
+            "none" => None,
+            value => Some(value.parse().map_err(|_| invalid())?),
+        },
+        "scoring" => settings.options.scoring = value.parse().map_err(|_| invalid())?,
+        "topology" => settings.topology = value.parse().map_err(|_| invalid())?,
+        "sort" => settings.options.order = value.parse().map_err(|_| invalid())?,
+        name => return Err(format!("Unknown setting {name}.")),
+    }
+
+    Ok(())
+}
+
+/// Lists words one per line.
+/// words: The words to list.
+fn to_lines(words: Vec<String>) -> String {
+    words.into_iter().map(|word| word + "\n").collect()
+}
@@ -0,0 +1,14 @@
+use crate::args::Args;
+use crate::load_dictionary;
+
+/// Answers newline-delimited JSON-RPC requests on stdin, writing responses to stdout.
+/// No dictionary is loaded unless `--dict` is given, so hosts can send a `load` request instead.
+/// arguments: The arguments after the command.
+pub fn run(arguments: impl Iterator<Item = String>) -> Result<(), String> {
+    let args = Args::parse(arguments, &["dict"], &[])?;
+    if let Some(path) = args.get("dict") {
+        load_dictionary(Some(path))?;
+    }
+
+    word_hunt::serve_rpc(std::io::stdin().lock(), std::io::stdout().lock()).map_err(|error| error.to_string())
+}
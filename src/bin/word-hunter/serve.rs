@@ -0,0 +1,16 @@
+use crate::args::Args;
+use crate::load_dictionary;
+
+/// The address the server listens on when `--address` isn't given.
+const DEFAULT_ADDRESS: &str = "127.0.0.1:8080";
+
+/// Serves the solver over HTTP.
+/// arguments: The arguments after the command.
+pub fn run(arguments: impl Iterator<Item = String>) -> Result<(), String> {
+    let args = Args::parse(arguments, &["dict", "address"], &[])?;
+    load_dictionary(args.get("dict"))?;
+
+    let address = args.get("address").unwrap_or(DEFAULT_ADDRESS);
+    println!("Listening on http://{address}");
+    word_hunt::serve(address).map_err(|error| format!("Couldn't serve on {address}: {error}"))
+}
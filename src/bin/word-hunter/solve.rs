@@ -0,0 +1,51 @@
+use word_hunt::{SolveOptions, Solver, WordMatch};
+use crate::args::Args;
+use crate::{load_dictionary, parse_board};
+
+/// Finds every word on a board.
+/// arguments: The arguments after the command.
+pub fn run(arguments: impl Iterator<Item = String>) -> Result<(), String> {
+    let args = Args::parse(
+        arguments,
+        &["dict", "board", "min-len", "max", "scoring", "topology", "sort", "format"],
+        &[]
+    )?;
+
+    load_dictionary(args.get("dict"))?;
+
+    let topology = args.parse_value("topology")?.unwrap_or_default();
+    let board = parse_board(args.get("board").ok_or("--board is required.")?, topology)?;
+
+    let options = SolveOptions {
+        min_length: args.parse_value("min-len")?.unwrap_or(3),
+        max_results: args.parse_value("max")?,
+        scoring: args.parse_value("scoring")?.unwrap_or_default(),
+        order: args.parse_value("sort")?.unwrap_or_default(),
+        ..Default::default()
+    };
+
+    let found = Solver::new().find_matches(&board, &options);
+    match args.get("format").unwrap_or("text") {
+        "text" => print!("{}", to_text(&found)),
+        "json" => println!("{}", to_json(&found)),
+        format => return Err(format!("Unknown format {format}.")),
+    }
+
+    Ok(())
+}
+
+/// Lists found words one per line, with their scores.
+/// found: The found words.
+pub fn to_text(found: &[WordMatch]) -> String {
+    found.iter()
+        .map(|found| format!("{}\t{}\n", found.word, found.score))
+        .collect()
+}
+
+/// Serializes found words as JSON, along with their total score.
+/// found: The found words.
+pub fn to_json(found: &[WordMatch]) -> String {
+    let words: Vec<String> = found.iter().map(WordMatch::to_json).collect();
+    let total_score = found.iter().map(|found| found.score).fold(0, u32::saturating_add);
+    format!("{{\"words\":[{}],\"total_score\":{total_score}}}", words.join(","))
+}
@@ -0,0 +1,520 @@
+use std::fmt;
+use std::str::FromStr;
+use crate::options::ParseNameError;
+
+/// All valid directions for locating adjacent characters on a square grid.
+const SQUARE_DIRECTIONS: [(i32, i32); 8] = [
+    (-1, -1), (-1, 0), (-1, 1),
+    (0, -1),           (0, 1),
+    (1, -1),  (1, 0),  (1, 1),
+];
+
+/// All valid directions for locating adjacent characters on a hex grid.
+/// Rows are the axial `r` axis and columns are the axial `q` axis.
+const HEX_DIRECTIONS: [(i32, i32); 6] = [
+              (-1, 0), (-1, 1),
+    (0, -1),           (0, 1),
+    (1, -1),  (1, 0),
+];
+
+/// All valid directions for locating adjacent characters with a chess knight's move.
+const KNIGHT_DIRECTIONS: [(i32, i32); 8] = [
+    (-2, -1), (-2, 1),
+    (-1, -2), (-1, 2),
+    (1, -2),  (1, 2),
+    (2, -1),  (2, 1),
+];
+
+/// The tile used to mark a cell that can never be visited.
+pub const BLOCKED_CELL: &str = "#";
+
+/// The tile used to mark a cell that matches any letter.
+/// "?" tiles are treated the same way.
+pub const WILDCARD_CELL: &str = "*";
+
+/// The way cells on a board connect to each other.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Topology {
+    /// A rectangular grid where every cell touches its 8 surrounding cells.
+    #[default]
+    Square,
+
+    /// A hexagonal grid stored in axial coordinates.
+    /// Every cell touches 6 cells.
+    Hex,
+
+    /// A stack of rectangular grids.
+    /// Every cell touches the 26 cells of the cube around it.
+    Cubic,
+
+    /// A rectangular grid where every cell touches the cells a chess knight could move to.
+    Knight,
+
+    /// Cells connect however the board's creator decided.
+    Custom,
+}
+
+impl Topology {
+    /// The names of the topologies which can be parsed.
+    /// Cubic and custom boards need more than a name to describe them.
+    pub const NAMES: [&'static str; 3] = ["square", "hex", "knight"];
+
+    /// Returns the (layer, row, col) offsets of all cells adjacent to a cell.
+    pub fn directions(&self) -> Vec<(i32, i32, i32)> {
+        match self {
+            Topology::Square => SQUARE_DIRECTIONS.iter()
+                .map(|&(dx, dy)| (0, dx, dy))
+                .collect(),
+            Topology::Hex => HEX_DIRECTIONS.iter()
+                .map(|&(dx, dy)| (0, dx, dy))
+                .collect(),
+            Topology::Knight => KNIGHT_DIRECTIONS.iter()
+                .map(|&(dx, dy)| (0, dx, dy))
+                .collect(),
+            Topology::Cubic => {
+                let mut directions = Vec::with_capacity(26);
+                for dz in -1..=1 {
+                    for dx in -1..=1 {
+                        for dy in -1..=1 {
+                            if (dz, dx, dy) != (0, 0, 0) {
+                                directions.push((dz, dx, dy));
+                            }
+                        }
+                    }
+                }
+                directions
+            }
+            Topology::Custom => Vec::new(),
+        }
+    }
+}
+
+/// The reasons a board can be rejected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BoardError {
+    /// The board has no tiles.
+    EmptyBoard,
+
+    /// A row has a different number of tiles than the first row.
+    RaggedRows { layer: usize, row: usize },
+
+    /// A layer has a different number of rows than the first layer.
+    RaggedLayers { layer: usize },
+
+    /// A flat board has the wrong number of tiles for its dimensions.
+    WrongSize { expected: usize, found: usize },
+
+    /// A neighbor list refers to a tile that isn't on the board.
+    InvalidNeighbor { index: usize, neighbor: usize },
+
+    /// A tile has no letters, even after trimming whitespace.
+    /// Use [`BLOCKED_CELL`] to mark cells that can't be visited.
+    InvalidCell { layer: usize, row: usize, col: usize },
+
+    /// The dimensions of a board describe more tiles than can be counted.
+    TooLarge { rows: usize, cols: usize },
+}
+
+impl FromStr for Topology {
+    type Err = ParseNameError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "square" => Ok(Topology::Square),
+            "hex" => Ok(Topology::Hex),
+            "knight" => Ok(Topology::Knight),
+            name => Err(ParseNameError { kind: "topology", name: name.to_string() }),
+        }
+    }
+}
+
+impl fmt::Display for BoardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BoardError::EmptyBoard => write!(f, "The board has no tiles."),
+            BoardError::RaggedRows { layer, row } => write!(
+                f, "Row {row} of layer {layer} isn't as long as the first row."
+            ),
+            BoardError::RaggedLayers { layer } => write!(
+                f, "Layer {layer} doesn't have as many rows as the first layer."
+            ),
+            BoardError::WrongSize { expected, found } => write!(
+                f, "The board should have {expected} tiles, but has {found}."
+            ),
+            BoardError::InvalidNeighbor { index, neighbor } => write!(
+                f, "Tile {index} connects to tile {neighbor}, which isn't on the board."
+            ),
+            BoardError::InvalidCell { layer, row, col } => write!(
+                f, "The tile at layer {layer}, row {row}, column {col} is empty."
+            ),
+            BoardError::TooLarge { rows, cols } => write!(
+                f, "A board of {rows} rows and {cols} columns is too large."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BoardError {}
+
+/// A bonus applied to the score of words using a tile.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Multiplier {
+    #[default]
+    None,
+
+    /// Doubles the value of the tile's letters.
+    DoubleLetter,
+
+    /// Triples the value of the tile's letters.
+    TripleLetter,
+
+    /// Doubles the score of the word.
+    DoubleWord,
+
+    /// Triples the score of the word.
+    TripleWord,
+}
+
+impl Multiplier {
+    /// Returns the factor applied to the tile's letters.
+    pub fn letter_factor(&self) -> u32 {
+        match self {
+            Multiplier::DoubleLetter => 2,
+            Multiplier::TripleLetter => 3,
+            _ => 1,
+        }
+    }
+
+    /// Returns the factor applied to the whole word.
+    pub fn word_factor(&self) -> u32 {
+        match self {
+            Multiplier::DoubleWord => 2,
+            Multiplier::TripleWord => 3,
+            _ => 1,
+        }
+    }
+}
+
+/// A game board made of letter tiles.
+/// Tiles are stored in a flat list, indexed by layer, then row, then column.
+#[derive(Clone)]
+pub struct Board {
+    cells: Vec<String>,
+    neighbors: Vec<Vec<usize>>,
+    multipliers: Vec<Multiplier>,
+    layers: usize,
+    rows: usize,
+    cols: usize,
+    topology: Topology,
+}
+
+impl Board {
+    /// Creates a new board.
+    /// cells: The tiles of the board, by row.
+    /// topology: How the tiles connect to each other.
+    pub fn new(cells: Vec<Vec<String>>, topology: Topology) -> Result<Self, BoardError> {
+        Self::build(vec![cells], topology)
+    }
+
+    /// Creates a rectangular board.
+    /// cells: The tiles of the board, by row.
+    pub fn square(cells: Vec<Vec<String>>) -> Result<Self, BoardError> {
+        Self::new(cells, Topology::Square)
+    }
+
+    /// Creates a hexagonal board.
+    /// cells: The tiles of the board, by axial row.
+    pub fn hex(cells: Vec<Vec<String>>) -> Result<Self, BoardError> {
+        Self::new(cells, Topology::Hex)
+    }
+
+    /// Creates a rectangular board where tiles connect by knight's moves.
+    /// cells: The tiles of the board, by row.
+    pub fn knight(cells: Vec<Vec<String>>) -> Result<Self, BoardError> {
+        Self::new(cells, Topology::Knight)
+    }
+
+    /// Creates a rectangular board from rows of single-letter tiles.
+    /// rows: The tiles of the board, by row.
+    pub fn from_chars(rows: &[&[char]]) -> Result<Self, BoardError> {
+        Self::square(rows.iter()
+            .map(|row| row.iter().map(char::to_string).collect())
+            .collect())
+    }
+
+    /// Creates a rectangular board from rows of single-letter tiles.
+    /// rows: The tiles of the board, by row, such as `["oetw", "iars"]`.
+    pub fn from_rows(rows: &[&str]) -> Result<Self, BoardError> {
+        Self::square(rows.iter()
+            .map(|row| row.chars().map(String::from).collect())
+            .collect())
+    }
+
+    /// Creates a rectangular board from a flat string of single-letter tiles.
+    /// letters: The tiles of the board, row after row, such as "oetwiars".
+    /// rows: The number of rows.
+    /// cols: The number of columns.
+    pub fn from_flat(letters: &str, rows: usize, cols: usize) -> Result<Self, BoardError> {
+        let size = rows.checked_mul(cols).ok_or(BoardError::TooLarge { rows, cols })?;
+        let letters: Vec<char> = letters.chars().collect();
+        if letters.len() != size {
+            return Err(BoardError::WrongSize { expected: size, found: letters.len() });
+        }
+
+        Self::from_chars(&letters.chunks(cols.max(1)).collect::<Vec<_>>())
+    }
+
+    /// Creates a board where a function decides which tiles connect.
+    /// cells: The tiles of the board, by row.
+    /// adjacent: Returns the (row, col) positions a tile at (row, col) connects to.
+    pub fn with_adjacency(
+        cells: Vec<Vec<String>>,
+        adjacent: impl Fn(usize, usize) -> Vec<(usize, usize)>
+    ) -> Result<Self, BoardError> {
+        Self::build_with(vec![cells], Topology::Custom, |_, row, col| {
+            adjacent(row, col)
+                .into_iter()
+                .map(|(new_row, new_col)| (0, new_row, new_col))
+                .collect()
+        })
+    }
+
+    /// Creates a board from tiles and precomputed neighbor lists.
+    /// The board is laid out as a single row.
+    /// cells: The tiles of the board.
+    /// neighbors: The indices of the tiles each tile connects to.
+    pub fn from_graph(cells: Vec<String>, neighbors: Vec<Vec<usize>>) -> Result<Self, BoardError> {
+        if neighbors.len() != cells.len() {
+            return Err(BoardError::WrongSize { expected: cells.len(), found: neighbors.len() });
+        }
+
+        for (index, adjacent) in neighbors.iter().enumerate() {
+            if let Some(&neighbor) = adjacent.iter().find(|&&neighbor| neighbor >= cells.len()) {
+                return Err(BoardError::InvalidNeighbor { index, neighbor });
+            }
+        }
+
+        Self::build_with(vec![vec![cells]], Topology::Custom, |_, _, col| {
+            neighbors[col].iter().map(|&neighbor| (0, 0, neighbor)).collect()
+        })
+    }
+
+    /// Creates a three-dimensional board from stacked rectangular grids.
+    /// layers: The grids of the board, from bottom to top.
+    pub fn layered(layers: Vec<Vec<Vec<String>>>) -> Result<Self, BoardError> {
+        Self::build(layers, Topology::Cubic)
+    }
+
+    /// Flattens the layers of a board and resolves the neighbors of every tile.
+    /// Boards must be non-empty and rectangular.
+    /// Tiles are trimmed and lowercased to match the dictionary.
+    /// layers: The grids of the board.
+    /// topology: How the tiles connect to each other.
+    fn build(layers: Vec<Vec<Vec<String>>>, topology: Topology) -> Result<Self, BoardError> {
+        let directions = topology.directions();
+        Self::build_with(layers, topology, |layer, row, col| {
+            directions.iter()
+                .filter_map(|&(dz, dx, dy)| Some((
+                    layer.checked_add_signed(dz as isize)?,
+                    row.checked_add_signed(dx as isize)?,
+                    col.checked_add_signed(dy as isize)?
+                )))
+                .collect()
+        })
+    }
+
+    /// Flattens the layers of a board and resolves the neighbors of every tile.
+    /// Neighbors outside of the board are ignored.
+    /// layers: The grids of the board.
+    /// topology: How the tiles connect to each other.
+    /// adjacent: Returns the (layer, row, col) positions adjacent to a position.
+    fn build_with(
+        layers: Vec<Vec<Vec<String>>>,
+        topology: Topology,
+        adjacent: impl Fn(usize, usize, usize) -> Vec<(usize, usize, usize)>
+    ) -> Result<Self, BoardError> {
+        validate(&layers)?;
+        let layers = normalize(layers)?;
+        let depth = layers.len();
+        let rows = layers.first().map_or(0, Vec::len);
+        let cols = layers.first()
+            .and_then(|layer| layer.first())
+            .map_or(0, Vec::len);
+
+        let cells: Vec<String> = layers.into_iter()
+            .flatten()
+            .flatten()
+            .collect();
+
+        let mut neighbors = Vec::with_capacity(cells.len());
+        for layer in 0..depth {
+            for row in 0..rows {
+                for col in 0..cols {
+                    // Blocked tiles connect to nothing.
+                    if is_blocked(&cells[(layer * rows + row) * cols + col]) {
+                        neighbors.push(Vec::new());
+                        continue;
+                    }
+
+                    let mut tiles: Vec<usize> = adjacent(layer, row, col)
+                        .into_iter()
+                        .filter(|&(new_layer, new_row, new_col)| {
+                            new_layer < depth && new_row < rows && new_col < cols
+                                && (new_layer, new_row, new_col) != (layer, row, col)
+                        })
+                        .map(|(new_layer, new_row, new_col)| (new_layer * rows + new_row) * cols + new_col)
+                        .filter(|&index| !is_blocked(&cells[index]))
+                        .collect();
+                    tiles.sort_unstable();
+                    tiles.dedup();
+                    neighbors.push(tiles);
+                }
+            }
+        }
+
+        let multipliers = vec![Multiplier::None; cells.len()];
+        Ok(Self { cells, neighbors, multipliers, layers: depth, rows, cols, topology })
+    }
+
+    /// Returns how the tiles connect to each other.
+    pub fn topology(&self) -> Topology {
+        self.topology
+    }
+
+    /// Returns the number of layers on the board.
+    pub fn layers(&self) -> usize {
+        self.layers
+    }
+
+    /// Returns the number of rows on the board.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns on the board.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the number of tiles on the board.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Checks if the board has no tiles.
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns the tile at an index.
+    /// index: The index of the tile.
+    pub fn cell(&self, index: usize) -> &str {
+        &self.cells[index]
+    }
+
+    /// Checks if a tile can never be visited.
+    /// index: The index of the tile.
+    pub fn is_blocked(&self, index: usize) -> bool {
+        is_blocked(&self.cells[index])
+    }
+
+    /// Checks if a tile matches any letter.
+    /// index: The index of the tile.
+    pub fn is_wildcard(&self, index: usize) -> bool {
+        let cell = &self.cells[index];
+        cell == WILDCARD_CELL || cell == "?"
+    }
+
+    /// Replaces the letters of a tile.
+    /// The tile's neighbors are kept, so tiles can't be blocked or unblocked this way.
+    /// index: The index of the tile.
+    /// cell: The new letters of the tile, already normalized.
+    pub(crate) fn set_cell(&mut self, index: usize, cell: String) {
+        debug_assert!(!self.is_blocked(index) && !is_blocked(&cell));
+        self.cells[index] = cell;
+    }
+
+    /// Returns the indices of all tiles adjacent to a tile.
+    /// index: The index of the tile.
+    pub fn neighbors(&self, index: usize) -> &[usize] {
+        &self.neighbors[index]
+    }
+
+    /// Returns the bonus of a tile.
+    /// index: The index of the tile.
+    pub fn multiplier(&self, index: usize) -> Multiplier {
+        self.multipliers[index]
+    }
+
+    /// Sets the bonus of a tile.
+    /// index: The index of the tile.
+    /// multiplier: The bonus of the tile.
+    pub fn set_multiplier(&mut self, index: usize, multiplier: Multiplier) {
+        self.multipliers[index] = multiplier;
+    }
+
+    /// Checks if any tile has a bonus.
+    pub fn has_multipliers(&self) -> bool {
+        self.multipliers.iter().any(|&multiplier| multiplier != Multiplier::None)
+    }
+
+    /// Returns the (layer, row, col) position of a tile.
+    /// index: The index of the tile.
+    pub fn position(&self, index: usize) -> (usize, usize, usize) {
+        let layer_size = self.rows * self.cols;
+        (index / layer_size, (index % layer_size) / self.cols, index % self.cols)
+    }
+}
+
+/// Checks if a tile marks a blocked cell.
+/// cell: The tile's contents.
+fn is_blocked(cell: &str) -> bool {
+    cell == BLOCKED_CELL
+}
+
+/// Checks that a board has tiles and that all of its layers and rows line up.
+/// layers: The grids of the board.
+fn validate(layers: &[Vec<Vec<String>>]) -> Result<(), BoardError> {
+    let rows = layers.first().map_or(0, Vec::len);
+    let cols = layers.first()
+        .and_then(|layer| layer.first())
+        .map_or(0, Vec::len);
+
+    if rows == 0 || cols == 0 {
+        return Err(BoardError::EmptyBoard);
+    }
+
+    for (layer, grid) in layers.iter().enumerate() {
+        if grid.len() != rows {
+            return Err(BoardError::RaggedLayers { layer });
+        }
+
+        if let Some(row) = grid.iter().position(|cells| cells.len() != cols) {
+            return Err(BoardError::RaggedRows { layer, row });
+        }
+    }
+
+    Ok(())
+}
+
+/// Trims and lowercases every tile of a board, rejecting empty tiles.
+/// layers: The grids of the board.
+fn normalize(layers: Vec<Vec<Vec<String>>>) -> Result<Vec<Vec<Vec<String>>>, BoardError> {
+    layers.into_iter()
+        .enumerate()
+        .map(|(layer, rows)| rows.into_iter()
+            .enumerate()
+            .map(|(row, cells)| cells.into_iter()
+                .enumerate()
+                .map(|(col, cell)| {
+                    let cell = cell.trim().to_lowercase();
+                    if cell.is_empty() {
+                        return Err(BoardError::InvalidCell { layer, row, col });
+                    }
+
+                    Ok(cell)
+                })
+                .collect())
+            .collect())
+        .collect()
+}
@@ -0,0 +1,293 @@
+use std::ffi::{c_char, CStr, CString};
+use std::ptr;
+use std::sync::Arc;
+use crate::analysis::DifficultyLevel;
+use crate::board::Board;
+use crate::insert_words;
+use crate::options::SolveOptions;
+use crate::solver::Solver;
+use crate::trie_node::TrieNode;
+
+/// A dictionary owned by C.
+/// Only ever handled through a pointer, so C can't see its fields.
+pub struct WhDictionary {
+    trie: Arc<TrieNode>,
+}
+
+/// The words found on a board, longest first.
+/// Must be freed with `wh_results_free`.
+#[repr(C)]
+pub struct WhResults {
+    /// The number of words.
+    pub count: usize,
+
+    /// The words, as null-terminated UTF-8 strings.
+    pub words: *mut *mut c_char,
+
+    /// The score of each word, in the same order.
+    pub scores: *mut u32,
+}
+
+/// Statistics about a board.
+#[repr(C)]
+pub struct WhAnalysis {
+    /// The number of words on the board.
+    pub word_count: usize,
+
+    /// The points earned by finding every word, with Word Hunt scoring.
+    pub total_score: u32,
+
+    /// The difficulty of the board, from 0 (easiest) to 1 (hardest).
+    pub difficulty: f64,
+
+    /// The bucket the difficulty falls in: 0 for easy, 1 for medium, 2 for hard.
+    pub difficulty_level: u8,
+
+    /// The average length of the words, in letters.
+    pub average_length: f64,
+
+    /// The share of playable tiles holding a rare letter, from 0 to 1.
+    pub rare_letters: f64,
+
+    /// The share of the total score earned by the best few words, from 0 to 1.
+    pub score_concentration: f64,
+}
+
+/// Reads a null-terminated UTF-8 string from C.
+/// Returns `None` if the pointer is null or the string isn't UTF-8.
+/// value: The string.
+///
+/// # Safety
+/// The pointer must be null or point to a null-terminated string.
+unsafe fn read_str<'a>(value: *const c_char) -> Option<&'a str> {
+    if value.is_null() {
+        return None;
+    }
+
+    CStr::from_ptr(value).to_str().ok()
+}
+
+/// Creates an empty dictionary.
+/// Must be freed with `wh_dictionary_free`.
+#[no_mangle]
+pub extern "C" fn wh_dictionary_new() -> *mut WhDictionary {
+    Box::into_raw(Box::new(WhDictionary { trie: Arc::new(TrieNode::new()) }))
+}
+
+/// Adds the words of a dictionary file to a dictionary.
+/// Each line holds a word, optionally followed by how often it is used.
+/// Returns whether the file could be read.
+/// dictionary: The dictionary to add the words to.
+/// path: The path to the dictionary file.
+///
+/// # Safety
+/// The dictionary must come from `wh_dictionary_new`, and the path must be a null-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn wh_dictionary_load(dictionary: *mut WhDictionary, path: *const c_char) -> bool {
+    let Some(path) = read_str(path) else {
+        return false;
+    };
+
+    match std::fs::read_to_string(path) {
+        Ok(contents) => wh_dictionary_add(dictionary, &contents),
+        Err(_) => false,
+    }
+}
+
+/// Adds the words of a dictionary file which is already in memory to a dictionary.
+/// Each line holds a word, optionally followed by how often it is used.
+/// Returns whether the contents were valid UTF-8.
+/// dictionary: The dictionary to add the words to.
+/// contents: The contents of the dictionary file.
+///
+/// # Safety
+/// The dictionary must come from `wh_dictionary_new`, and the contents must be a null-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn wh_dictionary_load_contents(dictionary: *mut WhDictionary, contents: *const c_char) -> bool {
+    match read_str(contents) {
+        Some(contents) => wh_dictionary_add(dictionary, contents),
+        None => false,
+    }
+}
+
+/// Adds words to a dictionary.
+/// Returns whether the dictionary was valid.
+/// dictionary: The dictionary to add the words to.
+/// contents: The contents of a dictionary file.
+///
+/// # Safety
+/// The dictionary must be null or come from `wh_dictionary_new`.
+unsafe fn wh_dictionary_add(dictionary: *mut WhDictionary, contents: &str) -> bool {
+    let Some(dictionary) = dictionary.as_mut() else {
+        return false;
+    };
+
+    insert_words(Arc::make_mut(&mut dictionary.trie), contents);
+    true
+}
+
+/// Frees a dictionary.
+/// Results found with the dictionary stay valid.
+/// dictionary: The dictionary, which may be null.
+///
+/// # Safety
+/// The dictionary must be null or come from `wh_dictionary_new`, and mustn't be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn wh_dictionary_free(dictionary: *mut WhDictionary) {
+    if !dictionary.is_null() {
+        drop(Box::from_raw(dictionary));
+    }
+}
+
+/// Finds every word on a board of single-letter tiles, with Word Hunt scoring.
+/// Returns null if the dictionary or letters are null, or the letters don't fill the board.
+/// dictionary: The dictionary to resolve words from.
+/// letters: The tiles of the board, row after row, such as "oetwiars".
+/// rows: The number of rows.
+/// cols: The number of columns.
+///
+/// # Safety
+/// The dictionary must be null or come from `wh_dictionary_new`, and the letters must be
+/// null or a null-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn wh_solve(
+    dictionary: *const WhDictionary,
+    letters: *const c_char,
+    rows: usize,
+    cols: usize
+) -> *mut WhResults {
+    let Some((solver, board)) = read_board(dictionary, letters, rows, cols) else {
+        return ptr::null_mut();
+    };
+
+    let found = solver.score_words(&board, &SolveOptions::default()).words;
+
+    // Words never hold a null character, since they come from lines of text.
+    let words: Box<[*mut c_char]> = found.iter()
+        .map(|found| CString::new(found.word.as_str()).unwrap_or_default().into_raw())
+        .collect();
+    let scores: Box<[u32]> = found.iter().map(|found| found.score).collect();
+
+    Box::into_raw(Box::new(WhResults {
+        count: found.len(),
+        words: Box::into_raw(words).cast(),
+        scores: Box::into_raw(scores).cast(),
+    }))
+}
+
+/// Measures a board, such as how hard it is.
+/// Returns false, leaving the analysis unchanged, if the dictionary or letters are null,
+/// or the letters don't fill the board.
+/// dictionary: The dictionary to resolve words from.
+/// letters: The tiles of the board, row after row, such as "oetwiars".
+/// rows: The number of rows.
+/// cols: The number of columns.
+/// analysis: Where to write the statistics.
+///
+/// # Safety
+/// The dictionary must be null or come from `wh_dictionary_new`, the letters must be
+/// null or a null-terminated string, and the analysis must be writable.
+#[no_mangle]
+pub unsafe extern "C" fn wh_analyze(
+    dictionary: *const WhDictionary,
+    letters: *const c_char,
+    rows: usize,
+    cols: usize,
+    analysis: *mut WhAnalysis
+) -> bool {
+    let Some((solver, board)) = read_board(dictionary, letters, rows, cols) else {
+        return false;
+    };
+    let Some(analysis) = analysis.as_mut() else {
+        return false;
+    };
+
+    let options = SolveOptions::default();
+    let difficulty = solver.difficulty(&board, &options);
+    *analysis = WhAnalysis {
+        word_count: difficulty.word_count,
+        total_score: solver.score_words(&board, &options).total_score,
+        difficulty: difficulty.rating,
+        difficulty_level: match difficulty.level {
+            DifficultyLevel::Easy => 0,
+            DifficultyLevel::Medium => 1,
+            DifficultyLevel::Hard => 2,
+        },
+        average_length: difficulty.average_length,
+        rare_letters: difficulty.rare_letters,
+        score_concentration: difficulty.score_concentration,
+    };
+    true
+}
+
+/// Counts how many words pass through each tile of a board.
+/// Returns false, leaving the counts unchanged, if the dictionary or letters are null,
+/// or the letters don't fill the board.
+/// dictionary: The dictionary to resolve words from.
+/// letters: The tiles of the board, row after row, such as "oetwiars".
+/// rows: The number of rows.
+/// cols: The number of columns.
+/// counts: Where to write the count of each tile, row after row; `rows * cols` long.
+///
+/// # Safety
+/// The dictionary must be null or come from `wh_dictionary_new`, the letters must be
+/// null or a null-terminated string, and the counts must have room for every tile.
+#[no_mangle]
+pub unsafe extern "C" fn wh_heatmap(
+    dictionary: *const WhDictionary,
+    letters: *const c_char,
+    rows: usize,
+    cols: usize,
+    counts: *mut usize
+) -> bool {
+    let Some((solver, board)) = read_board(dictionary, letters, rows, cols) else {
+        return false;
+    };
+    if counts.is_null() {
+        return false;
+    }
+
+    let heatmap = solver.heatmap(&board, &SolveOptions::default());
+    ptr::copy_nonoverlapping(heatmap.as_ptr(), counts, heatmap.len());
+    true
+}
+
+/// Reads a board of single-letter tiles from C, along with a solver for it.
+/// Returns `None` if the dictionary or letters are null, or the letters don't fill the board.
+/// dictionary: The dictionary to resolve words from.
+/// letters: The tiles of the board, row after row.
+/// rows: The number of rows.
+/// cols: The number of columns.
+///
+/// # Safety
+/// The dictionary must be null or come from `wh_dictionary_new`, and the letters must be
+/// null or a null-terminated string.
+unsafe fn read_board(
+    dictionary: *const WhDictionary,
+    letters: *const c_char,
+    rows: usize,
+    cols: usize
+) -> Option<(Solver, Board)> {
+    let dictionary = dictionary.as_ref()?;
+    let board = Board::from_flat(read_str(letters)?, rows, cols).ok()?;
+    Some((Solver::with_dictionary(Arc::clone(&dictionary.trie)), board))
+}
+
+/// Frees the words found on a board.
+/// results: The results, which may be null.
+///
+/// # Safety
+/// The results must be null or come from `wh_solve`, and mustn't be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn wh_results_free(results: *mut WhResults) {
+    if results.is_null() {
+        return;
+    }
+
+    let results = Box::from_raw(results);
+    let words = Box::from_raw(ptr::slice_from_raw_parts_mut(results.words, results.count));
+    for &word in words.iter() {
+        drop(CString::from_raw(word));
+    }
+    drop(Box::from_raw(ptr::slice_from_raw_parts_mut(results.scores, results.count)));
+}
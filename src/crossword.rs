@@ -0,0 +1,62 @@
+use crate::solver::Solver;
+use crate::trie_node::TrieNode;
+
+impl Solver {
+    /// Finds every dictionary word matching a crossword-style pattern.
+    /// Letters must match exactly, while "_", "." and "?" match any single letter.
+    /// Words are returned alphabetically.
+    /// pattern: The pattern, such as "c_t_e__".
+    pub fn match_pattern(&self, pattern: &str) -> Vec<String> {
+        let pattern: Vec<Option<char>> = pattern.to_lowercase()
+            .chars()
+            .map(|letter| match letter {
+                '_' | '.' | '?' => None,
+                letter => Some(letter),
+            })
+            .collect();
+
+        let mut words = Vec::new();
+        let mut current_word = String::new();
+        visit(self.dictionary(), &pattern, &mut current_word, &mut words);
+
+        words.sort_unstable();
+        words
+    }
+}
+
+/// Follows the pattern through the trie from the current word.
+/// node: The trie node of the current word.
+/// pattern: The rest of the pattern; blanks are `None`.
+/// current_word: The current word.
+/// words: The words found so far.
+fn visit(node: &TrieNode, pattern: &[Option<char>], current_word: &mut String, words: &mut Vec<String>) {
+    let Some((&spot, rest)) = pattern.split_first() else {
+        if node.is_end_of_word() {
+            words.push(current_word.clone());
+        }
+
+        return;
+    };
+
+    // Words too short to fill the pattern are never explored.
+    if node.longest_suffix() < pattern.len() {
+        return;
+    }
+
+    match spot {
+        Some(letter) => {
+            if let Some(next) = node.descend(letter.encode_utf8(&mut [0; 4])) {
+                current_word.push(letter);
+                visit(next, rest, current_word, words);
+                current_word.pop();
+            }
+        }
+        None => {
+            for (letter, next) in node.children() {
+                current_word.push(letter);
+                visit(next, rest, current_word, words);
+                current_word.pop();
+            }
+        }
+    }
+}
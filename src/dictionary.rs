@@ -0,0 +1,29 @@
+use std::fmt;
+
+/// A summary of the loaded dictionary.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DictionaryInfo {
+    /// The number of words in the dictionary.
+    pub word_count: usize,
+
+    /// The paths of the files the dictionary was loaded from, in the order they were loaded.
+    pub sources: Vec<String>,
+
+    /// Whether the dictionary knows how often its words are used.
+    pub has_frequencies: bool,
+}
+
+impl fmt::Display for DictionaryInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.sources.is_empty() {
+            return write!(f, "No dictionary is loaded.");
+        }
+
+        write!(f, "{} words from {}", self.word_count, self.sources.join(", "))?;
+        if self.has_frequencies {
+            write!(f, ", with frequencies")?;
+        }
+
+        write!(f, ".")
+    }
+}
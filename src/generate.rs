@@ -0,0 +1,468 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::ops::RangeInclusive;
+use crate::board::{Board, BoardError, BLOCKED_CELL};
+use crate::options::SolveOptions;
+use crate::scoring::ScoredBoard;
+use crate::solver::Solver;
+
+/// The dice of the classic 4x4 Boggle set.
+const CLASSIC_DICE: [&str; 16] = [
+    "aaeegn", "abbjoo", "achops", "affkps",
+    "aoottw", "cimotu", "deilrx", "delrvy",
+    "distty", "eeghnw", "eeinsu", "ehrtvw",
+    "eiosst", "elrtty", "himnqu", "hlnnrz",
+];
+
+/// The dice of the 5x5 Big Boggle set.
+const BIG_DICE: [&str; 25] = [
+    "aaafrs", "aaeeee", "aafirs", "adennn", "aeeeem",
+    "aeegmu", "aegmnn", "afirsy", "bjkqxz", "ccenst",
+    "ceiilt", "ceilpt", "ceipst", "ddhnot", "dhhlor",
+    "dhlnor", "dhlnor", "eiiitt", "emottt", "ensssu",
+    "fiprsy", "gorrvw", "iprrry", "nootuw", "ooottu",
+];
+
+/// A source of random numbers for generating boards.
+pub trait Rng {
+    /// Returns the next random number.
+    fn next_u64(&mut self) -> u64;
+
+    /// Returns a random number below a bound.
+    /// bound: The exclusive upper bound, which must not be 0.
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
+
+/// A random number generator seeded differently every time it is created.
+pub struct RandomRng {
+    state: RandomState,
+    counter: u64,
+}
+
+impl RandomRng {
+    /// Creates a new generator with a random seed.
+    pub fn new() -> Self {
+        Self { state: RandomState::new(), counter: 0 }
+    }
+}
+
+impl Default for RandomRng {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Rng for RandomRng {
+    fn next_u64(&mut self) -> u64 {
+        self.counter += 1;
+        let mut hasher = self.state.build_hasher();
+        hasher.write_u64(self.counter);
+        hasher.finish()
+    }
+}
+
+/// A random number generator which always produces the same numbers for the same seed.
+/// Uses SplitMix64, which only relies on 64-bit integer math, so the numbers are
+/// identical on every platform.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    /// Creates a new generator.
+    /// seed: The seed, such as the number of a daily puzzle.
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+}
+
+impl Rng for SeededRng {
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut value = self.state;
+        value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        value ^ (value >> 31)
+    }
+}
+
+/// A set of letter dice used to generate boards.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiceSet {
+    /// The faces of each die.
+    /// A face may hold several letters, such as "qu".
+    pub dice: Vec<Vec<String>>,
+
+    /// The number of rows of boards rolled from the set.
+    pub rows: usize,
+
+    /// The number of columns of boards rolled from the set.
+    pub cols: usize,
+}
+
+impl DiceSet {
+    /// Creates a custom set of dice.
+    /// dice: The faces of each die.
+    /// rows: The number of rows of boards rolled from the set.
+    /// cols: The number of columns of boards rolled from the set.
+    pub fn new(dice: Vec<Vec<String>>, rows: usize, cols: usize) -> Result<Self, BoardError> {
+        let size = rows.checked_mul(cols).ok_or(BoardError::TooLarge { rows, cols })?;
+        if dice.len() != size {
+            return Err(BoardError::WrongSize { expected: size, found: dice.len() });
+        }
+
+        if dice.is_empty() || dice.iter().any(Vec::is_empty) {
+            return Err(BoardError::EmptyBoard);
+        }
+
+        Ok(Self { dice, rows, cols })
+    }
+
+    /// The 16 dice of classic 4x4 Boggle.
+    pub fn classic() -> Self {
+        Self::from_letters(&CLASSIC_DICE, 4, 4)
+    }
+
+    /// The 25 dice of 5x5 Big Boggle.
+    pub fn big() -> Self {
+        Self::from_letters(&BIG_DICE, 5, 5)
+    }
+
+    /// Creates a set of dice with a single letter on each face.
+    /// 'q' faces become "qu", as on real dice.
+    /// dice: The letters of each die.
+    /// rows: The number of rows of boards rolled from the set.
+    /// cols: The number of columns of boards rolled from the set.
+    fn from_letters(dice: &[&str], rows: usize, cols: usize) -> Self {
+        let dice = dice.iter()
+            .map(|die| die.chars()
+                .map(|letter| match letter {
+                    'q' => "qu".to_string(),
+                    letter => letter.to_string(),
+                })
+                .collect())
+            .collect();
+
+        Self { dice, rows, cols }
+    }
+}
+
+/// The qualities a generated board must have.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BoardTargets {
+    /// The fewest words the board may have.
+    pub min_words: usize,
+
+    /// The length of the longest word the board must have, in letters.
+    pub min_longest: usize,
+
+    /// The range the total score of the board must fall in.
+    pub score_range: Option<RangeInclusive<u32>>,
+
+    /// How many fresh boards to roll before giving up.
+    pub attempts: usize,
+
+    /// How many single dice to reroll on each board while trying to meet the targets.
+    pub repairs: usize,
+}
+
+impl Default for BoardTargets {
+    fn default() -> Self {
+        Self {
+            min_words: 0,
+            min_longest: 0,
+            score_range: None,
+            attempts: 100,
+            repairs: 20,
+        }
+    }
+}
+
+impl BoardTargets {
+    /// Measures roughly how far a solved board is from meeting the targets.
+    /// Returns 0 when every target is met.
+    /// scored: The solved board.
+    fn shortfall(&self, scored: &ScoredBoard) -> u64 {
+        let longest = scored.words.iter()
+            .map(|found| found.word.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        let score_distance = self.score_range.as_ref().map_or(0, |range| {
+            if scored.total_score < *range.start() {
+                range.start() - scored.total_score
+            } else {
+                scored.total_score.saturating_sub(*range.end())
+            }
+        });
+
+        self.min_words.saturating_sub(scored.words.len()) as u64
+            + 10 * self.min_longest.saturating_sub(longest) as u64
+            + u64::from(score_distance.div_ceil(100))
+    }
+}
+
+/// Generates a board by shaking a set of dice into the grid.
+/// Every die lands in a random cell, showing a random face.
+/// The same dice and random numbers always give the same board.
+/// dice: The dice to roll.
+/// rng: The source of randomness.
+pub fn generate_board(dice: &DiceSet, rng: &mut impl Rng) -> Result<Board, BoardError> {
+    let (_, faces) = roll(dice, rng);
+    to_board(dice, &faces)
+}
+
+/// Generates the board for a seed.
+/// The same dice and seed give the same board on every platform.
+/// dice: The dice to roll.
+/// seed: The seed, such as the number of a daily puzzle.
+pub fn generate_seeded_board(dice: &DiceSet, seed: u64) -> Result<Board, BoardError> {
+    generate_board(dice, &mut SeededRng::new(seed))
+}
+
+/// Shakes a set of dice into the grid.
+/// Returns the die in each cell, and the face it shows.
+/// dice: The dice to roll.
+/// rng: The source of randomness.
+fn roll(dice: &DiceSet, rng: &mut impl Rng) -> (Vec<usize>, Vec<String>) {
+    // Shuffle the dice into the cells, with a Fisher-Yates shuffle.
+    let mut order: Vec<usize> = (0..dice.dice.len()).collect();
+    for index in (1..order.len()).rev() {
+        order.swap(index, rng.below(index + 1));
+    }
+
+    let faces = order.iter()
+        .map(|&die| {
+            let faces = &dice.dice[die];
+            faces[rng.below(faces.len())].clone()
+        })
+        .collect();
+
+    (order, faces)
+}
+
+/// Returns a random number between 0 and 1.
+/// rng: The source of randomness.
+fn unit(rng: &mut impl Rng) -> f64 {
+    (rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Lays rolled faces out into a board.
+/// dice: The dice which were rolled.
+/// faces: The face shown in each cell.
+fn to_board(dice: &DiceSet, faces: &[String]) -> Result<Board, BoardError> {
+    Board::square(faces.chunks(dice.cols.max(1))
+        .map(<[String]>::to_vec)
+        .collect())
+}
+
+/// Settings for searching for high scoring boards.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnnealOptions {
+    /// How many tile swaps to try.
+    pub iterations: usize,
+
+    /// How willing the search is to accept a worse board at the start, in points.
+    /// The temperature cools to nothing by the last iteration.
+    pub temperature: f64,
+}
+
+impl Default for AnnealOptions {
+    fn default() -> Self {
+        Self { iterations: 2000, temperature: 2000.0 }
+    }
+}
+
+/// The scores and word counts of many random boards.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ScoreDistribution {
+    /// The total score of every sampled board, from lowest to highest.
+    pub scores: Vec<u32>,
+
+    /// The word count of every sampled board, from lowest to highest.
+    pub word_counts: Vec<usize>,
+
+    /// The average total score.
+    pub mean_score: f64,
+
+    /// The average word count.
+    pub mean_words: f64,
+}
+
+impl ScoreDistribution {
+    /// Returns the total score which the given share of boards don't exceed.
+    /// percentile: The share of boards, from 0 to 100.
+    pub fn score_percentile(&self, percentile: f64) -> Option<u32> {
+        self.scores.get(percentile_index(self.scores.len(), percentile)?).copied()
+    }
+
+    /// Returns the word count which the given share of boards don't exceed.
+    /// percentile: The share of boards, from 0 to 100.
+    pub fn words_percentile(&self, percentile: f64) -> Option<usize> {
+        self.word_counts.get(percentile_index(self.word_counts.len(), percentile)?).copied()
+    }
+}
+
+/// Returns the index of a percentile in a sorted list, using the nearest rank.
+/// length: The length of the list.
+/// percentile: The share of the list, from 0 to 100.
+fn percentile_index(length: usize, percentile: f64) -> Option<usize> {
+    if length == 0 {
+        return None;
+    }
+
+    let rank = (percentile.clamp(0.0, 100.0) / 100.0 * length as f64).ceil() as usize;
+    Some(rank.saturating_sub(1).min(length - 1))
+}
+
+impl Solver {
+    /// Estimates the scores and word counts of boards rolled from a set of dice.
+    /// Each sample is a fresh board, solved in full.
+    /// dice: The dice to roll.
+    /// samples: The number of boards to roll.
+    /// options: The options to solve with.
+    /// rng: The source of randomness.
+    pub fn estimate_scores(
+        &self,
+        dice: &DiceSet,
+        samples: usize,
+        options: &SolveOptions,
+        rng: &mut impl Rng
+    ) -> ScoreDistribution {
+        let options = SolveOptions { max_results: None, ..options.clone() };
+
+        let mut distribution = ScoreDistribution::default();
+        for _ in 0..samples {
+            let Ok(board) = generate_board(dice, rng) else {
+                break;
+            };
+
+            let scored = self.score_words(&board, &options);
+            distribution.scores.push(scored.total_score);
+            distribution.word_counts.push(scored.words.len());
+        }
+
+        distribution.scores.sort_unstable();
+        distribution.word_counts.sort_unstable();
+
+        let count = distribution.scores.len().max(1) as f64;
+        distribution.mean_score = distribution.scores.iter().map(|&score| f64::from(score)).sum::<f64>() / count;
+        distribution.mean_words = distribution.word_counts.iter().sum::<usize>() as f64 / count;
+
+        distribution
+    }
+
+    /// Searches for the board with the highest total score, by simulated annealing.
+    /// Each iteration replaces a random tile with a random candidate. Better boards are
+    /// always kept, and worse boards are sometimes kept while the search is still hot,
+    /// so it can escape from local maximums.
+    /// Returns the best board seen, along with its total score.
+    /// start: The board to start from.
+    /// candidates: The letters tiles may hold.
+    /// options: The options to solve with.
+    /// anneal: How the search is run.
+    /// rng: The source of randomness.
+    pub fn optimize_board(
+        &self,
+        start: &Board,
+        candidates: &[&str],
+        options: &SolveOptions,
+        anneal: &AnnealOptions,
+        rng: &mut impl Rng
+    ) -> (Board, u32) {
+        let options = SolveOptions { max_results: None, ..options.clone() };
+        let candidates: Vec<String> = candidates.iter()
+            .map(|letters| letters.trim().to_lowercase())
+            .filter(|letters| !letters.is_empty() && letters != BLOCKED_CELL)
+            .collect();
+        let playable: Vec<usize> = (0..start.len())
+            .filter(|&index| !start.is_blocked(index))
+            .collect();
+
+        let mut current = start.clone();
+        let mut current_score = self.score_words(&current, &options).total_score;
+        let mut best = (current.clone(), current_score);
+        if candidates.is_empty() || playable.is_empty() {
+            return best;
+        }
+
+        for iteration in 0..anneal.iterations {
+            let index = playable[rng.below(playable.len())];
+            let letters = candidates[rng.below(candidates.len())].clone();
+            let previous = current.cell(index).to_string();
+            current.set_cell(index, letters);
+
+            let score = self.score_words(&current, &options).total_score;
+            let change = f64::from(score) - f64::from(current_score);
+            let temperature = anneal.temperature * (1.0 - iteration as f64 / anneal.iterations as f64);
+
+            let accept = change >= 0.0
+                || (temperature > 0.0 && unit(rng) < (change / temperature).exp());
+            if accept {
+                current_score = score;
+                if score > best.1 {
+                    best = (current.clone(), score);
+                }
+            } else {
+                current.set_cell(index, previous);
+            }
+        }
+
+        best
+    }
+
+    /// Generates a board which meets a set of targets.
+    /// Each rolled board is repaired by rerolling single dice, keeping rerolls which don't
+    /// move it further from the targets. Fresh boards are rolled until one meets the targets.
+    /// Returns nothing if no board met the targets in time.
+    /// dice: The dice to roll.
+    /// targets: The qualities the board must have.
+    /// options: The options to solve with.
+    /// rng: The source of randomness.
+    pub fn generate_with_targets(
+        &self,
+        dice: &DiceSet,
+        targets: &BoardTargets,
+        options: &SolveOptions,
+        rng: &mut impl Rng
+    ) -> Option<Board> {
+        let options = SolveOptions { max_results: None, ..options.clone() };
+        let shortfall = |faces: &[String]| {
+            let board = to_board(dice, faces).ok()?;
+            let shortfall = targets.shortfall(&self.score_words(&board, &options));
+            Some((board, shortfall))
+        };
+
+        for _ in 0..targets.attempts {
+            let (order, mut faces) = roll(dice, rng);
+            let (mut board, mut best) = shortfall(&faces)?;
+
+            for _ in 0..targets.repairs {
+                if best == 0 {
+                    break;
+                }
+
+                let cell = rng.below(faces.len());
+                let die = &dice.dice[order[cell]];
+                let previous = std::mem::replace(&mut faces[cell], die[rng.below(die.len())].clone());
+
+                match shortfall(&faces) {
+                    Some((repaired, repaired_shortfall)) if repaired_shortfall <= best => {
+                        board = repaired;
+                        best = repaired_shortfall;
+                    }
+                    _ => faces[cell] = previous,
+                }
+            }
+
+            if best == 0 {
+                return Some(board);
+            }
+        }
+
+        None
+    }
+}
@@ -0,0 +1,118 @@
+use std::collections::{HashMap, HashSet};
+use crate::board::Board;
+use crate::options::{SolveOptions, SortOrder};
+use crate::scoring::ScoredWord;
+use crate::solver::{Solver, WordMatch};
+
+/// The outcome of a single submitted word.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    /// The word counts, and is scored.
+    Accepted,
+
+    /// The word was already submitted.
+    Duplicate,
+
+    /// The word is shorter than the minimum length.
+    TooShort,
+
+    /// The word isn't in the dictionary.
+    NotAWord,
+
+    /// The word is in the dictionary, but can't be traced on the board.
+    NotOnBoard,
+}
+
+/// A submitted word, along with how it was graded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GradedAnswer {
+    /// The word, as submitted.
+    pub word: String,
+
+    pub verdict: Verdict,
+
+    /// The points the word earned; 0 unless it was accepted.
+    pub score: u32,
+
+    /// The tile path of the word, if it was accepted.
+    pub path: Option<Vec<usize>>,
+}
+
+/// A player's graded submissions for a board.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Graded {
+    /// Every submission, in the order it was submitted.
+    pub answers: Vec<GradedAnswer>,
+
+    /// The points earned by the accepted words.
+    pub total_score: u32,
+
+    /// The points earned by finding every word on the board.
+    pub optimal_score: u32,
+
+    /// The share of the optimal score which was earned, from 0 to 100.
+    pub percentage: f64,
+
+    /// The words the player didn't find, most valuable first.
+    pub missed: Vec<ScoredWord>,
+}
+
+impl Solver {
+    /// Grades the words a player submitted for a board.
+    /// Words are checked against the board as solved with the given options, so excluded
+    /// words are reported as not being on the board.
+    /// board: The game board.
+    /// submissions: The submitted words, in the order they were submitted.
+    /// options: The options to solve with.
+    pub fn grade_answers(&self, board: &Board, submissions: &[&str], options: &SolveOptions) -> Graded {
+        let options = SolveOptions { max_results: None, order: SortOrder::Score, ..options.clone() };
+        let matches = self.find_matches(board, &options);
+        let by_word: HashMap<&str, &WordMatch> = matches.iter()
+            .map(|found| (found.word.as_str(), found))
+            .collect();
+
+        let mut seen = HashSet::new();
+        let answers: Vec<GradedAnswer> = submissions.iter()
+            .map(|submission| {
+                let word = submission.trim().to_lowercase();
+                let found = by_word.get(word.as_str());
+
+                let verdict = if !seen.insert(word.clone()) {
+                    Verdict::Duplicate
+                } else if word.chars().count() < options.min_length {
+                    Verdict::TooShort
+                } else if !self.dictionary().is_word(&word) {
+                    Verdict::NotAWord
+                } else if found.is_none() {
+                    Verdict::NotOnBoard
+                } else {
+                    Verdict::Accepted
+                };
+
+                let accepted = verdict == Verdict::Accepted;
+                GradedAnswer {
+                    word: submission.to_string(),
+                    verdict,
+                    score: found.filter(|_| accepted).map_or(0, |found| found.score),
+                    path: found.filter(|_| accepted).map(|found| found.path.clone()),
+                }
+            })
+            .collect();
+
+        let total_score = answers.iter().map(|answer| answer.score).fold(0, u32::saturating_add);
+        let optimal_score = matches.iter().map(|found| found.score).fold(0, u32::saturating_add);
+        let percentage = if optimal_score == 0 {
+            100.0
+        } else {
+            f64::from(total_score) * 100.0 / f64::from(optimal_score)
+        };
+
+        // The matches are already ordered by score.
+        let missed = matches.into_iter()
+            .filter(|found| !seen.contains(&found.word))
+            .map(|found| ScoredWord { word: found.word, score: found.score })
+            .collect();
+
+        Graded { answers, total_score, optimal_score, percentage, missed }
+    }
+}
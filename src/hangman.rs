@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+use crate::solver::Solver;
+
+/// The words which fit a game of Hangman, and the letter to guess next.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HangmanHint {
+    /// Every word which could still be the answer, alphabetically.
+    pub candidates: Vec<String>,
+
+    /// The unguessed letter found in the most candidates.
+    /// Ties are broken alphabetically; `None` when no letter is left to guess.
+    pub best_letter: Option<char>,
+}
+
+impl Solver {
+    /// Finds the words which fit a game of Hangman, and the best letter to guess next.
+    /// Letters which have been revealed can't hide behind a blank, since guessing a
+    /// letter reveals every copy of it.
+    /// pattern: The revealed letters, with "_" for blanks, such as "_a__".
+    /// excluded: The letters which were guessed but aren't in the word.
+    pub fn hangman(&self, pattern: &str, excluded: &[char]) -> HangmanHint {
+        let pattern = pattern.to_lowercase();
+        let excluded: Vec<char> = excluded.iter()
+            .flat_map(|letter| letter.to_lowercase())
+            .collect();
+
+        let blanks: Vec<bool> = pattern.chars()
+            .map(|letter| matches!(letter, '_' | '.' | '?'))
+            .collect();
+        let guessed: Vec<char> = pattern.chars()
+            .zip(&blanks)
+            .filter(|&(_, &blank)| !blank)
+            .map(|(letter, _)| letter)
+            .chain(excluded.iter().copied())
+            .collect();
+
+        let candidates: Vec<String> = self.match_pattern(&pattern)
+            .into_iter()
+            .filter(|word| word.chars()
+                .zip(&blanks)
+                .all(|(letter, &blank)| !blank || !guessed.contains(&letter)))
+            .collect();
+
+        // Count each letter once per word, since one guess reveals every copy.
+        let mut counts: HashMap<char, usize> = HashMap::new();
+        for word in &candidates {
+            let mut letters: Vec<char> = word.chars()
+                .zip(&blanks)
+                .filter(|&(_, &blank)| blank)
+                .map(|(letter, _)| letter)
+                .collect();
+            letters.sort_unstable();
+            letters.dedup();
+
+            for letter in letters {
+                *counts.entry(letter).or_default() += 1;
+            }
+        }
+
+        let best_letter = counts.into_iter()
+            .max_by(|a, b| a.1.cmp(&b.1).then(b.0.cmp(&a.0)))
+            .map(|(letter, _)| letter);
+
+        HangmanHint { candidates, best_letter }
+    }
+}
@@ -0,0 +1,64 @@
+use crate::board::Board;
+use crate::options::SolveOptions;
+use crate::solver::Solver;
+
+/// A clue about a word, revealing part of it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Hint {
+    /// The length of the word, in letters.
+    Length(usize),
+
+    /// The first letter of the word.
+    FirstLetter(char),
+
+    /// The index of the tile the word starts on.
+    StartingTile(usize),
+
+    /// The tile indices of the first part of the word's path.
+    PartialPath(Vec<usize>),
+}
+
+/// A word which hasn't been found yet, along with its hints.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WordHints {
+    pub word: String,
+
+    /// The hints for the word, from least to most revealing.
+    pub hints: Vec<Hint>,
+}
+
+impl Solver {
+    /// Creates hints for the words on a board which haven't been found yet.
+    /// Each word gets a length, first letter, starting tile, then a partial path hint,
+    /// so they can be revealed one at a time.
+    /// Words are returned in the configured sort order.
+    /// board: The game board.
+    /// found: The words which were already found.
+    /// options: The options to solve with.
+    pub fn hints(&self, board: &Board, found: &[&str], options: &SolveOptions) -> Vec<WordHints> {
+        let mut options = options.clone();
+        options.exclude.extend(found.iter().map(|word| word.trim().to_lowercase()));
+
+        self.find_matches(board, &options)
+            .into_iter()
+            .map(|found| {
+                let mut hints = vec![Hint::Length(found.length)];
+                if let Some(letter) = found.word.chars().next() {
+                    hints.push(Hint::FirstLetter(letter));
+                }
+
+                if let Some(&start) = found.path.first() {
+                    hints.push(Hint::StartingTile(start));
+                }
+
+                // Reveal about half of the path, but never all of it.
+                let revealed = (found.path.len() / 2).max(2).min(found.path.len().saturating_sub(1));
+                if revealed > 1 {
+                    hints.push(Hint::PartialPath(found.path[..revealed].to_vec()));
+                }
+
+                WordHints { word: found.word, hints }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,1864 @@
+use std::any::Any;
+use std::error::Error;
+use std::panic::{self, AssertUnwindSafe};
+use std::ffi::c_void;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+use std::sync::atomic::{AtomicI64, Ordering};
+use jni::{JNIEnv, JavaVM, NativeMethod};
+use lazy_static::lazy_static;
+use log::{LevelFilter, Log, Metadata, Record};
+use jni::objects::{
+    GlobalRef, JByteArray, JByteBuffer, JCharArray, JClass, JIntArray, JMethodID, JObject, JObjectArray, JString, JValue,
+    ReleaseMode
+};
+use jni::signature::{Primitive, ReturnType};
+use jni::sys::{jboolean, jint, jlong, jobjectArray, jstring, JNI_ERR, JNI_FALSE, JNI_VERSION_1_8};
+use crate::{
+    clear_dictionary, dictionary_info, dictionary_names, generate_seeded_board, hints, is_valid_word,
+    load_compiled_dictionary, load_compiled_dictionary_bytes, load_dictionary, load_dictionary_contents,
+    load_named_dictionary, reload_dictionary, solve_flat, solve_many, solve_matches, solve_paths, solve_rack,
+    solve_scored, solve_words, Board, BoardError, CancelToken, DiceSet, Graded, LetterValues, ScoringMode, SolveOptions, Solver,
+    SortOrder, Topology, Verdict, WordMatch, WordPaths
+};
+use crate::json;
+use crate::pool::ThreadPool;
+
+/// The exception thrown when a native method fails.
+const EXCEPTION_CLASS: &str = "java/lang/RuntimeException";
+
+/// The Java class describing a found word.
+/// Constructed with `(String word, int score, int length, int[] path)`.
+const WORD_RESULT_CLASS: &str = "moe/seikimo/magixbot/features/game/type/WordResult";
+
+/// The system property, or environment variable, naming an extra class to register
+/// every native method on, such as `com.example.WordHunt`.
+const CLASS_PROPERTY: (&str, &str) = ("word.hunt.class", "WORD_HUNT_CLASS");
+
+/// The system property, or environment variable, naming the class constructed for found words.
+const RESULT_CLASS_PROPERTY: (&str, &str) = ("word.hunt.resultClass", "WORD_HUNT_RESULT_CLASS");
+
+/// Forwards log records to a Java logger.
+struct JavaLogger {
+    /// The JVM and logger to forward records to, if one has been set.
+    sink: RwLock<Option<Arc<(JavaVM, GlobalRef)>>>,
+}
+
+impl Log for JavaLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        // The JNI crate logs its own calls, which would recurse forever.
+        metadata.target().starts_with(env!("CARGO_CRATE_NAME"))
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        // Don't hold the lock while calling Java, in case the logger sets a new logger.
+        let Some(sink) = self.sink.read().unwrap().clone() else {
+            return;
+        };
+        let (vm, logger) = sink.as_ref();
+        let Ok(mut env) = vm.attach_current_thread() else {
+            return;
+        };
+
+        // Java can't be called while an exception is pending, so the record is dropped.
+        if env.exception_check().unwrap_or(true) {
+            return;
+        }
+
+        let result = env.with_local_frame(2, |env| -> jni::errors::Result<()> {
+            let target = env.new_string(record.target())?;
+            let message = env.new_string(record.args().to_string())?;
+            env.call_method(
+                logger,
+                "log",
+                "(ILjava/lang/String;Ljava/lang/String;)V",
+                &[JValue::Int(record.level() as jint), JValue::Object(&target), JValue::Object(&message)]
+            )?;
+            Ok(())
+        });
+
+        if result.is_err() && env.exception_check().unwrap_or(false) {
+            let _ = env.exception_clear();
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// A job run on the native thread pool, given the JNI environment of its thread.
+type Job = Box<dyn FnOnce(&mut JNIEnv) + Send>;
+
+/// Starts the native thread pool which runs asynchronous solves, so simultaneous games
+/// share threads rather than each starting their own.
+/// Each thread is attached to the JVM for as long as it runs.
+/// vm: The JVM to attach the threads to.
+/// threads: The number of threads.
+fn start_pool(vm: JavaVM, threads: usize) -> NativeResult<ThreadPool> {
+    let pool = ThreadPool::with_start("word-hunt-solver", threads, None, move || {
+        // Daemon threads don't keep the JVM from exiting.
+        let _ = vm.attach_current_thread_as_daemon();
+    })?;
+
+    Ok(pool)
+}
+
+/// The signature of the constructor of the class constructed for found words.
+const WORD_RESULT_CONSTRUCTOR: &str = "(Ljava/lang/String;II[I)V";
+
+/// The class constructed for found words, when it isn't the default.
+static RESULT_CLASS: OnceLock<String> = OnceLock::new();
+
+/// The classes and method IDs resolved when the library is loaded.
+static CACHE: OnceLock<JavaCache> = OnceLock::new();
+
+/// Classes and method IDs resolved once, so native methods don't have to look them up
+/// on every call. Native threads also can't find application classes on their own,
+/// since they only see the system class loader.
+struct JavaCache {
+    string_class: GlobalRef,
+    string_array_class: GlobalRef,
+    object_class: GlobalRef,
+    int_array_class: GlobalRef,
+
+    /// The class constructed for found words, and its constructor.
+    /// Missing if the class couldn't be found when the library was loaded.
+    result_class: Option<(GlobalRef, JMethodID)>,
+}
+
+impl JavaCache {
+    /// Resolves every cached class and method ID.
+    /// env: The JNI environment.
+    fn resolve(env: &mut JNIEnv) -> NativeResult<Self> {
+        let mut global_class = |name: &str| -> NativeResult<GlobalRef> {
+            let class = env.find_class(name)?;
+            Ok(env.new_global_ref(class)?)
+        };
+
+        let string_class = global_class("java/lang/String")?;
+        let string_array_class = global_class("[Ljava/lang/String;")?;
+        let object_class = global_class("java/lang/Object")?;
+        let int_array_class = global_class("[I")?;
+
+        let result_class = env.find_class(result_class_name())
+            .and_then(|class| {
+                let constructor = env.get_method_id(&class, "<init>", WORD_RESULT_CONSTRUCTOR)?;
+                Ok((env.new_global_ref(class)?, constructor))
+            })
+            .ok();
+
+        // Projects without a result class just can't call `findWordResults`.
+        if result_class.is_none() && env.exception_check()? {
+            env.exception_clear()?;
+        }
+
+        Ok(Self { string_class, string_array_class, object_class, int_array_class, result_class })
+    }
+}
+
+/// The native thread pool, once it has been started.
+static POOL: Mutex<Option<ThreadPool>> = Mutex::new(None);
+
+/// Forwards the library's log records to the logger set by Java.
+static LOGGER: JavaLogger = JavaLogger { sink: RwLock::new(None) };
+
+lazy_static! {
+    /// The solvers created by Java, by handle.
+    static ref SOLVERS: Mutex<HashMap<jlong, Arc<SolverHandle>>> = Mutex::new(HashMap::new());
+
+    /// The dice sets registered by Java, by name.
+    static ref DICE_SETS: Mutex<HashMap<String, DiceSet>> = Mutex::new(HashMap::new());
+}
+
+/// A solver created by Java, along with the solves it is running.
+struct SolverHandle {
+    solver: Solver,
+    running: Mutex<Vec<CancelToken>>,
+}
+
+impl SolverHandle {
+    /// Creates a handle for a solver.
+    /// solver: The solver.
+    fn new(solver: Solver) -> Self {
+        Self { solver, running: Mutex::new(Vec::new()) }
+    }
+
+    /// Starts tracking a solve.
+    /// Returns the token which cancels it.
+    fn start(&self) -> CancelToken {
+        let token = CancelToken::new();
+        self.running.lock().unwrap().push(token.clone());
+        token
+    }
+
+    /// Stops tracking a solve.
+    /// token: The token of the solve.
+    fn finish(&self, token: &CancelToken) {
+        self.running.lock().unwrap().retain(|running| running != token);
+    }
+
+    /// Cancels every running solve.
+    fn cancel(&self) {
+        for token in self.running.lock().unwrap().drain(..) {
+            token.cancel();
+        }
+    }
+}
+
+/// The handle given to the next solver created by Java.
+static NEXT_HANDLE: AtomicI64 = AtomicI64::new(1);
+
+/// The result of the body of a native method.
+type NativeResult<T> = Result<T, Box<dyn Error>>;
+
+/// Runs the body of a native method, turning errors and panics into Java exceptions.
+/// Exceptions already thrown by the JVM are left for Java to handle.
+/// Returns the fallback value when the body fails.
+/// env: The JNI environment.
+/// fallback: The value returned to Java when an exception is thrown.
+/// body: The body of the native method.
+fn guard<'local, T>(
+    env: &mut JNIEnv<'local>,
+    fallback: T,
+    body: impl FnOnce(&mut JNIEnv<'local>) -> NativeResult<T>
+) -> T {
+    let message = match panic::catch_unwind(AssertUnwindSafe(|| body(env))) {
+        Ok(Ok(value)) => return value,
+        Ok(Err(error)) => error.to_string(),
+        Err(payload) => panic_message(payload.as_ref()),
+    };
+
+    if !env.exception_check().unwrap_or(false) {
+        // There's nothing more to do if even the exception can't be thrown.
+        let _ = env.throw_new(EXCEPTION_CLASS, message);
+    }
+
+    fallback
+}
+
+/// Extracts the message from a caught panic.
+/// payload: The value the panic was raised with.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "The native library panicked.".to_string()
+    }
+}
+
+/// Loads all Scrabble! words.
+/// Exported with its signature, since `loadWords` is overloaded.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+/// dictionary_path: The path to the dictionary.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_MagixBot_loadWords__Ljava_lang_String_2(
+    mut env: JNIEnv, _class: JClass,
+    dictionary_path: JString
+) {
+    guard(&mut env, (), |env| {
+        // Read the dictionary path.
+        let dictionary_path: String = env.get_string(&dictionary_path)
+            .map_err(|error| format!("Couldn't get the dictionary path: {error}"))?
+            .into();
+
+        // Load the dictionary.
+        load_dictionary(dictionary_path);
+        Ok(())
+    })
+}
+
+/// Loads all words of a dictionary file into a named dictionary, such as one per language.
+/// Named dictionaries are kept apart from the default dictionary.
+/// Throws a `RuntimeException` if the file can't be read.
+/// Exported with its signature, since `loadWords` is overloaded.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+/// name: The name of the dictionary, such as "en" or "fr".
+/// dictionary_path: The path to the dictionary.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_MagixBot_loadWords__Ljava_lang_String_2Ljava_lang_String_2(
+    mut env: JNIEnv, _class: JClass,
+    name: JString, dictionary_path: JString
+) {
+    guard(&mut env, (), |env| {
+        let name: String = env.get_string(&name)?.into();
+        let dictionary_path: String = env.get_string(&dictionary_path)?.into();
+        Ok(load_named_dictionary(&name, dictionary_path)?)
+    })
+}
+
+/// Loads all Scrabble! words from the contents of a dictionary file,
+/// such as one read from an app's assets or a jar's resources.
+/// Throws a `RuntimeException` if the contents aren't UTF-8.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+/// contents: The contents of the dictionary file.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_MagixBot_loadWordsFromBytes(
+    mut env: JNIEnv, _class: JClass,
+    contents: JByteArray
+) {
+    guard(&mut env, (), |env| {
+        if contents.is_null() {
+            return Err("The dictionary contents are null.".into());
+        }
+
+        let contents = env.convert_byte_array(&contents)?;
+        let contents = String::from_utf8(contents)
+            .map_err(|_| "The dictionary contents aren't UTF-8.")?;
+
+        load_dictionary_contents(&contents, "bytes".to_string());
+        Ok(())
+    })
+}
+
+/// Loads a compiled dictionary file, which loads much faster than a list of words.
+/// Throws a `RuntimeException` if the file can't be read or isn't a compiled dictionary.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+/// dictionary_path: The path to the compiled dictionary.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_MagixBot_loadCompiledDictionary(
+    mut env: JNIEnv, _class: JClass,
+    dictionary_path: JString
+) {
+    guard(&mut env, (), |env| {
+        let dictionary_path: String = env.get_string(&dictionary_path)?.into();
+        Ok(load_compiled_dictionary(dictionary_path)?)
+    })
+}
+
+/// Loads a compiled dictionary from its contents, such as one read from an app's
+/// assets or a jar's resources.
+/// Throws a `RuntimeException` if the contents aren't a compiled dictionary.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+/// contents: The contents of the compiled dictionary.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_MagixBot_loadCompiledDictionaryFromBytes(
+    mut env: JNIEnv, _class: JClass,
+    contents: JByteArray
+) {
+    guard(&mut env, (), |env| {
+        if contents.is_null() {
+            return Err("The dictionary contents are null.".into());
+        }
+
+        let contents = env.convert_byte_array(&contents)?;
+        Ok(load_compiled_dictionary_bytes(&contents, "bytes".to_string())?)
+    })
+}
+
+/// Forwards the library's log records, such as dictionary load warnings and solve
+/// diagnostics, to a Java logger with a `void log(int level, String target, String message)`
+/// method, so they can be passed on to SLF4J or `java.util.logging`.
+/// Levels are 1 for errors, 2 for warnings, 3 for information, 4 for debugging and 5 for tracing.
+/// The logger may be null to stop forwarding records.
+/// Records can't be forwarded if another Rust logger was already installed in the process.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+/// logger: The object to forward records to.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_MagixBot_setLogger(
+    mut env: JNIEnv, _class: JClass,
+    logger: JObject
+) {
+    guard(&mut env, (), |env| {
+        if logger.is_null() {
+            *LOGGER.sink.write().unwrap() = None;
+            log::set_max_level(LevelFilter::Off);
+            return Ok(());
+        }
+
+        let sink = (env.get_java_vm()?, env.new_global_ref(&logger)?);
+        *LOGGER.sink.write().unwrap() = Some(Arc::new(sink));
+
+        // Only the first call installs the logger; later calls just replace its sink.
+        let _ = log::set_logger(&LOGGER);
+        log::set_max_level(LevelFilter::Trace);
+        Ok(())
+    })
+}
+
+/// Removes every word from the dictionary.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_MagixBot_clearDictionary(
+    mut env: JNIEnv, _class: JClass
+) {
+    guard(&mut env, (), |_| {
+        clear_dictionary();
+        Ok(())
+    })
+}
+
+/// Replaces the dictionary with the words of another file, all at once.
+/// Searches already running finish with the old dictionary.
+/// Throws a `RuntimeException`, keeping the current dictionary, if the file can't be read.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+/// dictionary_path: The path to the new dictionary.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_MagixBot_reloadDictionary(
+    mut env: JNIEnv, _class: JClass,
+    dictionary_path: JString
+) {
+    guard(&mut env, (), |env| {
+        let dictionary_path: String = env.get_string(&dictionary_path)?.into();
+        reload_dictionary(dictionary_path.clone())
+            .map_err(|error| format!("Couldn't reload the dictionary from {dictionary_path}: {error}"))?;
+        Ok(())
+    })
+}
+
+/// Native method to count the words in the loaded dictionary.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_MagixBot_getWordCount(
+    mut env: JNIEnv, _class: JClass
+) -> jint {
+    guard(&mut env, 0, |_| Ok(dictionary_info().word_count.min(jint::MAX as usize) as jint))
+}
+
+/// Native method to describe the loaded dictionary, such as where it was loaded from.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_MagixBot_getDictionaryInfo(
+    mut env: JNIEnv, _class: JClass
+) -> jstring {
+    guard(&mut env, JObject::null().into_raw(), |env| {
+        Ok(env.new_string(dictionary_info().to_string())?.into_raw())
+    })
+}
+
+/// Native method to get the version of the native library, such as "0.1.0",
+/// so Java can check it loaded the library it was built against.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_MagixBot_getNativeVersion(
+    mut env: JNIEnv, _class: JClass
+) -> jstring {
+    guard(&mut env, JObject::null().into_raw(), |env| {
+        Ok(env.new_string(env!("CARGO_PKG_VERSION"))?.into_raw())
+    })
+}
+
+/// Native method to describe what the native library supports, as JSON like
+/// `{"version":"0.1.0","features":["parallel","server"],"scoring_modes":[...],"topologies":[...],
+/// "dice_sets":[...],"dictionaries":[...]}`. Dice sets include those registered with
+/// `registerDiceSet`, and dictionaries list the named dictionaries, such as one per language.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_MagixBot_getCapabilities(
+    mut env: JNIEnv, _class: JClass
+) -> jstring {
+    guard(&mut env, JObject::null().into_raw(), |env| {
+        Ok(env.new_string(capabilities())?.into_raw())
+    })
+}
+
+/// Describes what the native library supports, as returned by `getCapabilities`.
+pub(crate) fn capabilities() -> String {
+    let features = [
+        ("parallel", cfg!(feature = "parallel")),
+        ("rayon", cfg!(feature = "rayon")),
+        ("capi", cfg!(feature = "capi")),
+        ("python", cfg!(feature = "python")),
+        ("wasm", cfg!(feature = "wasm")),
+        ("server", cfg!(feature = "server")),
+        ("tokio", cfg!(feature = "tokio")),
+    ];
+    let features = features.into_iter().filter(|&(_, enabled)| enabled).map(|(feature, _)| feature);
+
+    let mut dice_sets = vec!["classic".to_string(), "big".to_string()];
+    let mut registered: Vec<String> = DICE_SETS.lock().unwrap().keys().cloned().collect();
+    registered.sort_unstable();
+    dice_sets.extend(registered);
+
+    format!(
+        "{{\"version\":{},\"features\":{},\"scoring_modes\":{},\"topologies\":{},\
+         \"dice_sets\":{},\"dictionaries\":{}}}",
+        json::quote(env!("CARGO_PKG_VERSION")),
+        json::array(features, json::quote),
+        json::array(ScoringMode::NAMES, json::quote),
+        json::array(Topology::NAMES, json::quote),
+        json::array(&dice_sets, |name| json::quote(name)),
+        json::array(&dictionary_names(), |name| json::quote(name))
+    )
+}
+
+/// Native method to check if a word is in the loaded dictionary.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+/// word: The word, in any case.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_features_game_type_WordHunt_isValidWord(
+    mut env: JNIEnv, _class: JClass,
+    word: JString
+) -> jboolean {
+    guard(&mut env, JNI_FALSE, |env| {
+        let word: String = env.get_string(&word)?.into();
+        Ok(is_valid_word(&word).into())
+    })
+}
+
+/// Native method to find all valid Scrabble! words which can be spelled from a rack of letters.
+/// Requires the dictionary to be initialized.
+/// Throws a `RuntimeException` if the minimum length is negative.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+/// letters: The letters of the rack; "*" and "?" are blanks.
+/// min_length: The shortest a word may be, in letters.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_features_game_type_WordHunt_solveAnagrams(
+    mut env: JNIEnv, _class: JClass,
+    letters: JString, min_length: jint
+) -> jobjectArray {
+    guard(&mut env, JObject::null().into_raw(), |env| {
+        let letters: String = env.get_string(&letters)?.into();
+        let min_length = usize::try_from(min_length).map_err(|_| "The minimum length can't be negative.")?;
+
+        let options = SolveOptions { min_length, ..Default::default() };
+        let words = solve_rack(&letters, &options);
+        Ok(to_string_array(env, &words)?.into_raw())
+    })
+}
+
+/// Native method to find all valid Scrabble! words in a 2D board.
+/// Requires the dictionary to be initialized.
+/// Throws a `RuntimeException` if the board is invalid.
+/// Exported with its signature, since `findWords` is overloaded.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+/// board: A 2D array of characters.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_features_game_type_WordHunt_findWords___3_3Ljava_lang_String_2(
+    mut env: JNIEnv, _class: JClass,
+    java_board: JObjectArray
+) -> jobjectArray {
+    guard(&mut env, JObject::null().into_raw(), |env| {
+        // Convert Java 2D array to Rust Vec<Vec<String>>
+        let board = read_board(env, &java_board)?;
+
+        // Create WordHunt instance and find words
+        let words = solve_words(board)?;
+
+        // Convert result back to Java String array
+        Ok(to_string_array(env, &words)?.into_raw())
+    })
+}
+
+/// Native method to create a solver, holding a snapshot of the loaded dictionary.
+/// Solves using the handle skip copying the dictionary and locking it for every call.
+/// Reloading the dictionary doesn't affect existing solvers.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_features_game_type_WordHunt_createSolver(
+    mut env: JNIEnv, _class: JClass
+) -> jlong {
+    guard(&mut env, 0, |_| {
+        let handle = NEXT_HANDLE.fetch_add(1, Ordering::Relaxed);
+        SOLVERS.lock().unwrap().insert(handle, Arc::new(SolverHandle::new(Solver::new())));
+        Ok(handle)
+    })
+}
+
+/// Native method to destroy a solver.
+/// Solves already running with the solver finish normally.
+/// Destroying an unknown handle does nothing.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+/// handle: The handle of the solver.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_features_game_type_WordHunt_destroySolver(
+    mut env: JNIEnv, _class: JClass,
+    handle: jlong
+) {
+    guard(&mut env, (), |_| {
+        SOLVERS.lock().unwrap().remove(&handle);
+        Ok(())
+    })
+}
+
+/// Native method to find all valid Scrabble! words in a 2D board, using a solver.
+/// Throws a `RuntimeException` if the handle is unknown or the board is invalid.
+/// Exported with its signature, since `findWords` is overloaded.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+/// handle: The handle of the solver.
+/// board: A 2D array of characters.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_features_game_type_WordHunt_findWords__J_3_3Ljava_lang_String_2(
+    mut env: JNIEnv, _class: JClass,
+    handle: jlong, java_board: JObjectArray
+) -> jobjectArray {
+    guard(&mut env, JObject::null().into_raw(), |env| {
+        let solver = find_solver(handle)?;
+        let board = Board::square(read_board(env, &java_board)?)?;
+
+        let cancel = solver.start();
+        let options = SolveOptions { cancel: Some(cancel.clone()), ..Default::default() };
+        let words = solver.solver.find_all_words(&board, &options);
+        solver.finish(&cancel);
+
+        Ok(to_string_array(env, &words)?.into_raw())
+    })
+}
+
+/// Native method to start the native thread pool which runs asynchronous solves.
+/// Replaces the running pool, if there is one, after its queued solves have run.
+/// Without this, a pool with a thread per processor is started by the first asynchronous solve.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+/// threads: The number of threads, or 0 for a thread per processor.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_features_game_type_WordHunt_initThreadPool(
+    mut env: JNIEnv, _class: JClass,
+    threads: jint
+) {
+    guard(&mut env, (), |env| {
+        let threads = match usize::try_from(threads) {
+            Ok(0) => ThreadPool::default_threads(),
+            Ok(threads) => threads,
+            Err(_) => return Err("The number of threads can't be negative.".into()),
+        };
+
+        let pool = start_pool(env.get_java_vm()?, threads)?;
+        *POOL.lock().unwrap() = Some(pool);
+        Ok(())
+    })
+}
+
+/// Native method to cancel every solve running with a solver.
+/// Cancelled solves return, or report, the words found so far.
+/// Cancelling an unknown handle does nothing, since the solver may already be destroyed.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+/// handle: The handle of the solver.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_features_game_type_WordHunt_cancelSolve(
+    mut env: JNIEnv, _class: JClass,
+    handle: jlong
+) {
+    guard(&mut env, (), |_| {
+        if let Ok(solver) = find_solver(handle) {
+            solver.cancel();
+        }
+
+        Ok(())
+    })
+}
+
+/// Finds a solver created by Java.
+/// handle: The handle of the solver.
+fn find_solver(handle: jlong) -> NativeResult<Arc<SolverHandle>> {
+    SOLVERS.lock().unwrap()
+        .get(&handle)
+        .cloned()
+        .ok_or_else(|| format!("There is no solver with the handle {handle}.").into())
+}
+
+/// Native method to find all valid Scrabble! words in a 2D board, using a named dictionary.
+/// Throws a `RuntimeException` if there is no dictionary with the name, or the board is invalid.
+/// Exported with its signature, since `findWords` is overloaded.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+/// name: The name of the dictionary, as given to `loadWords`.
+/// board: A 2D array of characters.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_features_game_type_WordHunt_findWords__Ljava_lang_String_2_3_3Ljava_lang_String_2(
+    mut env: JNIEnv, _class: JClass,
+    name: JString, java_board: JObjectArray
+) -> jobjectArray {
+    guard(&mut env, JObject::null().into_raw(), |env| {
+        let name: String = env.get_string(&name)?.into();
+        let solver = Solver::with_named_dictionary(&name)
+            .ok_or_else(|| format!("There is no dictionary named {name}."))?;
+
+        let board = Board::square(read_board(env, &java_board)?)?;
+        let words = solver.find_all_words(&board, &SolveOptions::default());
+        Ok(to_string_array(env, &words)?.into_raw())
+    })
+}
+
+/// Native method to find all valid Scrabble! words in a 2D board, with options.
+/// The options may be null, or an object of any class with the fields
+/// `int minLength`, `int maxResults` (0 for no limit), `int scoringMode` (0 for Word Hunt,
+/// 1 for Boggle, 2 for Scrabble letter values), `int topology` (0 for square, 1 for hex,
+/// 2 for knight's moves), `boolean allowReuse` and `boolean sortByScore`.
+/// Requires the dictionary to be initialized.
+/// Throws a `RuntimeException` if the board or options are invalid.
+/// Exported with its signature, since `findWords` is overloaded.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+/// board: A 2D array of characters.
+/// options: The options to solve with.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_features_game_type_WordHunt_findWords___3_3Ljava_lang_String_2Ljava_lang_Object_2(
+    mut env: JNIEnv, _class: JClass,
+    java_board: JObjectArray, java_options: JObject
+) -> jobjectArray {
+    guard(&mut env, JObject::null().into_raw(), |env| {
+        let (options, topology) = read_options(env, &java_options)?;
+        let board = Board::new(read_board(env, &java_board)?, topology)?;
+
+        let words: Vec<String> = solve_scored(&board, &options).words
+            .into_iter()
+            .map(|found| found.word)
+            .collect();
+        Ok(to_string_array(env, &words)?.into_raw())
+    })
+}
+
+/// Native method to find all valid Scrabble! words in a 2D board, returned as JSON.
+/// The options may be null, or a JSON object with the optional keys `min_length`,
+/// `max_results`, `scoring` (`"wordhunt"`, `"boggle"` or `"scrabble"`), `topology`
+/// (`"square"`, `"hex"` or `"knight"`), `allow_reuse`, `prefix` and `sort_by_score`.
+/// Returns `{"words":[{"word":...,"score":...,"path":[...]}],"total_score":...}`,
+/// where each path lists the tile indices (`row * cols + col`) spelling the word.
+/// Requires the dictionary to be initialized.
+/// Throws a `RuntimeException` if the board or options are invalid.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+/// board: A 2D array of characters.
+/// options: The options to solve with, as JSON.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_features_game_type_WordHunt_findWordsJson(
+    mut env: JNIEnv, _class: JClass,
+    java_board: JObjectArray, java_options: JString
+) -> jstring {
+    guard(&mut env, JObject::null().into_raw(), |env| {
+        let options = if java_options.is_null() {
+            json::Value::Null
+        } else {
+            let options: String = env.get_string(&java_options)?.into();
+            json::parse(&options)?
+        };
+
+        let (options, topology) = json::read_options(&options)?;
+        let board = Board::new(read_board(env, &java_board)?, topology)?;
+
+        let found = solve_matches(&board, &options);
+        Ok(env.new_string(json::word_matches(&found))?.into_raw())
+    })
+}
+
+/// Native method to generate a board by rolling a set of dice.
+/// The same arguments always give the same board, on every platform.
+/// Throws a `RuntimeException` if the dice set is unknown or doesn't fit the size.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+/// rows: The number of rows of the board.
+/// cols: The number of columns of the board.
+/// seed: The seed to roll the dice with.
+/// dice_set: The name of a dice set given to `registerDiceSet`, "classic" for 4x4 Boggle,
+///           "big" for 5x5 Big Boggle, or null for whichever built-in set fits the size.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_features_game_type_WordHunt_generateBoard(
+    mut env: JNIEnv, _class: JClass,
+    rows: jint, cols: jint, seed: jlong, dice_set: JString
+) -> jobjectArray {
+    guard(&mut env, JObject::null().into_raw(), |env| {
+        let name = if dice_set.is_null() {
+            None
+        } else {
+            Some(String::from(env.get_string(&dice_set)?))
+        };
+
+        let registered = name.as_ref().and_then(|name| DICE_SETS.lock().unwrap().get(name).cloned());
+        let dice = match registered {
+            Some(dice) if (dice.rows as jint, dice.cols as jint) != (rows, cols) => return Err(format!(
+                "The {} dice set rolls {}x{} boards, not {rows}x{cols} boards.",
+                name.unwrap_or_default(), dice.rows, dice.cols
+            ).into()),
+            Some(dice) => dice,
+            None => match (name.as_deref(), rows, cols) {
+                (Some("classic"), 4, 4) | (None, 4, 4) => DiceSet::classic(),
+                (Some("big"), 5, 5) | (None, 5, 5) => DiceSet::big(),
+                (Some(name @ ("classic" | "big")), _, _) =>
+                    return Err(format!("The {name} dice set can't fill a {rows}x{cols} board.").into()),
+                (Some(name), _, _) => return Err(format!("Unknown dice set {name}.").into()),
+                (None, _, _) => return Err(format!("No dice set fills a {rows}x{cols} board.").into()),
+            },
+        };
+
+        // Java longs are signed, but every bit of the seed is used.
+        let board = generate_seeded_board(&dice, seed as u64)?;
+        Ok(to_string_arrays(env, &to_rows(&board))?.into_raw())
+    })
+}
+
+/// Native method to register a custom set of dice, such as one for another language,
+/// so `generateBoard` can roll it by name.
+/// Registering a set under a name which is already registered replaces it.
+/// Throws a `RuntimeException` if the name is built in, or the dice don't fill the board.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+/// name: The name of the dice set.
+/// dice: The faces of each die. A face may hold several letters, such as "qu".
+/// rows: The number of rows of boards rolled from the set.
+/// cols: The number of columns of boards rolled from the set.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_features_game_type_WordHunt_registerDiceSet(
+    mut env: JNIEnv, _class: JClass,
+    name: JString, java_dice: JObjectArray, rows: jint, cols: jint
+) {
+    guard(&mut env, (), |env| {
+        let name: String = env.get_string(&name)?.into();
+        if matches!(name.as_str(), "classic" | "big") {
+            return Err(format!("The {name} dice set is built in, so it can't be replaced.").into());
+        }
+
+        let rows = usize::try_from(rows).map_err(|_| "The number of rows can't be negative.")?;
+        let cols = usize::try_from(cols).map_err(|_| "The number of columns can't be negative.")?;
+
+        // Dice are read like a board, with a row of faces for each die.
+        let dice = read_board(env, &java_dice)?
+            .into_iter()
+            .map(|faces| faces.iter().map(|face| face.trim().to_lowercase()).collect())
+            .collect();
+
+        let dice = DiceSet::new(dice, rows, cols)?;
+        DICE_SETS.lock().unwrap().insert(name, dice);
+        Ok(())
+    })
+}
+
+/// Native method to find all valid Scrabble! words in several 2D boards at once,
+/// sharing one snapshot of the dictionary.
+/// Requires the dictionary to be initialized.
+/// Throws a `RuntimeException` if any board is invalid.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+/// boards: The 2D arrays of characters.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_features_game_type_WordHunt_findWordsBatch(
+    mut env: JNIEnv, _class: JClass,
+    java_boards: JObjectArray
+) -> jobjectArray {
+    guard(&mut env, JObject::null().into_raw(), |env| {
+        let count = env.get_array_length(&java_boards)?;
+        let mut boards = Vec::with_capacity(count as usize);
+        for i in 0..count {
+            let java_board = JObjectArray::from(env.get_object_array_element(&java_boards, i)?);
+            if java_board.is_null() {
+                return Err(format!("Board {i} is null.").into());
+            }
+
+            boards.push(Board::square(read_board(env, &java_board)?)?);
+            env.delete_local_ref(java_board)?;
+        }
+
+        let words = solve_many(&boards, &SolveOptions::default());
+        Ok(to_string_arrays(env, &words)?.into_raw())
+    })
+}
+
+/// Native method to find all valid Scrabble! words in a 2D board, without blocking.
+/// The board is read right away, then solved on the native thread pool. The callback's
+/// `void onWord(String word)` is called with each word as it is found, followed by
+/// `void onComplete()`, or `void onError(String message)` if the solve fails.
+/// The solve stops early if `onWord` throws.
+/// Requires the dictionary to be initialized.
+/// Throws a `RuntimeException` if the board is invalid.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+/// board: A 2D array of characters.
+/// callback: The object to report words to.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_features_game_type_WordHunt_findWordsAsync(
+    mut env: JNIEnv, _class: JClass,
+    java_board: JObjectArray, callback: JObject
+) {
+    guard(&mut env, (), |env| {
+        if callback.is_null() {
+            return Err("The callback is null.".into());
+        }
+
+        let board = Board::square(read_board(env, &java_board)?)?;
+        spawn_solve(env, Arc::new(SolverHandle::new(Solver::new())), board, &callback)
+    })
+}
+
+/// Native method to find all valid Scrabble! words in a 2D board using a solver, without blocking.
+/// Works like `findWordsAsync`, but the solve can be stopped with `cancelSolve`.
+/// Requires the dictionary to be initialized.
+/// Throws a `RuntimeException` if the handle is unknown or the board is invalid.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+/// handle: The handle of the solver.
+/// board: A 2D array of characters.
+/// callback: The object to report words to.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_features_game_type_WordHunt_solveAsync(
+    mut env: JNIEnv, _class: JClass,
+    handle: jlong, java_board: JObjectArray, callback: JObject
+) {
+    guard(&mut env, (), |env| {
+        if callback.is_null() {
+            return Err("The callback is null.".into());
+        }
+
+        let solver = find_solver(handle)?;
+        let board = Board::square(read_board(env, &java_board)?)?;
+        spawn_solve(env, solver, board, &callback)
+    })
+}
+
+/// Solves a board on the native thread pool, reporting to a Java callback.
+/// env: The JNI environment.
+/// solver: The solver to use.
+/// board: The game board.
+/// callback: The object to report words to.
+fn spawn_solve(env: &mut JNIEnv, solver: Arc<SolverHandle>, board: Board, callback: &JObject) -> NativeResult<()> {
+    let callback = env.new_global_ref(callback)?;
+    let cancel = solver.start();
+
+    let job_solver = Arc::clone(&solver);
+    let job_cancel = cancel.clone();
+    let queued = submit(env, Box::new(move |env| {
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            stream_words(env, &job_solver.solver, &board, &callback, &job_cancel)
+        }));
+        job_solver.finish(&job_cancel);
+
+        let error = match result {
+            Ok(Ok(())) => None,
+            Ok(Err(error)) => Some(error.to_string()),
+            Err(payload) => Some(panic_message(payload.as_ref())),
+        };
+
+        // The callback can't be called while an exception is pending.
+        if env.exception_check().unwrap_or(false) {
+            let _ = env.exception_describe();
+            let _ = env.exception_clear();
+        }
+
+        let _ = match error {
+            None => env.call_method(&callback, "onComplete", "()V", &[]).map(|_| ()),
+            Some(message) => env.new_string(message).and_then(|message| env.call_method(
+                &callback,
+                "onError",
+                "(Ljava/lang/String;)V",
+                &[JValue::Object(&message)]
+            ).map(|_| ())),
+        };
+
+        // Nobody on this thread can catch an exception thrown by the callback.
+        if env.exception_check().unwrap_or(false) {
+            let _ = env.exception_describe();
+            let _ = env.exception_clear();
+        }
+    }));
+
+    if queued.is_err() {
+        solver.finish(&cancel);
+    }
+    queued
+}
+
+/// Queues a job on the native thread pool, starting the pool if it isn't running.
+/// env: The JNI environment.
+/// job: The job to run.
+fn submit(env: &mut JNIEnv, job: Job) -> NativeResult<()> {
+    let vm = env.get_java_vm()?;
+    let mut pool = POOL.lock().unwrap();
+    let pool = match &mut *pool {
+        Some(pool) => pool,
+        None => pool.insert(start_pool(env.get_java_vm()?, ThreadPool::default_threads())?),
+    };
+
+    pool.execute(move || {
+        // The pool's threads were attached to the JVM when they started.
+        let Ok(mut env) = vm.get_env() else {
+            return;
+        };
+
+        // Each job gets its own local frame, since the thread never returns to Java.
+        // Panics are caught inside the frame, so it is always popped.
+        let _ = env.with_local_frame(16, |env| -> jni::errors::Result<()> {
+            let _ = panic::catch_unwind(AssertUnwindSafe(|| job(env)));
+            Ok(())
+        });
+    });
+    Ok(())
+}
+
+/// Solves a board, reporting each word to a Java callback as it is found.
+/// Stops early if the callback throws or the solve is cancelled.
+/// env: The JNI environment of the current thread.
+/// solver: The solver to use.
+/// board: The game board.
+/// callback: The object to report words to.
+/// cancel: The token which cancels the solve.
+fn stream_words(
+    env: &mut JNIEnv,
+    solver: &Solver,
+    board: &Board,
+    callback: &GlobalRef,
+    cancel: &CancelToken
+) -> NativeResult<()> {
+    let options = SolveOptions { cancel: Some(cancel.clone()), ..Default::default() };
+
+    // Resolve the callback once, rather than for every word.
+    let callback_class = env.get_object_class(callback)?;
+    let on_word = env.get_method_id(&callback_class, "onWord", "(Ljava/lang/String;)V")?;
+
+    let mut failure = None;
+    solver.find_all_words_streaming(board, &options, |found| {
+        let result = env.new_string(&found.word).and_then(|word| {
+            // SAFETY: `onWord` was resolved from the callback's class with matching arguments.
+            unsafe {
+                env.call_method_unchecked(
+                    callback,
+                    on_word,
+                    ReturnType::Primitive(Primitive::Void),
+                    &[JValue::Object(&word).as_jni()]
+                )?;
+            }
+            env.delete_local_ref(word)
+        });
+
+        if let Err(error) = result {
+            // Clear the exception, so the callback can still be told about the failure.
+            if env.exception_check().unwrap_or(false) {
+                let _ = env.exception_describe();
+                let _ = env.exception_clear();
+            }
+
+            failure.get_or_insert(error);
+            cancel.cancel();
+        }
+    });
+
+    match failure {
+        Some(error) => Err(error.into()),
+        None => Ok(()),
+    }
+}
+
+/// Native method to find all valid Scrabble! words in a 2D board of single characters.
+/// Avoids creating a Java string for every tile, and reads each row without copying it.
+/// The board mustn't be modified by another thread while this runs.
+/// Requires the dictionary to be initialized.
+/// Throws a `RuntimeException` if the board is invalid.
+/// Exported with its signature, since `findWords` is overloaded.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+/// board: A `char[][]` of tiles, by row.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_features_game_type_WordHunt_findWords___3_3C(
+    mut env: JNIEnv, _class: JClass,
+    java_board: JObjectArray
+) -> jobjectArray {
+    guard(&mut env, JObject::null().into_raw(), |env| {
+        let board = read_char_board(env, &java_board)?;
+        let words = solve_words(board)?;
+        Ok(to_string_array(env, &words)?.into_raw())
+    })
+}
+
+/// Native method to find all valid Scrabble! words in a board given as a flat string.
+/// Requires the dictionary to be initialized.
+/// Throws a `RuntimeException` if the letters don't fill the board.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+/// letters: The tiles of the board, row after row, such as "oetwiarsytoprwbs".
+/// rows: The number of rows of the board.
+/// cols: The number of columns of the board.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_features_game_type_WordHunt_findWordsFlat(
+    mut env: JNIEnv, _class: JClass,
+    letters: JString, rows: jint, cols: jint
+) -> jobjectArray {
+    guard(&mut env, JObject::null().into_raw(), |env| {
+        let letters: String = env.get_string(&letters)?.into();
+        let rows = usize::try_from(rows).map_err(|_| "The number of rows can't be negative.")?;
+        let cols = usize::try_from(cols).map_err(|_| "The number of columns can't be negative.")?;
+
+        let words = solve_flat(&letters, rows, cols)?;
+        Ok(to_string_array(env, &words)?.into_raw())
+    })
+}
+
+/// Native method to find all valid Scrabble! words in a 2D board, along with their paths.
+/// Returns `Object[] { String[] words, int[][] paths }`, where each path holds
+/// the row and column of every tile of the matching word, one after another.
+/// Requires the dictionary to be initialized.
+/// Throws a `RuntimeException` if the board is invalid.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+/// board: A 2D array of characters.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_features_game_type_WordHunt_findWordsWithPaths(
+    mut env: JNIEnv, _class: JClass,
+    java_board: JObjectArray
+) -> jobjectArray {
+    guard(&mut env, JObject::null().into_raw(), |env| {
+        let board = Board::square(read_board(env, &java_board)?)?;
+        let found = solve_paths(&board, &SolveOptions::default());
+
+        let words: Vec<String> = found.iter().map(|found| found.word.clone()).collect();
+        let paths: Vec<Vec<jint>> = found.iter()
+            .map(|found| to_coordinates(&board, &found.paths[0]))
+            .collect();
+
+        let words = to_string_array(env, &words)?;
+        let paths = to_int_arrays(env, &paths)?;
+
+        let object_class = cached_class(env, "java/lang/Object", |cache| &cache.object_class)?;
+        let result = env.new_object_array(2, object_class, JObject::null())?;
+        env.set_object_array_element(&result, 0, words)?;
+        env.set_object_array_element(&result, 1, paths)?;
+
+        Ok(result.into_raw())
+    })
+}
+
+/// Native method to find all valid Scrabble! words in a 2D board, with their scores.
+/// Returns an `Object[]` holding a `String[]` of the words and an `int[]` of their scores,
+/// in the same order, so Java never has to score words itself.
+/// Requires the dictionary to be initialized.
+/// Throws a `RuntimeException` if the board or scoring mode is invalid.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+/// board: A 2D array of characters.
+/// scoring_mode: 0 for Word Hunt, 1 for Boggle, 2 for Scrabble letter values.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_features_game_type_WordHunt_findWordsWithScores(
+    mut env: JNIEnv, _class: JClass,
+    java_board: JObjectArray, scoring_mode: jint
+) -> jobjectArray {
+    guard(&mut env, JObject::null().into_raw(), |env| {
+        let options = SolveOptions { scoring: read_scoring_mode(scoring_mode)?, ..Default::default() };
+        let board = Board::square(read_board(env, &java_board)?)?;
+        let found = solve_scored(&board, &options).words;
+
+        let words: Vec<String> = found.iter().map(|found| found.word.clone()).collect();
+        let scores: Vec<jint> = found.iter().map(|found| found.score as jint).collect();
+
+        let words = to_string_array(env, &words)?;
+        let scores = to_int_array(env, &scores)?;
+
+        let object_class = cached_class(env, "java/lang/Object", |cache| &cache.object_class)?;
+        let result = env.new_object_array(2, object_class, JObject::null())?;
+        env.set_object_array_element(&result, 0, words)?;
+        env.set_object_array_element(&result, 1, scores)?;
+
+        Ok(result.into_raw())
+    })
+}
+
+/// Native method to give hints for the best words on a board which haven't been found yet,
+/// so Java never needs the full solution.
+/// Each hint masks the letters it doesn't reveal with underscores: level 0 only reveals the
+/// length ("_____"), level 1 also reveals the first letter ("c____"), and level 2 or above
+/// reveals the whole word.
+/// Requires the dictionary to be initialized.
+/// Throws a `RuntimeException` if the board is invalid or the count or level is negative.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+/// board: A 2D array of characters.
+/// already_found: The words which were already found.
+/// count: The most hints to give, highest scoring words first.
+/// level: How much of each word to reveal.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_features_game_type_WordHunt_getHints(
+    mut env: JNIEnv, _class: JClass,
+    java_board: JObjectArray, already_found: JObjectArray, count: jint, level: jint
+) -> jobjectArray {
+    guard(&mut env, JObject::null().into_raw(), |env| {
+        let count = usize::try_from(count).map_err(|_| "The number of hints can't be negative.")?;
+        if level < 0 {
+            return Err("The hint level can't be negative.".into());
+        }
+
+        let board = Board::square(read_board(env, &java_board)?)?;
+        let found = if already_found.is_null() {
+            Vec::new()
+        } else {
+            read_string_array(env, &already_found)?
+        };
+        let found: Vec<&str> = found.iter().map(String::as_str).collect();
+
+        let options = SolveOptions { order: SortOrder::Score, max_results: Some(count), ..Default::default() };
+        let hints: Vec<String> = hints(&board, &found, &options)
+            .into_iter()
+            .map(|hint| mask_hint(&hint.word, level))
+            .collect();
+
+        Ok(to_string_array(env, &hints)?.into_raw())
+    })
+}
+
+/// Hides the letters of a word which a hint level doesn't reveal, with underscores.
+/// word: The word to hint at.
+/// level: 0 reveals only the length, 1 also the first letter, and 2 or more the whole word.
+pub(crate) fn mask_hint(word: &str, level: jint) -> String {
+    word.chars()
+        .enumerate()
+        .map(|(index, letter)| match level {
+            0 => '_',
+            1 if index > 0 => '_',
+            _ => letter,
+        })
+        .collect()
+}
+
+/// Native method to grade every player's submitted words for a board, all at once.
+/// Returns JSON like `{"optimal_score":...,"players":[{"total_score":...,"percentage":...,
+/// "answers":[{"word":...,"verdict":...,"score":...,"shared":...}]}]}`, with a player for
+/// each row of submissions, in order. Verdicts are "accepted", "duplicate" (the player
+/// already submitted the word), "too_short", "not_a_word" or "not_on_board", and accepted
+/// words are `shared` if another player also had them accepted.
+/// Requires the dictionary to be initialized.
+/// Throws a `RuntimeException` if the board or scoring mode is invalid.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+/// board: A 2D array of characters.
+/// submissions: The words each player submitted, in the order they were submitted.
+/// scoring_mode: 0 for Word Hunt, 1 for Boggle, 2 for Scrabble letter values.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_features_game_type_WordHunt_gradeAnswers(
+    mut env: JNIEnv, _class: JClass,
+    java_board: JObjectArray, submissions: JObjectArray, scoring_mode: jint
+) -> jstring {
+    guard(&mut env, JObject::null().into_raw(), |env| {
+        let options = SolveOptions { scoring: read_scoring_mode(scoring_mode)?, ..Default::default() };
+        let board = Board::square(read_board(env, &java_board)?)?;
+
+        // Submissions are read like a board, with a row of words for each player.
+        let submissions = read_board(env, &submissions)?;
+        let solver = Solver::new();
+        let graded: Vec<Graded> = submissions.iter()
+            .map(|words| {
+                let words: Vec<&str> = words.iter().map(String::as_str).collect();
+                solver.grade_answers(&board, &words, &options)
+            })
+            .collect();
+
+        let optimal_score = match graded.first() {
+            Some(graded) => graded.optimal_score,
+            None => solver.grade_answers(&board, &[], &options).optimal_score,
+        };
+
+        Ok(env.new_string(grades_json(&graded, optimal_score))?.into_raw())
+    })
+}
+
+/// Writes every player's graded answers as JSON, as returned by `gradeAnswers`.
+/// Accepted words are marked as shared if more than one player had them accepted.
+/// graded: The graded answers of each player.
+/// optimal_score: The total score of every word on the board.
+pub(crate) fn grades_json(graded: &[Graded], optimal_score: u32) -> String {
+    let mut players = HashMap::new();
+    for answer in graded.iter().flat_map(|graded| &graded.answers) {
+        if answer.verdict == Verdict::Accepted {
+            *players.entry(answer.word.trim().to_lowercase()).or_insert(0) += 1;
+        }
+    }
+
+    format!(
+        "{{\"optimal_score\":{optimal_score},\"players\":{}}}",
+        json::array(graded, |graded| format!(
+            "{{\"total_score\":{},\"percentage\":{:.2},\"answers\":{}}}",
+            graded.total_score,
+            graded.percentage,
+            json::array(&graded.answers, |answer| format!(
+                "{{\"word\":{},\"verdict\":{},\"score\":{},\"shared\":{}}}",
+                json::quote(&answer.word),
+                json::quote(match answer.verdict {
+                    Verdict::Accepted => "accepted",
+                    Verdict::Duplicate => "duplicate",
+                    Verdict::TooShort => "too_short",
+                    Verdict::NotAWord => "not_a_word",
+                    Verdict::NotOnBoard => "not_on_board",
+                }),
+                answer.score,
+                answer.verdict == Verdict::Accepted
+                    && players.get(&answer.word.trim().to_lowercase()).is_some_and(|&count| count > 1)
+            ))
+        ))
+    )
+}
+
+/// Native method to find all valid Scrabble! words in a board held in a direct buffer,
+/// writing the results into another direct buffer without creating any Java objects.
+/// The board is `rows * cols` ASCII letters, row after row.
+/// The results are big-endian, so they can be read with `ByteBuffer`'s defaults: an `int`
+/// word count, then for each word an `int` byte length, its UTF-8 bytes, an `int` path
+/// length, and an `int` tile index for every tile of its path.
+/// Returns the number of bytes written, or the negated number of bytes needed if the
+/// output buffer is too small, in which case nothing is written.
+/// Neither buffer may be used by another thread until the call returns.
+/// Requires the dictionary to be initialized.
+/// Throws a `RuntimeException` if a buffer isn't direct or the board is invalid.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+/// board: The direct buffer holding the board.
+/// rows: The number of rows of the board.
+/// cols: The number of columns of the board.
+/// output: The direct buffer to write the results to.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_features_game_type_WordHunt_findWordsBuffer(
+    mut env: JNIEnv, _class: JClass,
+    board: JByteBuffer, rows: jint, cols: jint, output: JByteBuffer
+) -> jint {
+    guard(&mut env, 0, |env| {
+        let rows = usize::try_from(rows).map_err(|_| "The number of rows can't be negative.")?;
+        let cols = usize::try_from(cols).map_err(|_| "The number of columns can't be negative.")?;
+
+        let size = rows.checked_mul(cols).ok_or(BoardError::TooLarge { rows, cols })?;
+
+        let (address, capacity) = direct_buffer(env, &board)?;
+        // SAFETY: The JVM keeps a direct buffer's memory valid for its whole capacity,
+        // and the local references keep both buffers alive for the rest of the call.
+        let letters = unsafe { std::slice::from_raw_parts(address, capacity) };
+        let letters = letters.get(..size)
+            .ok_or("The board buffer is smaller than the board.")?;
+        let letters = std::str::from_utf8(letters)
+            .map_err(|_| "The board buffer doesn't hold ASCII letters.")?;
+
+        let board = Board::from_flat(letters, rows, cols)?;
+        let found = solve_paths(&board, &SolveOptions::default());
+
+        let results = encode_word_paths(&found)?;
+
+        let size = jint::try_from(results.len()).map_err(|_| "The results are too large for a buffer.")?;
+        let (address, capacity) = direct_buffer(env, &output)?;
+        // SAFETY: As for the board, and Java mustn't use the output buffer until the call
+        // returns, so nothing else reads or writes it while it is borrowed here.
+        let output = unsafe { std::slice::from_raw_parts_mut(address, capacity) };
+        if output.len() < results.len() {
+            return Ok(-size);
+        }
+
+        output[..results.len()].copy_from_slice(&results);
+        Ok(size)
+    })
+}
+
+/// Encodes found words in the format written by `findWordsBuffer`.
+/// found: The found words, each with at least one path.
+pub(crate) fn encode_word_paths(found: &[WordPaths]) -> NativeResult<Vec<u8>> {
+    fn write_int(results: &mut Vec<u8>, value: usize) -> NativeResult<()> {
+        let value = jint::try_from(value).map_err(|_| "The results are too large for a buffer.")?;
+        results.extend_from_slice(&value.to_be_bytes());
+        Ok(())
+    }
+
+    let mut results = Vec::new();
+    write_int(&mut results, found.len())?;
+    for found in found {
+        let path = &found.paths[0];
+        write_int(&mut results, found.word.len())?;
+        results.extend_from_slice(found.word.as_bytes());
+        write_int(&mut results, path.len())?;
+        for &index in path {
+            write_int(&mut results, index)?;
+        }
+    }
+
+    Ok(results)
+}
+
+/// Native method to find all valid Scrabble! words in a 2D board, described fully.
+/// Returns a `WordResult[]`, with each path holding the row and column of every tile
+/// of the word, one after another.
+/// Requires the dictionary to be initialized.
+/// Throws a `RuntimeException` if the board is invalid.
+/// env: The JNI environment.
+/// class: The Java class calling this method.
+/// board: A 2D array of characters.
+#[no_mangle]
+pub extern "system" fn Java_moe_seikimo_magixbot_features_game_type_WordHunt_findWordResults(
+    mut env: JNIEnv, _class: JClass,
+    java_board: JObjectArray
+) -> jobjectArray {
+    guard(&mut env, JObject::null().into_raw(), |env| {
+        let board = Board::square(read_board(env, &java_board)?)?;
+        let matches = solve_matches(&board, &SolveOptions::default());
+        Ok(to_word_results(env, &board, &matches)?.into_raw())
+    })
+}
+
+/// Reads solve options from a Java object.
+/// Null options are the defaults.
+/// env: The JNI environment.
+/// java_options: The options object.
+fn read_options(env: &mut JNIEnv, java_options: &JObject) -> NativeResult<(SolveOptions, Topology)> {
+    let mut options = SolveOptions::default();
+    if java_options.is_null() {
+        return Ok((options, Topology::Square));
+    }
+
+    let min_length = env.get_field(java_options, "minLength", "I")?.i()?;
+    options.min_length = usize::try_from(min_length).map_err(|_| "The minimum length can't be negative.")?;
+
+    let max_results = env.get_field(java_options, "maxResults", "I")?.i()?;
+    options.max_results = usize::try_from(max_results).ok().filter(|&max_results| max_results > 0);
+
+    options.scoring = read_scoring_mode(env.get_field(java_options, "scoringMode", "I")?.i()?)?;
+
+    let topology = match env.get_field(java_options, "topology", "I")?.i()? {
+        0 => Topology::Square,
+        1 => Topology::Hex,
+        2 => Topology::Knight,
+        topology => return Err(format!("Unknown topology {topology}.").into()),
+    };
+
+    options.allow_reuse = env.get_field(java_options, "allowReuse", "Z")?.z()?;
+    if env.get_field(java_options, "sortByScore", "Z")?.z()? {
+        options.order = SortOrder::Score;
+    }
+
+    Ok((options, topology))
+}
+
+/// Reads a scoring mode passed from Java.
+/// mode: 0 for Word Hunt, 1 for Boggle, 2 for Scrabble letter values.
+pub(crate) fn read_scoring_mode(mode: jint) -> NativeResult<ScoringMode> {
+    match mode {
+        0 => Ok(ScoringMode::WordHunt),
+        1 => Ok(ScoringMode::Boggle),
+        2 => Ok(ScoringMode::Letters(LetterValues::scrabble())),
+        mode => Err(format!("Unknown scoring mode {mode}.").into()),
+    }
+}
+
+/// Reads the address and capacity of a direct buffer.
+/// env: The JNI environment.
+/// buffer: The direct buffer.
+fn direct_buffer(env: &JNIEnv, buffer: &JByteBuffer) -> NativeResult<(*mut u8, usize)> {
+    if buffer.is_null() {
+        return Err("The buffer is null.".into());
+    }
+
+    let address = env.get_direct_buffer_address(buffer)
+        .map_err(|_| "The buffer isn't a direct buffer.")?;
+    let capacity = env.get_direct_buffer_capacity(buffer)?;
+    Ok((address, capacity))
+}
+
+/// Reads a 2D array of strings from Java.
+/// env: The JNI environment.
+/// java_board: The array, by row.
+fn read_board(env: &mut JNIEnv, java_board: &JObjectArray) -> NativeResult<Vec<Vec<String>>> {
+    let rows = env.get_array_length(java_board)? as usize;
+    let mut board = Vec::with_capacity(rows);
+
+    for i in 0..rows {
+        let row = env.get_object_array_element(java_board, i as jint)?;
+        if row.is_null() {
+            return Err(format!("Row {i} of the board is null.").into());
+        }
+
+        let row_array = JObjectArray::from(row);
+        let cols = env.get_array_length(&row_array)? as usize;
+        let mut row_vec = Vec::with_capacity(cols);
+
+        for j in 0..cols {
+            let cell = env.get_object_array_element(&row_array, j as jint)?;
+            if cell.is_null() {
+                return Err(format!("The tile at row {i}, column {j} is null.").into());
+            }
+
+            let cell = JString::from(cell);
+            let cell_str = env.get_string(&cell)?.into();
+            env.delete_local_ref(cell)?;
+            row_vec.push(cell_str);
+        }
+
+        env.delete_local_ref(row_array)?;
+        board.push(row_vec);
+    }
+
+    Ok(board)
+}
+
+/// Reads an array of strings from Java.
+/// env: The JNI environment.
+/// java_strings: The array.
+fn read_string_array(env: &mut JNIEnv, java_strings: &JObjectArray) -> NativeResult<Vec<String>> {
+    let count = env.get_array_length(java_strings)?;
+    let mut strings = Vec::with_capacity(count as usize);
+
+    for i in 0..count {
+        let string = env.get_object_array_element(java_strings, i)?;
+        if string.is_null() {
+            return Err(format!("String {i} of the array is null.").into());
+        }
+
+        let string = JString::from(string);
+        strings.push(env.get_string(&string)?.into());
+        env.delete_local_ref(string)?;
+    }
+
+    Ok(strings)
+}
+
+/// Reads a 2D array of characters from Java.
+/// env: The JNI environment.
+/// java_board: The array, by row.
+fn read_char_board(env: &mut JNIEnv, java_board: &JObjectArray) -> NativeResult<Vec<Vec<String>>> {
+    let rows = env.get_array_length(java_board)? as usize;
+    let mut board = Vec::with_capacity(rows);
+
+    for i in 0..rows {
+        let row = env.get_object_array_element(java_board, i as jint)?;
+        if row.is_null() {
+            return Err(format!("Row {i} of the board is null.").into());
+        }
+
+        let row_array = JCharArray::from(row);
+
+        // Read the row in place, rather than copying it out first.
+        // SAFETY: No JNI calls are made while the row is held, it is only read, and
+        // the row array has no other element views.
+        let row_vec = {
+            let chars = unsafe { env.get_array_elements_critical(&row_array, ReleaseMode::NoCopyBack)? };
+
+            // Java characters are UTF-16, so each one is decoded on its own.
+            char::decode_utf16(chars.iter().copied())
+                .map(|cell| cell.map(String::from))
+                .collect::<Result<_, _>>()
+        };
+        env.delete_local_ref(row_array)?;
+
+        let row_vec = row_vec.map_err(|_| format!("Row {i} of the board has an unpaired surrogate."))?;
+        board.push(row_vec);
+    }
+
+    Ok(board)
+}
+
+/// Finds a class, using the copy resolved when the library was loaded if there is one.
+/// env: The JNI environment.
+/// name: The name of the class, such as `java/lang/String`.
+/// cached: Picks the class out of the cache.
+fn cached_class<'local>(
+    env: &mut JNIEnv<'local>,
+    name: &str,
+    cached: fn(&JavaCache) -> &GlobalRef
+) -> NativeResult<JClass<'local>> {
+    match CACHE.get() {
+        Some(cache) => Ok(JClass::from(env.new_local_ref(cached(cache))?)),
+        None => Ok(env.find_class(name)?),
+    }
+}
+
+/// Returns the name of the class constructed for found words.
+fn result_class_name() -> &'static str {
+    RESULT_CLASS.get().map_or(WORD_RESULT_CLASS, String::as_str)
+}
+
+/// Creates a Java string array.
+/// env: The JNI environment.
+/// values: The strings to put in the array.
+fn to_string_array<'local>(env: &mut JNIEnv<'local>, values: &[String]) -> NativeResult<JObjectArray<'local>> {
+    let string_class = cached_class(env, "java/lang/String", |cache| &cache.string_class)?;
+    let result_array = env.new_object_array(
+        values.len() as jint,
+        string_class,
+        JString::default()
+    )?;
+
+    for (i, value) in values.iter().enumerate() {
+        // Free each string as soon as it's stored, so large results can't run out of local references.
+        let j_string = env.new_string(value)?;
+        env.set_object_array_element(&result_array, i as jint, &j_string)?;
+        env.delete_local_ref(j_string)?;
+    }
+
+    Ok(result_array)
+}
+
+/// Creates a Java `String[][]` array.
+/// env: The JNI environment.
+/// values: The rows of the array.
+fn to_string_arrays<'local>(env: &mut JNIEnv<'local>, values: &[Vec<String>]) -> NativeResult<JObjectArray<'local>> {
+    let array_class = cached_class(env, "[Ljava/lang/String;", |cache| &cache.string_array_class)?;
+    let result_array = env.new_object_array(values.len() as jint, array_class, JObject::null())?;
+
+    for (i, row) in values.iter().enumerate() {
+        let row_array = to_string_array(env, row)?;
+        env.set_object_array_element(&result_array, i as jint, &row_array)?;
+        env.delete_local_ref(row_array)?;
+    }
+
+    Ok(result_array)
+}
+
+/// Splits the tiles of a board into rows.
+/// board: The board to split.
+pub(crate) fn to_rows(board: &Board) -> Vec<Vec<String>> {
+    (0..board.len())
+        .map(|index| board.cell(index).to_string())
+        .collect::<Vec<_>>()
+        .chunks(board.cols().max(1))
+        .map(<[String]>::to_vec)
+        .collect()
+}
+
+/// Converts a tile path to the row and column of every tile, one after another.
+/// board: The board the path is on.
+/// path: The tile indices of the path.
+pub(crate) fn to_coordinates(board: &Board, path: &[usize]) -> Vec<jint> {
+    path.iter()
+        .flat_map(|&index| {
+            let (_, row, col) = board.position(index);
+            [row as jint, col as jint]
+        })
+        .collect()
+}
+
+/// Creates a Java `int[][]` array.
+/// env: The JNI environment.
+/// values: The rows of the array.
+fn to_int_arrays<'local>(env: &mut JNIEnv<'local>, values: &[Vec<jint>]) -> NativeResult<JObjectArray<'local>> {
+    let array_class = cached_class(env, "[I", |cache| &cache.int_array_class)?;
+    let result_array = env.new_object_array(values.len() as jint, array_class, JObject::null())?;
+
+    for (i, row) in values.iter().enumerate() {
+        let row_array = to_int_array(env, row)?;
+        env.set_object_array_element(&result_array, i as jint, &row_array)?;
+        env.delete_local_ref(row_array)?;
+    }
+
+    Ok(result_array)
+}
+
+/// Creates a Java `int[]` array.
+/// env: The JNI environment.
+/// values: The values to put in the array.
+fn to_int_array<'local>(env: &mut JNIEnv<'local>, values: &[jint]) -> NativeResult<JIntArray<'local>> {
+    let array = env.new_int_array(values.len() as jint)?;
+    env.set_int_array_region(&array, 0, values)?;
+    Ok(array)
+}
+
+/// Creates a Java `WordResult[]` array.
+/// env: The JNI environment.
+/// board: The board the words were found on.
+/// matches: The words to describe.
+fn to_word_results<'local>(
+    env: &mut JNIEnv<'local>,
+    board: &Board,
+    matches: &[WordMatch]
+) -> NativeResult<JObjectArray<'local>> {
+    let cached = CACHE.get().and_then(|cache| cache.result_class.as_ref());
+    let (result_class, constructor) = match cached {
+        Some((class, constructor)) => (JClass::from(env.new_local_ref(class)?), *constructor),
+        None => {
+            let class = env.find_class(result_class_name())?;
+            let constructor = env.get_method_id(&class, "<init>", WORD_RESULT_CONSTRUCTOR)?;
+            (class, constructor)
+        }
+    };
+
+    let result_array = env.new_object_array(matches.len() as jint, &result_class, JObject::null())?;
+
+    for (i, found) in matches.iter().enumerate() {
+        let word = env.new_string(&found.word)?;
+        let path = to_int_array(env, &to_coordinates(board, &found.path))?;
+        // SAFETY: The constructor was resolved from the class with matching arguments.
+        let result = unsafe {
+            env.new_object_unchecked(
+                &result_class,
+                constructor,
+                &[
+                    JValue::Object(&word).as_jni(),
+                    JValue::Int(found.score as jint).as_jni(),
+                    JValue::Int(found.length as jint).as_jni(),
+                    JValue::Object(&path).as_jni(),
+                ]
+            )?
+        };
+
+        env.set_object_array_element(&result_array, i as jint, &result)?;
+        env.delete_local_ref(result)?;
+        env.delete_local_ref(path)?;
+        env.delete_local_ref(word)?;
+    }
+
+    Ok(result_array)
+}
+
+/// Called by the JVM when the library is loaded.
+/// Registers every native method on the class named by the `word.hunt.class` system
+/// property or `WORD_HUNT_CLASS` environment variable, so projects outside of MagixBot
+/// can declare the methods on their own class. Methods the class doesn't declare are
+/// skipped. The exported `Java_moe_seikimo_magixbot_*` symbols keep working either way.
+/// Also caches the classes and constructors native methods create objects with.
+/// vm: The JVM loading the library.
+/// reserved: Unused.
+#[no_mangle]
+pub extern "system" fn JNI_OnLoad(vm: *mut jni::sys::JavaVM, _reserved: *mut c_void) -> jint {
+    // SAFETY: The JVM passes a valid pointer to itself.
+    let Ok(vm) = (unsafe { JavaVM::from_raw(vm) }) else {
+        return JNI_ERR;
+    };
+    let Ok(mut env) = vm.get_env() else {
+        return JNI_ERR;
+    };
+
+    if let Some(result_class) = read_setting(&mut env, RESULT_CLASS_PROPERTY) {
+        let _ = RESULT_CLASS.set(result_class);
+    }
+
+    match JavaCache::resolve(&mut env) {
+        Ok(cache) => {
+            let _ = CACHE.set(cache);
+        }
+        Err(_) => return JNI_ERR,
+    }
+
+    if let Some(class) = read_setting(&mut env, CLASS_PROPERTY) {
+        if register_natives(&mut env, &class).is_err() {
+            // Leave the exception for Java, so it knows why loading failed.
+            return JNI_ERR;
+        }
+    }
+
+    JNI_VERSION_1_8
+}
+
+/// Reads a setting from a system property, falling back to an environment variable.
+/// Class names are returned in JNI form, such as `com/example/WordHunt`.
+/// env: The JNI environment.
+/// (property, variable): The names of the system property and environment variable.
+fn read_setting(env: &mut JNIEnv, (property, variable): (&str, &str)) -> Option<String> {
+    let from_property = env.new_string(property)
+        .and_then(|property| env.call_static_method(
+            "java/lang/System",
+            "getProperty",
+            "(Ljava/lang/String;)Ljava/lang/String;",
+            &[JValue::Object(&property)]
+        ))
+        .and_then(|value| value.l())
+        .ok()
+        .filter(|value| !value.is_null())
+        .and_then(|value| env.get_string(&JString::from(value)).ok().map(String::from));
+
+    if env.exception_check().unwrap_or(false) {
+        let _ = env.exception_clear();
+    }
+
+    from_property
+        .or_else(|| std::env::var(variable).ok())
+        .map(|value| value.trim().replace('.', "/"))
+        .filter(|value| !value.is_empty())
+}
+
+/// Registers every native method the class declares.
+/// class: The name of the class, such as `com/example/WordHunt`.
+/// env: The JNI environment.
+fn register_natives(env: &mut JNIEnv, class: &str) -> NativeResult<()> {
+    let class = env.find_class(class)?;
+    for method in native_methods() {
+        // Registering a method the class doesn't declare throws a NoSuchMethodError.
+        if env.register_native_methods(&class, &[method]).is_err() {
+            env.exception_clear()?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns every native method, with the signature it is registered under.
+fn native_methods() -> Vec<NativeMethod> {
+    let method = |name: &str, sig: &str, fn_ptr: *mut c_void| NativeMethod {
+        name: name.into(),
+        sig: sig.into(),
+        fn_ptr,
+    };
+
+    vec![
+        method("loadWords", "(Ljava/lang/String;)V",
+            Java_moe_seikimo_magixbot_MagixBot_loadWords__Ljava_lang_String_2 as *mut c_void),
+        method("loadWords", "(Ljava/lang/String;Ljava/lang/String;)V",
+            Java_moe_seikimo_magixbot_MagixBot_loadWords__Ljava_lang_String_2Ljava_lang_String_2 as *mut c_void),
+        method("loadWordsFromBytes", "([B)V",
+            Java_moe_seikimo_magixbot_MagixBot_loadWordsFromBytes as *mut c_void),
+        method("loadCompiledDictionary", "(Ljava/lang/String;)V",
+            Java_moe_seikimo_magixbot_MagixBot_loadCompiledDictionary as *mut c_void),
+        method("loadCompiledDictionaryFromBytes", "([B)V",
+            Java_moe_seikimo_magixbot_MagixBot_loadCompiledDictionaryFromBytes as *mut c_void),
+        method("setLogger", "(Ljava/lang/Object;)V",
+            Java_moe_seikimo_magixbot_MagixBot_setLogger as *mut c_void),
+        method("clearDictionary", "()V",
+            Java_moe_seikimo_magixbot_MagixBot_clearDictionary as *mut c_void),
+        method("reloadDictionary", "(Ljava/lang/String;)V",
+            Java_moe_seikimo_magixbot_MagixBot_reloadDictionary as *mut c_void),
+        method("getWordCount", "()I",
+            Java_moe_seikimo_magixbot_MagixBot_getWordCount as *mut c_void),
+        method("getDictionaryInfo", "()Ljava/lang/String;",
+            Java_moe_seikimo_magixbot_MagixBot_getDictionaryInfo as *mut c_void),
+        method("getNativeVersion", "()Ljava/lang/String;",
+            Java_moe_seikimo_magixbot_MagixBot_getNativeVersion as *mut c_void),
+        method("getCapabilities", "()Ljava/lang/String;",
+            Java_moe_seikimo_magixbot_MagixBot_getCapabilities as *mut c_void),
+        method("isValidWord", "(Ljava/lang/String;)Z",
+            Java_moe_seikimo_magixbot_features_game_type_WordHunt_isValidWord as *mut c_void),
+        method("solveAnagrams", "(Ljava/lang/String;I)[Ljava/lang/String;",
+            Java_moe_seikimo_magixbot_features_game_type_WordHunt_solveAnagrams as *mut c_void),
+        method("findWords", "([[Ljava/lang/String;)[Ljava/lang/String;",
+            Java_moe_seikimo_magixbot_features_game_type_WordHunt_findWords___3_3Ljava_lang_String_2 as *mut c_void),
+        method("findWords", "([[Ljava/lang/String;Ljava/lang/Object;)[Ljava/lang/String;",
+            Java_moe_seikimo_magixbot_features_game_type_WordHunt_findWords___3_3Ljava_lang_String_2Ljava_lang_Object_2
+                as *mut c_void),
+        method("findWords", "(Ljava/lang/String;[[Ljava/lang/String;)[Ljava/lang/String;",
+            Java_moe_seikimo_magixbot_features_game_type_WordHunt_findWords__Ljava_lang_String_2_3_3Ljava_lang_String_2
+                as *mut c_void),
+        method("findWordsJson", "([[Ljava/lang/String;Ljava/lang/String;)Ljava/lang/String;",
+            Java_moe_seikimo_magixbot_features_game_type_WordHunt_findWordsJson as *mut c_void),
+        method("createSolver", "()J",
+            Java_moe_seikimo_magixbot_features_game_type_WordHunt_createSolver as *mut c_void),
+        method("destroySolver", "(J)V",
+            Java_moe_seikimo_magixbot_features_game_type_WordHunt_destroySolver as *mut c_void),
+        method("findWords", "(J[[Ljava/lang/String;)[Ljava/lang/String;",
+            Java_moe_seikimo_magixbot_features_game_type_WordHunt_findWords__J_3_3Ljava_lang_String_2 as *mut c_void),
+        method("initThreadPool", "(I)V",
+            Java_moe_seikimo_magixbot_features_game_type_WordHunt_initThreadPool as *mut c_void),
+        method("cancelSolve", "(J)V",
+            Java_moe_seikimo_magixbot_features_game_type_WordHunt_cancelSolve as *mut c_void),
+        method("solveAsync", "(J[[Ljava/lang/String;Ljava/lang/Object;)V",
+            Java_moe_seikimo_magixbot_features_game_type_WordHunt_solveAsync as *mut c_void),
+        method("findWords", "([[C)[Ljava/lang/String;",
+            Java_moe_seikimo_magixbot_features_game_type_WordHunt_findWords___3_3C as *mut c_void),
+        method("findWordsFlat", "(Ljava/lang/String;II)[Ljava/lang/String;",
+            Java_moe_seikimo_magixbot_features_game_type_WordHunt_findWordsFlat as *mut c_void),
+        method("findWordsWithPaths", "([[Ljava/lang/String;)[Ljava/lang/Object;",
+            Java_moe_seikimo_magixbot_features_game_type_WordHunt_findWordsWithPaths as *mut c_void),
+        method("findWordsWithScores", "([[Ljava/lang/String;I)[Ljava/lang/Object;",
+            Java_moe_seikimo_magixbot_features_game_type_WordHunt_findWordsWithScores as *mut c_void),
+        method("getHints", "([[Ljava/lang/String;[Ljava/lang/String;II)[Ljava/lang/String;",
+            Java_moe_seikimo_magixbot_features_game_type_WordHunt_getHints as *mut c_void),
+        method("gradeAnswers", "([[Ljava/lang/String;[[Ljava/lang/String;I)Ljava/lang/String;",
+            Java_moe_seikimo_magixbot_features_game_type_WordHunt_gradeAnswers as *mut c_void),
+        method("findWordsBuffer", "(Ljava/nio/ByteBuffer;IILjava/nio/ByteBuffer;)I",
+            Java_moe_seikimo_magixbot_features_game_type_WordHunt_findWordsBuffer as *mut c_void),
+        method("findWordResults", "([[Ljava/lang/String;)[Ljava/lang/Object;",
+            Java_moe_seikimo_magixbot_features_game_type_WordHunt_findWordResults as *mut c_void),
+        method("generateBoard", "(IIJLjava/lang/String;)[[Ljava/lang/String;",
+            Java_moe_seikimo_magixbot_features_game_type_WordHunt_generateBoard as *mut c_void),
+        method("registerDiceSet", "(Ljava/lang/String;[[Ljava/lang/String;II)V",
+            Java_moe_seikimo_magixbot_features_game_type_WordHunt_registerDiceSet as *mut c_void),
+        method("findWordsBatch", "([[[Ljava/lang/String;)[[Ljava/lang/String;",
+            Java_moe_seikimo_magixbot_features_game_type_WordHunt_findWordsBatch as *mut c_void),
+        method("findWordsAsync", "([[Ljava/lang/String;Ljava/lang/Object;)V",
+            Java_moe_seikimo_magixbot_features_game_type_WordHunt_findWordsAsync as *mut c_void),
+    ]
+}
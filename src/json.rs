@@ -0,0 +1,390 @@
+use std::fmt::Write;
+use std::iter::Peekable;
+use std::str::Chars;
+use std::time::Duration;
+use crate::board::{Board, Topology};
+use crate::options::{SolveOptions, SortOrder};
+use crate::solver::{Solver, WordMatch};
+
+/// Quotes a string as a JSON string literal.
+/// value: The string to quote.
+pub(crate) fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => {
+                let _ = write!(quoted, "\\u{:04x}", ch as u32);
+            }
+            ch => quoted.push(ch),
+        }
+    }
+    quoted.push('"');
+
+    quoted
+}
+
+/// Writes a list of values as a JSON array.
+/// values: The values to write.
+/// write_value: Converts a value to JSON.
+pub(crate) fn array<T>(values: impl IntoIterator<Item = T>, write_value: impl Fn(T) -> String) -> String {
+    let values: Vec<String> = values.into_iter().map(write_value).collect();
+    format!("[{}]", values.join(","))
+}
+
+/// Writes a found word as a JSON object.
+/// found: The found word.
+pub(crate) fn word_match(found: &WordMatch) -> String {
+    format!(
+        "{{\"word\":{},\"score\":{},\"path\":{}}}",
+        quote(&found.word),
+        found.score,
+        array(&found.path, usize::to_string)
+    )
+}
+
+/// Writes the words found on a board as a JSON object, along with their total score.
+/// found: The found words.
+pub(crate) fn word_matches(found: &[WordMatch]) -> String {
+    let total_score = found.iter().map(|found| found.score).fold(0, u32::saturating_add);
+    format!("{{\"words\":{},\"total_score\":{total_score}}}", array(found, word_match))
+}
+
+/// A parsed JSON value.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    /// Looks up a key of an object.
+    /// key: The key.
+    pub(crate) fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(entries) => entries.iter().find(|(name, _)| name == key).map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    /// Reads the value as a non-negative integer.
+    pub(crate) fn as_usize(&self) -> Option<usize> {
+        match *self {
+            Value::Number(number) if number >= 0.0 && number.fract() == 0.0 => Some(number as usize),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_bool(&self) -> Option<bool> {
+        match *self {
+            Value::Bool(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+// Deeper documents are rejected, so untrusted input can't overflow the stack.
+const MAX_DEPTH: usize = 64;
+
+/// Parses a JSON document.
+/// Returns a description of the problem if the document isn't valid JSON.
+/// text: The document.
+pub(crate) fn parse(text: &str) -> Result<Value, String> {
+    let mut parser = Parser { chars: text.chars().peekable(), depth: 0 };
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    match parser.chars.next() {
+        None => Ok(value),
+        Some(ch) => Err(format!("Unexpected '{ch}' after the JSON value.")),
+    }
+}
+
+struct Parser<'text> {
+    chars: Peekable<Chars<'text>>,
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|ch| ch.is_whitespace()).is_some() {}
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        match self.chars.next() {
+            Some(ch) if ch == expected => Ok(()),
+            Some(ch) => Err(format!("Expected '{expected}' but found '{ch}'.")),
+            None => Err(format!("Expected '{expected}' but the JSON ended.")),
+        }
+    }
+
+    fn keyword(&mut self, keyword: &str, value: Value) -> Result<Value, String> {
+        keyword.chars().try_for_each(|ch| self.expect(ch))?;
+        Ok(value)
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        self.skip_whitespace();
+        match self.chars.peek() {
+            Some('n') => self.keyword("null", Value::Null),
+            Some('t') => self.keyword("true", Value::Bool(true)),
+            Some('f') => self.keyword("false", Value::Bool(false)),
+            Some('"') => self.string().map(Value::String),
+            Some('[') => self.nested(Self::array),
+            Some('{') => self.nested(Self::object),
+            Some(ch) if *ch == '-' || ch.is_ascii_digit() => self.number(),
+            Some(ch) => Err(format!("Unexpected '{ch}' in the JSON.")),
+            None => Err("The JSON ended unexpectedly.".to_string()),
+        }
+    }
+
+    fn nested(&mut self, parse: fn(&mut Self) -> Result<Value, String>) -> Result<Value, String> {
+        if self.depth >= MAX_DEPTH {
+            return Err(format!("The JSON is nested too deeply, past {MAX_DEPTH} levels."));
+        }
+
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+        value
+    }
+
+    fn number(&mut self) -> Result<Value, String> {
+        let mut number = String::new();
+        while let Some(ch) = self.chars.next_if(|ch| matches!(ch, '0'..='9' | '-' | '+' | '.' | 'e' | 'E')) {
+            number.push(ch);
+        }
+
+        number.parse().map(Value::Number).map_err(|_| format!("Invalid number '{number}'."))
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.expect('"')?;
+        let mut string = String::new();
+        loop {
+            match self.chars.next().ok_or("A string in the JSON isn't terminated.")? {
+                '"' => return Ok(string),
+                '\\' => match self.chars.next().ok_or("A string in the JSON isn't terminated.")? {
+                    'n' => string.push('\n'),
+                    'r' => string.push('\r'),
+                    't' => string.push('\t'),
+                    'b' => string.push('\u{8}'),
+                    'f' => string.push('\u{c}'),
+                    'u' => string.push(self.unicode_escape()?),
+                    ch @ ('"' | '\\' | '/') => string.push(ch),
+                    ch => return Err(format!("Invalid escape '\\{ch}'.")),
+                },
+                ch => string.push(ch),
+            }
+        }
+    }
+
+    fn hex_escape(&mut self) -> Result<u32, String> {
+        let digits: String = (0..4).filter_map(|_| self.chars.next()).collect();
+        match u32::from_str_radix(&digits, 16) {
+            // `from_str_radix` also accepts a sign, so the digits are checked as well.
+            Ok(code) if digits.len() == 4 && digits.bytes().all(|byte| byte.is_ascii_hexdigit()) => Ok(code),
+            _ => Err(format!("Invalid escape '\\u{digits}'.")),
+        }
+    }
+
+    fn unicode_escape(&mut self) -> Result<char, String> {
+        let code = self.hex_escape()?;
+        let code = match code {
+            0xd800..0xdc00 => {
+                // A high surrogate must be followed by an escaped low surrogate.
+                self.expect('\\')?;
+                self.expect('u')?;
+                let low = self.hex_escape()?;
+                if !(0xdc00..0xe000).contains(&low) {
+                    return Err(format!("Invalid low surrogate {low:#x} after {code:#x}."));
+                }
+
+                0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00)
+            }
+            0xdc00..0xe000 => return Err(format!("Unpaired low surrogate {code:#x}.")),
+            code => code,
+        };
+
+        char::from_u32(code).ok_or_else(|| format!("Invalid character code {code:#x}."))
+    }
+
+    fn array(&mut self) -> Result<Value, String> {
+        self.expect('[')?;
+        let mut values = Vec::new();
+        self.skip_whitespace();
+        if self.chars.next_if_eq(&']').is_some() {
+            return Ok(Value::Array(values));
+        }
+
+        loop {
+            values.push(self.value()?);
+            self.skip_whitespace();
+            match self.chars.next() {
+                Some(',') => continue,
+                Some(']') => return Ok(Value::Array(values)),
+                _ => return Err("Expected ',' or ']' in an array.".to_string()),
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, String> {
+        self.expect('{')?;
+        let mut entries = Vec::new();
+        self.skip_whitespace();
+        if self.chars.next_if_eq(&'}').is_some() {
+            return Ok(Value::Object(entries));
+        }
+
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            entries.push((key, self.value()?));
+            self.skip_whitespace();
+            match self.chars.next() {
+                Some(',') => continue,
+                Some('}') => return Ok(Value::Object(entries)),
+                _ => return Err("Expected ',' or '}' in an object.".to_string()),
+            }
+        }
+    }
+}
+
+/// Reads a board from a JSON array of rows.
+/// Each row is either an array of tiles, or a string of single-letter tiles.
+/// board: The JSON array.
+pub(crate) fn read_board(board: &Value) -> Result<Vec<Vec<String>>, String> {
+    let Value::Array(rows) = board else {
+        return Err("The board must be a JSON array of rows.".to_string());
+    };
+
+    rows.iter()
+        .map(|row| match row {
+            Value::String(letters) => Ok(letters.chars().map(String::from).collect()),
+            Value::Array(tiles) => tiles.iter()
+                .map(|tile| tile.as_str().map(str::to_string).ok_or("Every tile must be a string."))
+                .collect::<Result<_, _>>()
+                .map_err(str::to_string),
+            _ => Err("Every row must be a string or an array of tiles.".to_string()),
+        })
+        .collect()
+}
+
+/// Reads solve options from a JSON object.
+/// Every key is optional: `min_length`, `max_results`, `scoring` (`"wordhunt"`, `"boggle"`
+/// or `"scrabble"`), `topology` (`"square"`, `"hex"` or `"knight"`), `allow_reuse`,
+/// `prefix` and `sort_by_score`.
+/// options: The JSON object, or `null` for the defaults.
+pub(crate) fn read_options(options: &Value) -> Result<(SolveOptions, Topology), String> {
+    let mut solve_options = SolveOptions::default();
+    let mut topology = Topology::Square;
+    if *options == Value::Null {
+        return Ok((solve_options, topology));
+    }
+
+    let Value::Object(entries) = options else {
+        return Err("The options must be a JSON object.".to_string());
+    };
+
+    for (key, value) in entries {
+        let invalid = || format!("Invalid value for option '{key}'.");
+        match key.as_str() {
+            "min_length" => solve_options.min_length = value.as_usize().ok_or_else(invalid)?,
+            "max_results" => solve_options.max_results = value.as_usize().filter(|&max| max > 0),
+            "allow_reuse" => solve_options.allow_reuse = value.as_bool().ok_or_else(invalid)?,
+            "prefix" => solve_options.prefix = Some(value.as_str().ok_or_else(invalid)?.to_lowercase()),
+            "sort_by_score" => if value.as_bool().ok_or_else(invalid)? {
+                solve_options.order = SortOrder::Score;
+            },
+            "scoring" => solve_options.scoring = value.as_str()
+                .and_then(|name| name.parse().ok())
+                .ok_or_else(invalid)?,
+            "topology" => topology = value.as_str()
+                .and_then(|name| name.parse().ok())
+                .ok_or_else(invalid)?,
+            _ => return Err(format!("Unknown option '{key}'.")),
+        }
+    }
+
+    Ok((solve_options, topology))
+}
+
+/// Reads the board of a request, laid out with the topology from its options.
+/// request: The request, holding a `board` and optionally `options`.
+fn read_request_board(request: &Value) -> Result<(Board, SolveOptions), String> {
+    let (options, topology) = read_options(request.get("options").unwrap_or(&Value::Null))?;
+    let board = read_board(request.get("board").ok_or("The request needs a board.")?)?;
+    let board = Board::new(board, topology).map_err(|error| error.to_string())?;
+    Ok((board, options))
+}
+
+/// Finds every word on the board of a request.
+/// Returns `{"words":[...],"total_score":...}`.
+/// request: The request, such as `{"board":["oetw","rsae"],"options":{"min_length":4}}`.
+/// time_limit: The longest the search may run for, or `None` for no limit.
+pub(crate) fn solve_request(request: &Value, time_limit: Option<Duration>) -> Result<String, String> {
+    let (board, mut options) = read_request_board(request)?;
+    options.time_limit = time_limit;
+    Ok(word_matches(&Solver::new().find_matches(&board, &options)))
+}
+
+/// Checks the words of a request against the dictionary, and against its board if it has one.
+/// Returns `{"results":[{"word":...,"valid":...}]}`, with the path of each valid word on the board.
+/// request: The request, such as `{"words":["cat","xyzzy"],"board":["cat","dog"]}`.
+pub(crate) fn validate_request(request: &Value) -> Result<String, String> {
+    let Some(Value::Array(words)) = request.get("words") else {
+        return Err("The request needs an array of words.".to_string());
+    };
+    let words = words.iter()
+        .map(|word| word.as_str().ok_or("Every word must be a string."))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let board = match request.get("board") {
+        Some(_) => Some(read_request_board(request)?.0),
+        None => None,
+    };
+
+    let solver = Solver::new();
+    let results = array(words, |word| match &board {
+        Some(board) => match solver.find_word(board, word) {
+            Some(path) => format!(
+                "{{\"word\":{},\"valid\":true,\"path\":{}}}",
+                quote(word),
+                array(&path, usize::to_string)
+            ),
+            None => format!("{{\"word\":{},\"valid\":false}}", quote(word)),
+        },
+        None => format!("{{\"word\":{},\"valid\":{}}}", quote(word), crate::is_valid_word(word)),
+    });
+
+    Ok(format!("{{\"results\":{results}}}"))
+}
+
+/// Describes the loaded dictionary.
+/// Returns `{"word_count":...,"sources":[...],"has_frequencies":...}`.
+pub(crate) fn dictionary_stats() -> String {
+    let info = crate::dictionary_info();
+    format!(
+        "{{\"word_count\":{},\"sources\":{},\"has_frequencies\":{}}}",
+        info.word_count,
+        array(&info.sources, |source| quote(source)),
+        info.has_frequencies
+    )
+}
@@ -0,0 +1,79 @@
+use std::collections::{HashMap, VecDeque};
+use crate::solver::Solver;
+use crate::trie_node::TrieNode;
+
+impl Solver {
+    /// Finds the shortest chain of words between two words, changing one letter at a time.
+    /// Every word in the chain, including both ends, must be in the dictionary.
+    /// Returns nothing if the words differ in length or can't be connected.
+    /// from: The first word of the chain.
+    /// to: The last word of the chain.
+    pub fn word_ladder(&self, from: &str, to: &str) -> Option<Vec<String>> {
+        let (from, to) = (from.to_lowercase(), to.to_lowercase());
+        let dictionary = self.dictionary();
+        if from.chars().count() != to.chars().count() || !dictionary.is_word(&from) || !dictionary.is_word(&to) {
+            return None;
+        }
+
+        // Search outwards from the first word, remembering how each word was reached.
+        let mut previous: HashMap<String, Option<String>> = HashMap::from([(from.clone(), None)]);
+        let mut queue = VecDeque::from([from]);
+        while let Some(word) = queue.pop_front() {
+            if word == to {
+                let mut chain = vec![word];
+                while let Some(Some(before)) = previous.get(chain.last().unwrap()) {
+                    chain.push(before.clone());
+                }
+
+                chain.reverse();
+                return Some(chain);
+            }
+
+            let letters: Vec<char> = word.chars().collect();
+            let mut next_words = Vec::new();
+            one_letter_away(dictionary, &letters, false, &mut String::new(), &mut next_words);
+
+            for next in next_words {
+                if !previous.contains_key(&next) {
+                    previous.insert(next.clone(), Some(word.clone()));
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// Finds every dictionary word which differs from a word by exactly one letter.
+/// node: The trie node of the current word.
+/// rest: The letters of the word left to match.
+/// changed: Whether a letter has already been changed.
+/// current_word: The current word.
+/// words: The words found so far.
+fn one_letter_away(
+    node: &TrieNode,
+    rest: &[char],
+    changed: bool,
+    current_word: &mut String,
+    words: &mut Vec<String>
+) {
+    let Some((&letter, rest)) = rest.split_first() else {
+        if changed && node.is_end_of_word() {
+            words.push(current_word.clone());
+        }
+
+        return;
+    };
+
+    for (next_letter, next) in node.children() {
+        let changes = next_letter != letter;
+        if changes && changed {
+            continue;
+        }
+
+        current_word.push(next_letter);
+        one_letter_away(next, rest, changed || changes, current_word, words);
+        current_word.pop();
+    }
+}
@@ -1,61 +1,620 @@
 // This flag is required because Java names follow camelCase.
 #![allow(non_snake_case)]
 
+mod analysis;
+mod board;
+mod cancel;
+#[cfg(feature = "capi")]
+mod capi;
+mod crossword;
+mod dictionary;
+mod generate;
+mod grading;
+mod hangman;
+mod hints;
+mod java;
+mod json;
+mod ladder;
+mod options;
+mod pattern;
+mod pool;
+mod puzzles;
+mod rack;
+mod rarity;
+mod rpc;
+mod scoring;
+#[cfg(feature = "server")]
+mod server;
 mod solver;
+mod strategy;
+#[cfg(feature = "tokio")]
+mod task;
 mod trie_node;
+#[cfg(feature = "wasm")]
+mod wasm;
+mod word_bites;
+mod wordle;
 
-use std::sync::RwLock;
-use jni::JNIEnv;
-use jni::objects::{JClass, JObjectArray, JString};
-use jni::sys::{jint, jobjectArray};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
 use lazy_static::lazy_static;
-use crate::solver::Solver;
 use crate::trie_node::TrieNode;
 
+pub use crate::analysis::{Coverage, Difficulty, DifficultyLevel, TileSwap};
+pub use crate::board::{Board, BoardError, Multiplier, Topology, BLOCKED_CELL, WILDCARD_CELL};
+#[cfg(feature = "capi")]
+pub use crate::capi::{WhAnalysis, WhDictionary, WhResults};
+pub use crate::cancel::CancelToken;
+pub use crate::dictionary::DictionaryInfo;
+pub use crate::generate::{
+    generate_board, generate_seeded_board, AnnealOptions, BoardTargets, DiceSet, RandomRng, Rng,
+    ScoreDistribution, SeededRng
+};
+pub use crate::grading::{Graded, GradedAnswer, Verdict};
+pub use crate::hangman::HangmanHint;
+pub use crate::hints::{Hint, WordHints};
+pub use crate::options::{ParseNameError, SolveOptions, SortOrder};
+pub use crate::pattern::Pattern;
+pub use crate::puzzles::{Puzzle, PuzzlePack};
+pub use crate::rarity::Rarity;
+pub use crate::rpc::serve_rpc;
+pub use crate::scoring::{LetterValues, ScoredBoard, ScoredWord, ScoringMode};
+#[cfg(feature = "server")]
+pub use crate::server::{serve, serve_listener};
+pub use crate::solver::{Solver, WildcardWord, WordIter, WordMatch, WordPaths};
+pub use crate::strategy::{PlannedWord, PlayPlan, SwipeCost};
+#[cfg(feature = "tokio")]
+pub use crate::task::{solve_words_async, SolveTask};
+pub use crate::word_bites::{Bite, BiteWord, Direction};
+pub use crate::wordle::{rank_guesses, Feedback, WordleGuess};
+
 lazy_static! {
-    pub static ref DICTIONARY: RwLock<TrieNode> = RwLock::new(TrieNode::new());
+    pub static ref DICTIONARY: RwLock<Arc<TrieNode>> = RwLock::new(Arc::new(TrieNode::new()));
+
+    /// The paths of the files loaded into the dictionary.
+    /// Always locked after the dictionary.
+    static ref DICTIONARY_SOURCES: RwLock<Vec<String>> = RwLock::new(Vec::new());
+
+    /// Dictionaries loaded under a name, such as one per language.
+    /// Kept apart from the default dictionary.
+    pub(crate) static ref NAMED_DICTIONARIES: RwLock<HashMap<String, Arc<TrieNode>>> = RwLock::new(HashMap::new());
 }
 
 /// Loads a dictionary file.
+/// Each line holds a word, optionally followed by how often it is used.
+/// Words with frequencies are annotated with their rarity.
 /// path: The path to the dictionary file.
 pub fn load_dictionary(path: String) {
-    // Lock the dictionary.
-    let mut dictionary = DICTIONARY.write().unwrap();
-
     // Check if the file exists.
     if !std::fs::exists(&path)
         .expect("Couldn't check if the dictionary file exists.") {
+        log::warn!("The dictionary file {path} doesn't exist, so no words were loaded.");
         return;
     }
 
     // Read the dictionary file.
+    // The file is read first, so a failed read can't poison the lock.
     let contents = std::fs::read_to_string(&path)
         .expect("Couldn't read the dictionary file.");
 
-    // Split the contents by newlines.
-    for word in contents.lines() {
-        dictionary.insert(word.to_lowercase().to_string());
-    }
+    load_dictionary_contents(&contents, path);
+}
+
+/// Loads the contents of a dictionary file which is already in memory,
+/// such as one bundled as a resource.
+/// Each line holds a word, optionally followed by how often it is used.
+/// contents: The contents of the dictionary file.
+/// source: Where the contents came from, as reported by `dictionary_info`.
+pub fn load_dictionary_contents(contents: &str, source: String) {
+    // Lock the dictionary.
+    let mut dictionary = DICTIONARY.write().unwrap();
+
+    // Solvers still holding the old dictionary keep their own copy.
+    let count = insert_words(Arc::make_mut(&mut dictionary), contents);
+    log::info!("Loaded {count} words from {source}.");
+
+    DICTIONARY_SOURCES.write().unwrap().push(source);
 
     // Unlock the dictionary.
     drop(dictionary);
 }
 
+/// Replaces the dictionary with the words of a dictionary file, all at once.
+/// The current dictionary is kept if the file can't be read.
+/// Solvers created before the reload keep using the old dictionary.
+/// path: The path to the dictionary file.
+pub fn reload_dictionary(path: String) -> std::io::Result<()> {
+    let contents = std::fs::read_to_string(&path)?;
+    let mut trie = TrieNode::new();
+    let count = insert_words(&mut trie, &contents);
+    log::info!("Reloaded the dictionary with {count} words from {path}.");
+
+    let mut dictionary = DICTIONARY.write().unwrap();
+    *dictionary = Arc::new(trie);
+    *DICTIONARY_SOURCES.write().unwrap() = vec![path];
+
+    Ok(())
+}
+
+/// Turns the contents of a dictionary file into a compiled dictionary,
+/// which loads much faster than the words themselves.
+/// Each line holds a word, optionally followed by how often it is used.
+/// contents: The contents of the dictionary file.
+pub fn compile_dictionary(contents: &str) -> Vec<u8> {
+    let mut trie = TrieNode::new();
+    insert_words(&mut trie, contents);
+    trie.to_bytes()
+}
+
+/// Loads a compiled dictionary file, made by `compile_dictionary`.
+/// path: The path to the compiled dictionary file.
+pub fn load_compiled_dictionary(path: String) -> std::io::Result<()> {
+    let bytes = std::fs::read(&path)?;
+    load_compiled_dictionary_bytes(&bytes, path)
+}
+
+/// Loads a compiled dictionary which is already in memory, such as one bundled as a resource.
+/// Fails if the bytes aren't a compiled dictionary, in which case the dictionary is unchanged.
+/// bytes: The compiled dictionary, made by `compile_dictionary`.
+/// source: Where the bytes came from, as reported by `dictionary_info`.
+pub fn load_compiled_dictionary_bytes(bytes: &[u8], source: String) -> std::io::Result<()> {
+    let trie = TrieNode::from_bytes(bytes).ok_or_else(|| std::io::Error::new(
+        std::io::ErrorKind::InvalidData,
+        format!("{source} isn't a compiled dictionary.")
+    ))?;
+
+    let mut dictionary = DICTIONARY.write().unwrap();
+    if dictionary.word_count() == 0 {
+        *dictionary = Arc::new(trie);
+    } else {
+        // Solvers still holding the old dictionary keep their own copy.
+        Arc::make_mut(&mut dictionary).merge(trie);
+    }
+    log::info!("Loaded the compiled dictionary {source}.");
+
+    DICTIONARY_SOURCES.write().unwrap().push(source);
+    Ok(())
+}
+
+/// Loads a dictionary file into a named dictionary, creating it if it doesn't exist.
+/// Named dictionaries are kept apart from the default dictionary, so one process can
+/// solve boards in several languages at once. Use `Solver::with_named_dictionary` to solve with one.
+/// name: The name of the dictionary, such as "en" or "fr".
+/// path: The path to the dictionary file.
+pub fn load_named_dictionary(name: &str, path: String) -> std::io::Result<()> {
+    let contents = std::fs::read_to_string(&path)?;
+
+    let mut dictionaries = NAMED_DICTIONARIES.write().unwrap();
+    let dictionary = dictionaries.entry(name.to_string()).or_default();
+
+    // Solvers still holding the old dictionary keep their own copy.
+    let count = insert_words(Arc::make_mut(dictionary), &contents);
+    log::info!("Loaded {count} words from {path} into the {name} dictionary.");
+
+    Ok(())
+}
+
+/// Removes a named dictionary.
+/// Solvers created before it was removed keep using it.
+/// Returns whether there was a dictionary with the name.
+/// name: The name of the dictionary.
+pub fn remove_named_dictionary(name: &str) -> bool {
+    NAMED_DICTIONARIES.write().unwrap().remove(name).is_some()
+}
+
+/// Lists the names of every named dictionary, alphabetically.
+pub fn dictionary_names() -> Vec<String> {
+    let mut names: Vec<String> = NAMED_DICTIONARIES.read().unwrap().keys().cloned().collect();
+    names.sort_unstable();
+    names
+}
+
+/// Removes every word from the dictionary.
+/// Solvers created before the dictionary was cleared keep using the old dictionary.
+pub fn clear_dictionary() {
+    let mut dictionary = DICTIONARY.write().unwrap();
+    *dictionary = Arc::new(TrieNode::new());
+    DICTIONARY_SOURCES.write().unwrap().clear();
+}
+
+/// Adds the words of a dictionary file to a trie.
+/// Each line holds a word, optionally followed by how often it is used.
+/// trie: The trie to add the words to.
+/// contents: The contents of the dictionary file.
+/// Returns how many words were added.
+pub(crate) fn insert_words(trie: &mut TrieNode, contents: &str) -> usize {
+    let mut count = 0;
+    for line in contents.lines() {
+        let mut columns = line.split_whitespace();
+        let Some(word) = columns.next() else {
+            continue;
+        };
+
+        let frequency = columns.next()
+            .and_then(|column| {
+                let frequency = column.parse().ok();
+                if frequency.is_none() {
+                    log::warn!("Ignoring the invalid frequency {column:?} of {word:?}.");
+                }
+                frequency
+            })
+            .unwrap_or(0);
+        trie.insert_with_frequency(word.to_lowercase(), frequency);
+        count += 1;
+    }
+
+    count
+}
+
+/// Describes the loaded dictionary.
+pub fn dictionary_info() -> DictionaryInfo {
+    let dictionary = DICTIONARY.read().unwrap();
+    DictionaryInfo {
+        word_count: dictionary.word_count(),
+        sources: DICTIONARY_SOURCES.read().unwrap().clone(),
+        has_frequencies: dictionary.total_frequency() > 0,
+    }
+}
+
+/// Lists every word in the loaded dictionary, alphabetically.
+pub fn dictionary_words() -> Vec<String> {
+    DICTIONARY.read().unwrap().words()
+}
+
+/// Checks if a word is in the loaded dictionary.
+/// word: The word, in any case.
+pub fn is_valid_word(word: &str) -> bool {
+    DICTIONARY.read().unwrap().is_word(&word.trim().to_lowercase())
+}
+
 /// Finds all words on a 2D board.
 /// board: A 2D vector of strings.
-pub fn solve_words(board: Vec<Vec<String>>) -> Vec<String> {
+pub fn solve_words(board: Vec<Vec<String>>) -> Result<Vec<String>, BoardError> {
+    Ok(solve_board(&Board::square(board)?))
+}
+
+/// Finds all words on a 2D board of single-letter tiles.
+/// board: The rows of the board, such as `["oetw", "iars"]`.
+pub fn solve_rows(board: &[&str]) -> Result<Vec<String>, BoardError> {
+    Ok(solve_board(&Board::from_rows(board)?))
+}
+
+/// Finds all words on a 2D board of single-letter tiles.
+/// board: The rows of the board.
+pub fn solve_chars(board: &[&[char]]) -> Result<Vec<String>, BoardError> {
+    Ok(solve_board(&Board::from_chars(board)?))
+}
+
+/// Finds all words on a 2D board described by a flat string.
+/// letters: The tiles of the board, row after row, such as "oetwiarsytoprwbs".
+/// rows: The number of rows.
+/// cols: The number of columns.
+pub fn solve_flat(letters: &str, rows: usize, cols: usize) -> Result<Vec<String>, BoardError> {
+    Ok(solve_board(&Board::from_flat(letters, rows, cols)?))
+}
+
+/// Finds all words on a hexagonal board.
+/// board: A 2D vector of strings, in axial coordinates.
+pub fn solve_hex_words(board: Vec<Vec<String>>) -> Result<Vec<String>, BoardError> {
+    Ok(solve_board(&Board::hex(board)?))
+}
+
+/// Finds all words on a board where tiles connect by knight's moves.
+/// board: A 2D vector of strings.
+pub fn solve_knight_words(board: Vec<Vec<String>>) -> Result<Vec<String>, BoardError> {
+    Ok(solve_board(&Board::knight(board)?))
+}
+
+/// Finds all words on a stack of boards.
+/// board: A 3D vector of strings, by layer.
+pub fn solve_layered_words(board: Vec<Vec<Vec<String>>>) -> Result<Vec<String>, BoardError> {
+    Ok(solve_board(&Board::layered(board)?))
+}
+
+/// Finds all words on a board of any topology.
+/// board: The game board.
+pub fn solve_board(board: &Board) -> Vec<String> {
+    solve_with_options(board, &SolveOptions::default())
+}
+
+/// Finds all words on a board.
+/// board: The game board.
+/// options: The options to solve with.
+pub fn solve_with_options(board: &Board, options: &SolveOptions) -> Vec<String> {
+    let solver = Solver::new();
+    solver.find_all_words(board, options)
+}
+
+/// Counts the words on a board, without collecting them.
+/// board: The game board.
+/// options: The options to solve with.
+pub fn count_words(board: &Board, options: &SolveOptions) -> usize {
+    let solver = Solver::new();
+    solver.count_words(board, options)
+}
+
+/// Checks if a word can be played on a board.
+/// Returns the tile path of the word, if it is in the dictionary and can be traced.
+/// board: The game board.
+/// word: The word to look for.
+pub fn find_word(board: &Board, word: &str) -> Option<Vec<usize>> {
+    let solver = Solver::new();
+    solver.find_word(board, word)
+}
+
+/// Counts how many words pass through each tile of a board.
+/// Counts are indexed by tile.
+/// board: The game board.
+/// options: The options to solve with.
+pub fn heatmap(board: &Board, options: &SolveOptions) -> Vec<usize> {
+    let solver = Solver::new();
+    solver.heatmap(board, options)
+}
+
+/// Estimates how hard a board is from its solve profile.
+/// board: The game board.
+/// options: The options to solve with.
+pub fn difficulty(board: &Board, options: &SolveOptions) -> Difficulty {
+    let solver = Solver::new();
+    solver.difficulty(board, options)
+}
+
+/// Finds a small set of words whose paths cover every playable tile of a board.
+/// board: The game board.
+/// options: The options to solve with.
+pub fn cover_board(board: &Board, options: &SolveOptions) -> Coverage {
+    let solver = Solver::new();
+    solver.cover_board(board, options)
+}
+
+/// Finds words whose paths use every playable tile of a board exactly once.
+/// board: The game board.
+/// options: The options to solve with.
+pub fn tile_board(board: &Board, options: &SolveOptions) -> Option<Vec<(String, Vec<usize>)>> {
+    let solver = Solver::new();
+    solver.tile_board(board, options)
+}
+
+/// Generates a board which meets a set of targets.
+/// dice: The dice to roll.
+/// targets: The qualities the board must have.
+/// options: The options to solve with.
+/// rng: The source of randomness.
+pub fn generate_with_targets(
+    dice: &DiceSet,
+    targets: &BoardTargets,
+    options: &SolveOptions,
+    rng: &mut impl Rng
+) -> Option<Board> {
+    let solver = Solver::new();
+    solver.generate_with_targets(dice, targets, options, rng)
+}
+
+/// Searches for the board with the highest total score, starting from a board.
+/// start: The board to start from.
+/// candidates: The letters tiles may hold.
+/// options: The options to solve with.
+/// anneal: How the search is run.
+/// rng: The source of randomness.
+pub fn optimize_board(
+    start: &Board,
+    candidates: &[&str],
+    options: &SolveOptions,
+    anneal: &AnnealOptions,
+    rng: &mut impl Rng
+) -> (Board, u32) {
+    let solver = Solver::new();
+    solver.optimize_board(start, candidates, options, anneal, rng)
+}
+
+/// Grades the words a player submitted for a board.
+/// board: The game board.
+/// submissions: The submitted words, in the order they were submitted.
+/// options: The options to solve with.
+pub fn grade_answers(board: &Board, submissions: &[&str], options: &SolveOptions) -> Graded {
+    let solver = Solver::new();
+    solver.grade_answers(board, submissions, options)
+}
+
+/// Creates hints for the words on a board which haven't been found yet.
+/// board: The game board.
+/// found: The words which were already found.
+/// options: The options to solve with.
+pub fn hints(board: &Board, found: &[&str], options: &SolveOptions) -> Vec<WordHints> {
+    let solver = Solver::new();
+    solver.hints(board, found, options)
+}
+
+/// Estimates the scores and word counts of boards rolled from a set of dice.
+/// dice: The dice to roll.
+/// samples: The number of boards to roll.
+/// options: The options to solve with.
+/// rng: The source of randomness.
+pub fn estimate_scores(
+    dice: &DiceSet,
+    samples: usize,
+    options: &SolveOptions,
+    rng: &mut impl Rng
+) -> ScoreDistribution {
+    let solver = Solver::new();
+    solver.estimate_scores(dice, samples, options, rng)
+}
+
+/// Generates and solves a puzzle for every seed.
+/// dice: The dice to roll.
+/// seeds: The seeds of the puzzles.
+/// options: The options to solve with.
+pub fn generate_pack(
+    dice: &DiceSet,
+    seeds: impl IntoIterator<Item = u64>,
+    options: &SolveOptions
+) -> Result<PuzzlePack, BoardError> {
+    let solver = Solver::new();
+    solver.generate_pack(dice, seeds, options)
+}
+
+/// Finds the single tile swap which raises the total score of a board the most.
+/// board: The game board.
+/// options: The options to solve with.
+/// candidates: The letters a tile may be replaced with.
+pub fn best_tile_swap(board: &Board, options: &SolveOptions, candidates: &[&str]) -> Option<TileSwap> {
+    let solver = Solver::new();
+    solver.best_tile_swap(board, options, candidates)
+}
+
+/// Plans the words to play in a timed round to earn the most points.
+/// board: The game board.
+/// options: The options to solve with.
+/// cost: How long it takes to enter a word.
+/// duration: The length of the round.
+pub fn plan_play(
+    board: &Board,
+    options: &SolveOptions,
+    cost: SwipeCost,
+    duration: std::time::Duration
+) -> PlayPlan {
+    let solver = Solver::new();
+    solver.plan_play(board, options, cost, duration)
+}
+
+/// Finds all words which can be spelled from a rack of letters.
+/// letters: The letters of the rack; "*" and "?" are blanks.
+/// options: The options to solve with.
+pub fn solve_rack(letters: &str, options: &SolveOptions) -> Vec<String> {
+    let solver = Solver::new();
+    solver.solve_rack(letters, options)
+}
+
+/// Finds all words which can be spelled by lining up Word Bites pieces.
+/// pieces: The pieces on the board.
+/// max_length: The longest a word may be, in letters.
+/// options: The options to solve with.
+pub fn solve_word_bites(pieces: &[Bite], max_length: usize, options: &SolveOptions) -> Vec<BiteWord> {
+    let solver = Solver::new();
+    solver.solve_word_bites(pieces, max_length, options)
+}
+
+/// Finds every word which could still be the answer to a Wordle puzzle.
+/// length: The length of the answer, in letters.
+/// guesses: The guesses so far.
+pub fn wordle_candidates(length: usize, guesses: &[WordleGuess]) -> Vec<String> {
+    let solver = Solver::new();
+    solver.wordle_candidates(length, guesses)
+}
+
+/// Finds every word matching a crossword-style pattern.
+/// pattern: The pattern, with "_" for unknown letters, such as "c_t_e__".
+pub fn match_pattern(pattern: &str) -> Vec<String> {
+    let solver = Solver::new();
+    solver.match_pattern(pattern)
+}
+
+/// Finds the words which fit a game of Hangman, and the best letter to guess next.
+/// pattern: The revealed letters, with "_" for blanks, such as "_a__".
+/// excluded: The letters which were guessed but aren't in the word.
+pub fn hangman(pattern: &str, excluded: &[char]) -> HangmanHint {
     let solver = Solver::new();
-    solver.find_all_words(&board)
+    solver.hangman(pattern, excluded)
+}
+
+/// Finds the shortest chain of words between two words, changing one letter at a time.
+/// from: The first word of the chain.
+/// to: The last word of the chain.
+pub fn word_ladder(from: &str, to: &str) -> Option<Vec<String>> {
+    let solver = Solver::new();
+    solver.word_ladder(from, to)
+}
+
+/// Finds the longest word on a board, along with its tile path.
+/// board: The game board.
+/// options: The options to solve with.
+pub fn solve_longest_word(board: &Board, options: &SolveOptions) -> Option<(String, Vec<usize>)> {
+    let solver = Solver::new();
+    solver.find_longest_word(board, options)
+}
+
+/// Finds all words on several boards, sharing one dictionary snapshot.
+/// boards: The game boards.
+/// options: The options to solve with.
+pub fn solve_many(boards: &[Board], options: &SolveOptions) -> Vec<Vec<String>> {
+    let solver = Solver::new();
+    solver.solve_many(boards, options)
+}
+
+/// Finds all words on a board with wildcard tiles.
+/// Reports which letter was assumed for every wildcard in a word.
+/// board: The game board.
+/// options: The options to solve with.
+pub fn solve_wildcard_words(board: &Board, options: &SolveOptions) -> Vec<WildcardWord> {
+    let solver = Solver::new();
+    solver.find_wildcard_words(board, options)
+}
+
+/// Finds all words on a board, along with the tile paths that spell them.
+/// board: The game board.
+/// options: The options to solve with.
+pub fn solve_paths(board: &Board, options: &SolveOptions) -> Vec<WordPaths> {
+    let solver = Solver::new();
+    solver.find_word_paths(board, options)
+}
+
+/// Finds all words on a board, describing each word fully.
+/// board: The game board.
+/// options: The options to solve with.
+pub fn solve_matches(board: &Board, options: &SolveOptions) -> Vec<WordMatch> {
+    let solver = Solver::new();
+    solver.find_matches(board, options)
+}
+
+/// Finds all words on a board, reporting each word as soon as it is found.
+/// board: The game board.
+/// options: The options to solve with.
+/// on_match: Called with each word.
+pub fn solve_streaming(
+    board: &Board,
+    options: &SolveOptions,
+    on_match: impl FnMut(&WordMatch)
+) {
+    let solver = Solver::new();
+    solver.find_all_words_streaming(board, options, on_match);
+}
+
+/// Finds and scores all words on a board.
+/// board: The game board.
+/// options: The options to solve with.
+pub fn solve_scored(board: &Board, options: &SolveOptions) -> ScoredBoard {
+    let solver = Solver::new();
+    solver.score_words(board, options)
+}
+
+/// Finds all words on a board with their scores.
+/// The most valuable words are returned first.
+/// board: The game board.
+/// options: The options to solve with.
+pub fn solve_by_score(board: &Board, options: &SolveOptions) -> Vec<(String, u32)> {
+    let options = SolveOptions { order: SortOrder::Score, ..options.clone() };
+    solve_scored(board, &options).words
+        .into_iter()
+        .map(|found| (found.word, found.score))
+        .collect()
 }
 
 #[cfg(test)]
 mod test {
+    use std::sync::Once;
     use super::*;
 
+    static LOAD: Once = Once::new();
+
+    /// Loads the dictionary once for all tests.
+    fn load_words() {
+        LOAD.call_once(|| load_dictionary("words.txt".to_string()));
+    }
+
     #[test]
     fn find_all_words() {
         // Load the dictionary.
-        load_dictionary("words.txt".to_string());
+        load_words();
 
         let board = vec![
             vec!["o".to_string(), "e".to_string(), "t".to_string(), "w".to_string()],
@@ -65,7 +624,7 @@ mod test {
         ];
 
         // Find all words on the board.
-        let words = solve_words(board);
+        let words = solve_words(board).unwrap();
 
         // Log all words.
         println!("Found {} words:", words.len());
@@ -83,80 +642,1511 @@ mod test {
         assert!(words.contains(&"top".to_string()));
         assert!(words.contains(&"tops".to_string()));
     }
-}
 
-/// Loads all Scrabble! words.
-/// env: The JNI environment.
-/// class: The Java class calling this method.
-/// dictionary_path: The path to the dictionary.
-#[no_mangle]
-pub extern "system" fn Java_moe_seikimo_magixbot_MagixBot_loadWords(
-    mut env: JNIEnv, _class: JClass,
-    dictionary_path: JString
-) {
-    // Read the dictionary path.
-    let dictionary_path: String = env.get_string(&dictionary_path)
-        .expect("Couldn't get the dictionary path.")
-        .into();
-
-    // Load the dictionary.
-    load_dictionary(dictionary_path);
-}
-
-/// Native method to find all valid Scrabble! words in a 2D board.
-/// Requires the dictionary to be initialized.
-/// env: The JNI environment.
-/// class: The Java class calling this method.
-/// board: A 2D array of characters.
-#[no_mangle]
-pub extern "system" fn Java_moe_seikimo_magixbot_features_game_type_WordHunt_findWords(
-    mut env: JNIEnv, _class: JClass,
-    java_board: JObjectArray
-) -> jobjectArray {
-    // Convert Java 2D array to Rust Vec<Vec<String>>
-    let rows = env.get_array_length(&java_board).unwrap() as usize;
-    let mut board = Vec::with_capacity(rows);
-
-    for i in 0..rows {
-        let row = env.get_object_array_element(&java_board, i as jint)
-            .expect("Failed to get row");
-        let row_array = JObjectArray::from(row);
-        let cols = env.get_array_length(&row_array).unwrap() as usize;
-        let mut row_vec = Vec::with_capacity(cols);
-
-        for j in 0..cols {
-            let cell = env.get_object_array_element(&row_array, j as jint)
-                .expect("Failed to get cell");
-            let cell_str = env.get_string(&JString::from(cell))
-                .expect("Failed to get string")
-                .into();
-            row_vec.push(cell_str);
-        }
+    #[test]
+    fn find_hex_words() {
+        // Load the dictionary.
+        load_words();
+
+        let board = vec![
+            vec!["c".to_string(), "x".to_string()],
+            vec!["t".to_string(), "a".to_string()]
+        ];
+
+        // The square grid connects 'c' and 'a' diagonally.
+        let words = solve_words(board.clone()).unwrap();
+        assert!(words.contains(&"cat".to_string()));
+        assert!(words.contains(&"tax".to_string()));
+
+        // The hex grid doesn't.
+        let words = solve_hex_words(board).unwrap();
+        assert!(!words.contains(&"cat".to_string()));
+        assert!(words.contains(&"tax".to_string()));
+    }
+
+    #[test]
+    fn find_layered_words() {
+        // Load the dictionary.
+        load_words();
+
+        // 'c', 'a' and 't' are only connected across layers.
+        let board = vec![
+            vec![
+                vec!["c".to_string(), "x".to_string()],
+                vec!["x".to_string(), "x".to_string()]
+            ],
+            vec![
+                vec!["x".to_string(), "x".to_string()],
+                vec!["x".to_string(), "a".to_string()]
+            ],
+            vec![
+                vec!["t".to_string(), "x".to_string()],
+                vec!["x".to_string(), "x".to_string()]
+            ]
+        ];
+
+        let words = solve_layered_words(board).unwrap();
+        assert!(words.contains(&"cat".to_string()));
+        assert!(words.contains(&"tax".to_string()));
+    }
+
+    #[test]
+    fn skip_blocked_cells() {
+        // Load the dictionary.
+        load_words();
+
+        let board = vec![
+            vec!["c".to_string(), "#".to_string(), "t".to_string()],
+            vec!["#".to_string(), "a".to_string(), "#".to_string()],
+            vec!["#".to_string(), "#".to_string(), "#".to_string()]
+        ];
+
+        // Blocked cells must never be part of a word.
+        let words = solve_words(board).unwrap();
+        assert!(words.contains(&"cat".to_string()));
+        assert!(words.iter().all(|word| !word.contains('#')));
+    }
+
+    #[test]
+    fn find_multi_letter_tiles() {
+        // Load the dictionary.
+        load_words();
+
+        let board = vec![
+            vec!["qu".to_string(), "i".to_string(), "t".to_string()],
+            vec!["x".to_string(), "e".to_string(), "s".to_string()],
+            vec!["x".to_string(), "x".to_string(), "x".to_string()]
+        ];
+
+        // The "qu" tile is consumed as a whole.
+        let words = solve_words(board).unwrap();
+        assert!(words.contains(&"quit".to_string()));
+        assert!(words.contains(&"quite".to_string()));
+        assert!(words.contains(&"quits".to_string()));
+        assert!(words.iter().all(|word| !word.contains('q') || word.contains("qu")));
+    }
+
+    #[test]
+    fn find_wildcard_words() {
+        // Load the dictionary.
+        load_words();
+
+        let board = Board::square(vec![
+            vec!["c".to_string(), "*".to_string()],
+            vec!["#".to_string(), "t".to_string()]
+        ]).unwrap();
+
+        // The wildcard stands in for the missing vowel.
+        let words = solve_wildcard_words(&board, &SolveOptions::default());
+        let cat = words.iter()
+            .find(|found| found.word == "cat")
+            .expect("Couldn't find 'cat'.");
+        assert_eq!(cat.assumed, vec![(1, 'a')]);
+    }
+
+    #[test]
+    fn reuse_tiles() {
+        // Load the dictionary.
+        load_words();
+
+        let board = Board::square(vec![
+            vec!["t".to_string(), "o".to_string()],
+            vec!["#".to_string(), "#".to_string()]
+        ]).unwrap();
+
+        // "tot" needs the "t" tile twice.
+        let words = solve_board(&board);
+        assert!(!words.contains(&"tot".to_string()));
+
+        let options = SolveOptions { allow_reuse: true, ..Default::default() };
+        let words = solve_with_options(&board, &options);
+        assert!(words.contains(&"tot".to_string()));
+    }
+
+    #[test]
+    fn limit_results() {
+        // Load the dictionary.
+        load_words();
+
+        let board = Board::square(vec![
+            vec!["o".to_string(), "e".to_string(), "t".to_string(), "w".to_string()],
+            vec!["i".to_string(), "a".to_string(), "r".to_string(), "s".to_string()],
+            vec!["y".to_string(), "t".to_string(), "o".to_string(), "p".to_string()],
+            vec!["r".to_string(), "w".to_string(), "b".to_string(), "s".to_string()]
+        ]).unwrap();
+
+        // The limited results are the best of the full results.
+        let all = solve_board(&board);
+        let options = SolveOptions { max_results: Some(5), ..Default::default() };
+        let limited = solve_with_options(&board, &options);
+        assert_eq!(limited, all[..5]);
+    }
+
+    #[test]
+    fn score_word_hunt() {
+        // Load the dictionary.
+        load_words();
+
+        let board = Board::square(vec![
+            vec!["t".to_string(), "e".to_string()],
+            vec!["a".to_string(), "r".to_string()]
+        ]).unwrap();
+
+        let scored = solve_scored(&board, &SolveOptions::default());
+        let tear = scored.words.iter()
+            .find(|found| found.word == "tear")
+            .expect("Couldn't find 'tear'.");
+        assert_eq!(tear.score, 400);
+
+        let total: u32 = scored.words.iter().map(|found| found.score).sum();
+        assert_eq!(scored.total_score, total);
+    }
+
+    #[test]
+    fn score_boggle() {
+        let scoring = ScoringMode::Boggle;
+        assert_eq!(scoring.score("at"), 0);
+        assert_eq!(scoring.score("tar"), 1);
+        assert_eq!(scoring.score("tars"), 1);
+        assert_eq!(scoring.score("stare"), 2);
+        assert_eq!(scoring.score("tiers"), 2);
+        assert_eq!(scoring.score("staring"), 5);
+        assert_eq!(scoring.score("starting"), 11);
+        assert_eq!(scoring.score("restarting"), 11);
+    }
+
+    #[test]
+    fn score_letters() {
+        let mut values = LetterValues::scrabble();
+        let scoring = ScoringMode::Letters(values.clone());
+        assert_eq!(scoring.score("quiz"), 22);
+        assert_eq!(scoring.score("tears"), 5);
+
+        // Long words receive the last bonus.
+        values.length_bonuses = vec![0, 0, 0, 0, 0, 10];
+        let scoring = ScoringMode::Letters(values);
+        assert_eq!(scoring.score("tear"), 4);
+        assert_eq!(scoring.score("tears"), 15);
+        assert_eq!(scoring.score("stares"), 16);
+    }
+
+    #[test]
+    fn parse_names() {
+        assert!(ScoringMode::NAMES.iter().all(|name| name.parse::<ScoringMode>().is_ok()));
+        assert!(Topology::NAMES.iter().all(|name| name.parse::<Topology>().is_ok()));
+        assert!(SortOrder::NAMES.iter().all(|name| name.parse::<SortOrder>().is_ok()));
+
+        assert_eq!("scrabble".parse(), Ok(ScoringMode::Letters(LetterValues::scrabble())));
+        assert!(matches!("hex".parse(), Ok(Topology::Hex)));
+
+        let error = "triangle".parse::<Topology>().unwrap_err();
+        assert_eq!(error.to_string(), "Unknown topology \"triangle\".");
+    }
+
+    #[test]
+    fn order_by_score() {
+        // Load the dictionary.
+        load_words();
+
+        let board = Board::square(vec![
+            vec!["q".to_string(), "i".to_string(), "s".to_string()],
+            vec!["a".to_string(), "t".to_string(), "e".to_string()],
+            vec!["#".to_string(), "r".to_string(), "#".to_string()]
+        ]).unwrap();
+
+        let options = SolveOptions {
+            scoring: ScoringMode::Letters(LetterValues::scrabble()),
+            ..Default::default()
+        };
 
-        board.push(row_vec);
+        // Scores never increase down the list.
+        let words = solve_by_score(&board, &options);
+        assert!(words.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+        assert!(words[0].0.starts_with("qat"));
     }
 
-    // Create WordHunt instance and find words
-    let words = solve_words(board);
+    #[test]
+    fn find_all_paths() {
+        // Load the dictionary.
+        load_words();
+
+        // "tar" can start from either 't'.
+        let board = Board::square(vec![
+            vec!["t".to_string(), "a".to_string(), "t".to_string()],
+            vec!["#".to_string(), "r".to_string(), "#".to_string()]
+        ]).unwrap();
+
+        let words = solve_paths(&board, &SolveOptions::default());
+        let tar = words.iter().find(|found| found.word == "tar").unwrap();
+        assert_eq!(tar.paths.len(), 1);
+
+        let options = SolveOptions { all_paths: true, ..Default::default() };
+        let words = solve_paths(&board, &options);
+        let tar = words.iter().find(|found| found.word == "tar").unwrap();
+        assert_eq!(tar.paths.len(), 2);
+        assert!(tar.paths.contains(&vec![0, 1, 4]));
+        assert!(tar.paths.contains(&vec![2, 1, 4]));
+    }
+
+    #[test]
+    fn stream_words() {
+        // Load the dictionary.
+        load_words();
+
+        let board = Board::square(vec![
+            vec!["t".to_string(), "e".to_string()],
+            vec!["a".to_string(), "r".to_string()]
+        ]).unwrap();
 
-    // Convert result back to Java String array
-    let string_class = env.find_class("java/lang/String")
-        .expect("Failed to find String class");
-    let result_array = env.new_object_array(
-        words.len() as jint,
-        string_class,
-        JString::default()
-    ).expect("Failed to create result array");
+        // Every word is streamed exactly once.
+        let mut streamed = Vec::new();
+        solve_streaming(&board, &SolveOptions::default(), |found| {
+            assert_eq!(found.path.len(), found.length);
+            streamed.push(found.word.clone());
+        });
 
-    for (i, word) in words.iter().enumerate() {
-        let j_string = env.new_string(word)
-            .expect("Failed to create Java string");
-        env.set_object_array_element(
-            &result_array,
-            i as jint,
-            j_string
-        ).expect("Failed to set array element");
+        streamed.sort_by(|a, b| b.len().cmp(&a.len()).then(a.cmp(b)));
+        assert_eq!(streamed, solve_board(&board));
     }
 
-    result_array.into_raw()
-}
\ No newline at end of file
+    #[test]
+    fn iterate_words() {
+        // Load the dictionary.
+        load_words();
+
+        let board = Board::square(vec![
+            vec!["t".to_string(), "e".to_string()],
+            vec!["a".to_string(), "r".to_string()]
+        ]).unwrap();
+
+        // Taking a few words doesn't need the whole board.
+        let solver = Solver::new();
+        let options = SolveOptions::default();
+        let first: Vec<_> = solver.iter_words(&board, &options).take(2).collect();
+        assert_eq!(first.len(), 2);
+
+        let mut all: Vec<String> = solver.iter_words(&board, &options)
+            .map(|found| found.word)
+            .collect();
+        all.sort_by(|a, b| b.len().cmp(&a.len()).then(a.cmp(b)));
+        assert_eq!(all, solve_board(&board));
+    }
+
+    #[test]
+    fn cancel_solve() {
+        // Load the dictionary.
+        load_words();
+
+        let board = Board::square(vec![
+            vec!["t".to_string(), "e".to_string()],
+            vec!["a".to_string(), "r".to_string()]
+        ]).unwrap();
+
+        // A cancelled solve stops before finding anything.
+        let cancel = CancelToken::new();
+        cancel.cancel();
+
+        let options = SolveOptions { cancel: Some(cancel), ..Default::default() };
+        assert!(solve_with_options(&board, &options).is_empty());
+    }
+
+    #[test]
+    fn limit_solve_time() {
+        // Load the dictionary.
+        load_words();
+
+        let board = Board::square(vec![
+            vec!["o".to_string(), "e".to_string(), "t".to_string(), "w".to_string()],
+            vec!["i".to_string(), "a".to_string(), "r".to_string(), "s".to_string()],
+            vec!["y".to_string(), "t".to_string(), "o".to_string(), "p".to_string()],
+            vec!["r".to_string(), "w".to_string(), "b".to_string(), "s".to_string()]
+        ]).unwrap();
+
+        // A generous budget covers the whole board.
+        let options = SolveOptions {
+            time_limit: Some(std::time::Duration::from_secs(60)),
+            ..Default::default()
+        };
+        assert!(!solve_scored(&board, &options).truncated);
+
+        // An empty budget doesn't.
+        let options = SolveOptions {
+            time_limit: Some(std::time::Duration::ZERO),
+            ..Default::default()
+        };
+        let scored = solve_scored(&board, &options);
+        assert!(scored.truncated);
+        assert!(scored.words.len() < solve_board(&board).len());
+    }
+
+    #[test]
+    fn solve_batch() {
+        // Load the dictionary.
+        load_words();
+
+        let boards = vec![
+            Board::square(vec![
+                vec!["t".to_string(), "e".to_string()],
+                vec!["a".to_string(), "r".to_string()]
+            ]).unwrap(),
+            Board::square(vec![
+                vec!["c".to_string(), "a".to_string()],
+                vec!["#".to_string(), "t".to_string()]
+            ]).unwrap()
+        ];
+
+        // Every board is solved in order.
+        let solved = solve_many(&boards, &SolveOptions::default());
+        assert_eq!(solved.len(), 2);
+        assert_eq!(solved[0], solve_board(&boards[0]));
+        assert_eq!(solved[1], solve_board(&boards[1]));
+    }
+
+    #[test]
+    fn find_longest_word() {
+        // Load the dictionary.
+        load_words();
+
+        let board = Board::square(vec![
+            vec!["o".to_string(), "e".to_string(), "t".to_string(), "w".to_string()],
+            vec!["i".to_string(), "a".to_string(), "r".to_string(), "s".to_string()],
+            vec!["y".to_string(), "t".to_string(), "o".to_string(), "p".to_string()],
+            vec!["r".to_string(), "w".to_string(), "b".to_string(), "s".to_string()]
+        ]).unwrap();
+
+        // The longest word is the first full result.
+        let (word, path) = solve_longest_word(&board, &SolveOptions::default()).unwrap();
+        assert_eq!(word, solve_board(&board)[0]);
+        assert_eq!(path.len(), word.len());
+
+        // Nothing is found when no word is long enough.
+        let options = SolveOptions { min_length: word.len() + 1, ..Default::default() };
+        assert_eq!(solve_longest_word(&board, &options), None);
+
+        // Filters apply as they would to a full solve.
+        let options = SolveOptions { exclude: [word.clone()].into(), ..Default::default() };
+        let (next, _) = solve_longest_word(&board, &options).unwrap();
+        assert_eq!(next, solve_with_options(&board, &options)[0]);
+
+        let cancel = CancelToken::new();
+        cancel.cancel();
+        let options = SolveOptions { cancel: Some(cancel), ..Default::default() };
+        assert_eq!(solve_longest_word(&board, &options), None);
+    }
+
+    #[test]
+    fn find_single_word() {
+        // Load the dictionary.
+        load_words();
+
+        let board = Board::square(vec![
+            vec!["qu".to_string(), "i".to_string(), "t".to_string()],
+            vec!["x".to_string(), "*".to_string(), "s".to_string()]
+        ]).unwrap();
+
+        assert!(find_word(&board, "QUITS").is_some());
+        assert_eq!(find_word(&board, "quite"), Some(vec![0, 1, 2, 4]));
+        assert_eq!(find_word(&board, "quix"), None);
+        assert_eq!(find_word(&board, "tisx"), None);
+    }
+
+    #[test]
+    fn count_all_words() {
+        // Load the dictionary.
+        load_words();
+
+        let board = Board::square(vec![
+            vec!["o".to_string(), "e".to_string(), "t".to_string(), "w".to_string()],
+            vec!["i".to_string(), "a".to_string(), "r".to_string(), "s".to_string()],
+            vec!["y".to_string(), "t".to_string(), "o".to_string(), "p".to_string()],
+            vec!["r".to_string(), "w".to_string(), "b".to_string(), "s".to_string()]
+        ]).unwrap();
+
+        let options = SolveOptions::default();
+        assert_eq!(count_words(&board, &options), solve_board(&board).len());
+    }
+
+    #[test]
+    fn score_multipliers() {
+        // Load the dictionary.
+        load_words();
+
+        let mut board = Board::square(vec![
+            vec!["c".to_string(), "a".to_string()],
+            vec!["#".to_string(), "t".to_string()]
+        ]).unwrap();
+        board.set_multiplier(0, Multiplier::TripleLetter);
+        board.set_multiplier(3, Multiplier::DoubleWord);
+
+        let options = SolveOptions {
+            scoring: ScoringMode::Letters(LetterValues::scrabble()),
+            ..Default::default()
+        };
+
+        // (3 * 3 + 1 + 1) * 2
+        let scored = solve_scored(&board, &options);
+        let cat = scored.words.iter().find(|found| found.word == "cat").unwrap();
+        assert_eq!(cat.score, 22);
+
+        // Length-based scoring only uses word bonuses.
+        let scored = solve_scored(&board, &SolveOptions::default());
+        let cat = scored.words.iter().find(|found| found.word == "cat").unwrap();
+        assert_eq!(cat.score, 200);
+
+        // Scores too large to hold are capped rather than overflowing.
+        let mut board = Board::from_flat(&"a".repeat(20), 4, 5).unwrap();
+        for index in 0..board.len() {
+            board.set_multiplier(index, Multiplier::DoubleWord);
+        }
+        let path: Vec<usize> = (0..board.len()).collect();
+        assert_eq!(ScoringMode::WordHunt.score_path(&board, &"a".repeat(20), &path), u32::MAX);
+    }
+
+    #[test]
+    fn require_cells() {
+        // Load the dictionary.
+        load_words();
+
+        let board = Board::square(vec![
+            vec!["t".to_string(), "e".to_string()],
+            vec!["a".to_string(), "r".to_string()]
+        ]).unwrap();
+
+        // Every word must use the 'e'.
+        let options = SolveOptions { must_include: vec![1], ..Default::default() };
+        let words = solve_with_options(&board, &options);
+        assert!(!words.is_empty());
+        assert!(words.iter().all(|word| word.contains('e')));
+        assert!(!words.contains(&"tar".to_string()));
+    }
+
+    #[test]
+    fn require_prefix() {
+        // Load the dictionary.
+        load_words();
+
+        let board = Board::square(vec![
+            vec!["o".to_string(), "e".to_string(), "t".to_string(), "w".to_string()],
+            vec!["i".to_string(), "a".to_string(), "r".to_string(), "s".to_string()],
+            vec!["y".to_string(), "t".to_string(), "o".to_string(), "p".to_string()],
+            vec!["r".to_string(), "w".to_string(), "b".to_string(), "s".to_string()]
+        ]).unwrap();
+
+        // Only the words starting with "te" are kept.
+        let options = SolveOptions { prefix: Some("te".to_string()), ..Default::default() };
+        let expected: Vec<String> = solve_board(&board)
+            .into_iter()
+            .filter(|word| word.starts_with("te"))
+            .collect();
+        assert!(!expected.is_empty());
+        assert_eq!(solve_with_options(&board, &options), expected);
+    }
+
+    #[test]
+    fn match_patterns() {
+        assert!(Pattern::glob("*ing").matches("sing"));
+        assert!(Pattern::glob("t?a*").matches("tears"));
+        assert!(!Pattern::glob("*ing").matches("singe"));
+
+        assert!(Pattern::regex("^..a").matches("tears"));
+        assert!(Pattern::regex("rs$").matches("tears"));
+        assert!(Pattern::regex("^te*ars$").matches("tars"));
+        assert!(!Pattern::regex("^..a").matches("tar"));
+
+        // Load the dictionary.
+        load_words();
+
+        let board = Board::square(vec![
+            vec!["t".to_string(), "e".to_string()],
+            vec!["a".to_string(), "r".to_string()]
+        ]).unwrap();
+
+        let options = SolveOptions { pattern: Some(Pattern::glob("*r")), ..Default::default() };
+        let words = solve_with_options(&board, &options);
+        assert!(words.contains(&"tear".to_string()));
+        assert!(words.iter().all(|word| word.ends_with('r')));
+    }
+
+    #[test]
+    fn exclude_words() {
+        // Load the dictionary.
+        load_words();
+
+        let board = Board::square(vec![
+            vec!["t".to_string(), "e".to_string()],
+            vec!["a".to_string(), "r".to_string()]
+        ]).unwrap();
+
+        // Words already found are skipped.
+        let options = SolveOptions {
+            exclude: ["tear".to_string(), "rate".to_string()].into(),
+            ..Default::default()
+        };
+        let words = solve_with_options(&board, &options);
+        assert!(!words.contains(&"tear".to_string()));
+        assert!(!words.contains(&"rate".to_string()));
+        assert_eq!(words.len(), solve_board(&board).len() - 2);
+    }
+
+    #[test]
+    fn count_words_per_tile() {
+        // Load the dictionary.
+        load_words();
+
+        let board = Board::square(vec![
+            vec!["c".to_string(), "a".to_string(), "t".to_string()],
+            vec!["#".to_string(), "#".to_string(), "x".to_string()]
+        ]).unwrap();
+
+        // Blocked tiles are never used, and every word uses the 'a'.
+        let counts = heatmap(&board, &SolveOptions::default());
+        let words = solve_board(&board).len();
+        assert_eq!(counts[1], words);
+        assert_eq!(counts[3], 0);
+        assert_eq!(counts[4], 0);
+    }
+
+    #[test]
+    fn cover_all_tiles() {
+        // Load the dictionary.
+        load_words();
+
+        let board = Board::square(vec![
+            vec!["o".to_string(), "e".to_string(), "t".to_string(), "w".to_string()],
+            vec!["i".to_string(), "a".to_string(), "r".to_string(), "s".to_string()],
+            vec!["y".to_string(), "t".to_string(), "o".to_string(), "p".to_string()],
+            vec!["r".to_string(), "w".to_string(), "b".to_string(), "s".to_string()]
+        ]).unwrap();
+
+        // Every tile is used by at least one chosen word.
+        let coverage = cover_board(&board, &SolveOptions::default());
+        let mut used = vec![false; board.len()];
+        for (_, path) in &coverage.words {
+            path.iter().for_each(|&index| used[index] = true);
+        }
+
+        for (index, &used) in used.iter().enumerate() {
+            assert_eq!(used, !coverage.uncovered.contains(&index));
+        }
+    }
+
+    #[test]
+    fn plan_timed_round() {
+        // Load the dictionary.
+        load_words();
+
+        let board = Board::square(vec![
+            vec!["o".to_string(), "e".to_string(), "t".to_string(), "w".to_string()],
+            vec!["i".to_string(), "a".to_string(), "r".to_string(), "s".to_string()],
+            vec!["y".to_string(), "t".to_string(), "o".to_string(), "p".to_string()],
+            vec!["r".to_string(), "w".to_string(), "b".to_string(), "s".to_string()]
+        ]).unwrap();
+
+        let cost = SwipeCost {
+            base: std::time::Duration::from_millis(1000),
+            per_tile: std::time::Duration::from_millis(250),
+        };
+        let duration = std::time::Duration::from_secs(10);
+
+        // The plan fits the round and beats playing the longest words greedily.
+        let plan = plan_play(&board, &SolveOptions::default(), cost, duration);
+        assert!(plan.total_time <= duration);
+        assert!(!plan.words.is_empty());
+
+        let mut greedy_time = std::time::Duration::ZERO;
+        let mut greedy_score = 0;
+        for found in solve_scored(&board, &SolveOptions::default()).words {
+            let time = cost.time(found.word.len());
+            if greedy_time + time <= duration {
+                greedy_time += time;
+                greedy_score += found.score;
+            }
+        }
+        assert!(plan.total_score >= greedy_score);
+
+        // Rounds of any length can be planned, and long enough rounds play every word.
+        let plan = plan_play(&board, &SolveOptions::default(), cost, std::time::Duration::MAX);
+        assert_eq!(plan.words.len(), solve_board(&board).len());
+    }
+
+    #[test]
+    fn normalize_board() {
+        // Load the dictionary.
+        load_words();
+
+        // Tiles are trimmed and lowercased.
+        let words = solve_words(vec![
+            vec![" T ".to_string(), "E".to_string()],
+            vec!["A".to_string(), "R\n".to_string()]
+        ]).unwrap();
+        assert!(words.contains(&"tear".to_string()));
+
+        // Empty tiles are rejected.
+        let error = solve_words(vec![
+            vec!["t".to_string(), "e".to_string()],
+            vec!["a".to_string(), "  ".to_string()]
+        ]).unwrap_err();
+        assert_eq!(error, BoardError::InvalidCell { layer: 0, row: 1, col: 1 });
+    }
+
+    #[test]
+    fn validate_board() {
+        assert_eq!(solve_words(vec![]).unwrap_err(), BoardError::EmptyBoard);
+        assert_eq!(solve_words(vec![vec![]]).unwrap_err(), BoardError::EmptyBoard);
+
+        let error = solve_words(vec![
+            vec!["t".to_string(), "e".to_string()],
+            vec!["a".to_string()]
+        ]).unwrap_err();
+        assert_eq!(error, BoardError::RaggedRows { layer: 0, row: 1 });
+
+        let error = solve_layered_words(vec![
+            vec![vec!["t".to_string()], vec!["e".to_string()]],
+            vec![vec!["a".to_string()]]
+        ]).unwrap_err();
+        assert_eq!(error, BoardError::RaggedLayers { layer: 1 });
+    }
+
+    #[test]
+    fn describe_matches() {
+        // Load the dictionary.
+        load_words();
+
+        let board = Board::square(vec![
+            vec!["qu".to_string(), "i".to_string(), "t".to_string()],
+            vec!["x".to_string(), "e".to_string(), "s".to_string()]
+        ]).unwrap();
+
+        let matches = solve_matches(&board, &SolveOptions::default());
+        let quite = matches.iter().find(|found| found.word == "quite").unwrap();
+        assert_eq!(quite.path, vec![0, 1, 2, 4]);
+        assert_eq!(quite.length, 5);
+        assert_eq!(quite.score, 800);
+        assert_eq!(quite.dictionary_tag, None);
+    }
+
+    #[test]
+    fn borrow_board_input() {
+        // Load the dictionary.
+        load_words();
+
+        let expected = solve_words(vec![
+            vec!["t".to_string(), "e".to_string()],
+            vec!["a".to_string(), "r".to_string()]
+        ]).unwrap();
+
+        assert_eq!(solve_rows(&["te", "ar"]).unwrap(), expected);
+        assert_eq!(solve_chars(&[&['t', 'e'], &['a', 'r']]).unwrap(), expected);
+    }
+
+    #[test]
+    fn solve_flat_board() {
+        // Load the dictionary.
+        load_words();
+
+        let expected = solve_rows(&["oetw", "iars", "ytop", "rwbs"]).unwrap();
+        assert_eq!(solve_flat("oetwiarsytoprwbs", 4, 4).unwrap(), expected);
+
+        let error = solve_flat("oetwiars", 4, 4).unwrap_err();
+        assert_eq!(error, BoardError::WrongSize { expected: 16, found: 8 });
+
+        let error = solve_flat("oetw", usize::MAX, 2).unwrap_err();
+        assert_eq!(error, BoardError::TooLarge { rows: usize::MAX, cols: 2 });
+    }
+
+    #[test]
+    fn custom_adjacency() {
+        // Load the dictionary.
+        load_words();
+
+        // A ring where every tile touches the next one.
+        let cells: Vec<String> = ["c", "a", "t", "s"].iter().map(|cell| cell.to_string()).collect();
+        let neighbors = (0..4).map(|index| vec![(index + 1) % 4]).collect();
+        let board = Board::from_graph(cells, neighbors).unwrap();
+
+        let words = solve_board(&board);
+        assert!(words.contains(&"cats".to_string()));
+        assert!(!words.contains(&"act".to_string()));
+
+        // Tiles only connect to the tile two columns over.
+        let board = Board::with_adjacency(
+            vec![vec!["c".to_string(), "x".to_string(), "a".to_string(), "x".to_string(), "t".to_string()]],
+            |row, col| vec![(row, col + 2)]
+        ).unwrap();
+        assert!(solve_board(&board).contains(&"cat".to_string()));
+
+        let error = Board::from_graph(vec!["a".to_string()], vec![vec![1]]).err();
+        assert_eq!(error, Some(BoardError::InvalidNeighbor { index: 0, neighbor: 1 }));
+    }
+
+    #[test]
+    fn find_knight_words() {
+        // Load the dictionary.
+        load_words();
+
+        // 'c', 'a' and 't' are a knight's move apart, but 'a', 'c' and 't' aren't.
+        let board = vec![
+            vec!["c".to_string(), "x".to_string(), "x".to_string()],
+            vec!["x".to_string(), "x".to_string(), "a".to_string()],
+            vec!["t".to_string(), "x".to_string(), "x".to_string()],
+        ];
+
+        let words = solve_knight_words(board).unwrap();
+        assert!(words.contains(&"cat".to_string()));
+        assert!(!words.contains(&"act".to_string()));
+    }
+
+    #[test]
+    fn max_span() {
+        // Load the dictionary.
+        load_words();
+
+        // "cat" spans three columns, so it doesn't fit in a 2x2 window.
+        let board = Board::from_rows(&["cat", "xxx"]).unwrap();
+        let options = SolveOptions { max_span: Some(2), ..Default::default() };
+        assert!(!solve_with_options(&board, &options).contains(&"cat".to_string()));
+
+        let options = SolveOptions { max_span: Some(3), ..Default::default() };
+        assert!(solve_with_options(&board, &options).contains(&"cat".to_string()));
+    }
+
+    #[test]
+    fn region() {
+        // Load the dictionary.
+        load_words();
+
+        // Only the top row is in play, so "cat" can be found but "tax" can't.
+        let board = Board::from_rows(&["cat", "xxx"]).unwrap();
+        let region = (0..board.len()).map(|index| index < 3).collect();
+        let options = SolveOptions { region: Some(region), ..Default::default() };
+
+        let words = solve_with_options(&board, &options);
+        assert!(words.contains(&"cat".to_string()));
+        assert!(!words.contains(&"tax".to_string()));
+    }
+
+    #[test]
+    fn best_tile_swap() {
+        // Load the dictionary.
+        load_words();
+
+        // Turning the 'x' into a 't' spells "cat".
+        let board = Board::from_rows(&["ca", "xx"]).unwrap();
+        let swap = super::best_tile_swap(&board, &SolveOptions::default(), &["t", "x"]).unwrap();
+
+        assert_eq!(swap.letters, "t");
+        assert!(swap.score_change > 0);
+        assert_eq!(i64::from(swap.total_score), swap.score_change);
+    }
+
+    #[test]
+    fn rarity() {
+        let mut dictionary = TrieNode::new();
+        dictionary.insert_with_frequency("cat", 1_000_000);
+        dictionary.insert_with_frequency("act", 5);
+        dictionary.insert("tac");
+        let solver = Solver::with_dictionary(Arc::new(dictionary));
+
+        // Common words come first.
+        let board = Board::from_rows(&["ca", "tx"]).unwrap();
+        let options = SolveOptions { order: SortOrder::Rarity, ..Default::default() };
+        let matches = solver.find_matches(&board, &options);
+
+        let rarities: Vec<(&str, Option<Rarity>)> = matches.iter()
+            .map(|found| (found.word.as_str(), found.rarity))
+            .collect();
+        assert_eq!(rarities, vec![
+            ("cat", Some(Rarity::Common)),
+            ("act", Some(Rarity::Uncommon)),
+            ("tac", Some(Rarity::Rare)),
+        ]);
+
+        // Dictionaries without frequencies don't know how rare words are.
+        let mut dictionary = TrieNode::new();
+        dictionary.insert("cat");
+        let solver = Solver::with_dictionary(Arc::new(dictionary));
+        assert_eq!(solver.find_matches(&board, &SolveOptions::default())[0].rarity, None);
+    }
+
+    #[test]
+    fn required_letters() {
+        // Load the dictionary.
+        load_words();
+
+        let board = Board::from_rows(&["oetw", "iars", "ytop", "rwbs"]).unwrap();
+        let options = SolveOptions { required_letters: vec!['w', 'b'], ..Default::default() };
+
+        let words = solve_with_options(&board, &options);
+        assert!(!words.is_empty());
+        assert!(words.iter().all(|word| word.contains('w') && word.contains('b')));
+
+        // Every matching word is still found.
+        let expected: Vec<String> = solve_board(&board).into_iter()
+            .filter(|word| word.contains('w') && word.contains('b'))
+            .collect();
+        assert_eq!(words, expected);
+    }
+
+    #[test]
+    fn solve_rack() {
+        // Load the dictionary.
+        load_words();
+
+        // Every letter is used at most once.
+        let words = super::solve_rack("tca", &SolveOptions::default());
+        assert!(words.contains(&"cat".to_string()));
+        assert!(words.contains(&"act".to_string()));
+        assert!(!words.contains(&"tact".to_string()));
+
+        // Blanks stand for any letter.
+        let words = super::solve_rack("tca?", &SolveOptions::default());
+        assert!(words.contains(&"tact".to_string()));
+        assert!(words.contains(&"cats".to_string()));
+    }
+
+    #[test]
+    fn solve_word_bites() {
+        // Load the dictionary.
+        load_words();
+
+        let pieces = [Bite::Single('c'), Bite::Horizontal('a', 't'), Bite::Vertical('s', 'x')];
+        let words = super::solve_word_bites(&pieces, 8, &SolveOptions::default());
+
+        // "cats" lines up the pieces side by side, using the 's' of the stacked piece.
+        let cats = words.iter().find(|found| found.word == "cats").unwrap();
+        assert_eq!(cats.direction, Direction::Horizontal);
+        assert_eq!(cats.pieces, vec![0, 1, 2]);
+
+        // "act" would need the "at" piece split apart.
+        assert!(!words.iter().any(|found| found.word == "act"));
+
+        // Words longer than the limit aren't found.
+        let words = super::solve_word_bites(&pieces, 3, &SolveOptions::default());
+        assert!(!words.iter().any(|found| found.word == "cats"));
+    }
+
+    #[test]
+    fn wordle_candidates() {
+        // Load the dictionary.
+        load_words();
+
+        // The answer is "crane".
+        let guesses = [
+            WordleGuess::from_colors("trace", "xggyg").unwrap(),
+            WordleGuess::from_colors("brine", "xgxgg").unwrap(),
+        ];
+
+        let words = super::wordle_candidates(5, &guesses);
+        assert!(words.contains(&"crane".to_string()));
+        assert!(words.iter().all(|word| word.starts_with(|letter| letter != 't' && letter != 'b')));
+        assert!(words.iter().all(|word| word.ends_with("ne") && &word[1..2] == "r"));
+
+        // Every candidate is ranked.
+        let ranked = rank_guesses(&words);
+        assert_eq!(ranked.len(), words.len());
+        assert!(ranked.windows(2).all(|pair| pair[0].score >= pair[1].score));
+    }
+
+    #[test]
+    fn match_pattern() {
+        // Load the dictionary.
+        load_words();
+
+        let words = super::match_pattern("c_t_e__");
+        assert!(words.contains(&"cattery".to_string()));
+        assert!(words.iter().all(|word| word.len() == 7 && word.starts_with('c') && &word[4..5] == "e"));
+
+        assert_eq!(super::match_pattern("CA_"), super::match_pattern("ca?"));
+        assert!(super::match_pattern("c_t").contains(&"cat".to_string()));
+    }
+
+    #[test]
+    fn hangman() {
+        // Load the dictionary.
+        load_words();
+
+        let hint = super::hangman("_at", &['c', 'h']);
+        assert!(hint.candidates.contains(&"bat".to_string()));
+        assert!(!hint.candidates.contains(&"cat".to_string()));
+
+        // The 'a' and 't' are already revealed, so they can't be behind the blank.
+        assert!(!hint.candidates.contains(&"tat".to_string()));
+        assert!(hint.best_letter.is_some_and(|letter| !"acht".contains(letter)));
+
+        // Nothing is left to guess once the word is solved.
+        assert_eq!(super::hangman("cat", &[]).best_letter, None);
+    }
+
+    #[test]
+    fn word_ladder() {
+        // Load the dictionary.
+        load_words();
+
+        let ladder = super::word_ladder("cold", "warm").unwrap();
+        assert_eq!(ladder.first().unwrap(), "cold");
+        assert_eq!(ladder.last().unwrap(), "warm");
+        for pair in ladder.windows(2) {
+            let changes = pair[0].chars().zip(pair[1].chars()).filter(|(a, b)| a != b).count();
+            assert_eq!(changes, 1);
+        }
+
+        assert_eq!(super::word_ladder("cat", "cat"), Some(vec!["cat".to_string()]));
+        assert_eq!(super::word_ladder("cat", "cats"), None);
+    }
+
+    #[test]
+    fn tile_board() {
+        // Load the dictionary.
+        load_words();
+
+        let board = Board::from_rows(&["cat", "dog"]).unwrap();
+        let tiling = super::tile_board(&board, &SolveOptions::default()).unwrap();
+
+        // Every tile is used exactly once.
+        let mut used = vec![0; board.len()];
+        tiling.iter().flat_map(|(_, path)| path).for_each(|&index| used[index] += 1);
+        assert!(used.iter().all(|&count| count == 1));
+
+        // Five tiles can't be split into words of at least three letters.
+        let board = Board::from_rows(&["cat", "do#"]).unwrap();
+        assert_eq!(super::tile_board(&board, &SolveOptions::default()), None);
+    }
+
+    #[test]
+    fn generate_board() {
+        let dice = DiceSet::classic();
+        let board = super::generate_board(&dice, &mut RandomRng::new()).unwrap();
+        assert_eq!((board.rows(), board.cols()), (4, 4));
+
+        // Every tile shows a face of one of the dice.
+        assert!((0..board.len()).all(|index| dice.dice.iter()
+            .any(|faces| faces.iter().any(|face| face == board.cell(index)))));
+
+        assert_eq!(super::generate_board(&DiceSet::big(), &mut RandomRng::new()).unwrap().len(), 25);
+        assert!(DiceSet::new(vec![vec!["a".to_string()]], 2, 2).is_err());
+        assert_eq!(
+            DiceSet::new(vec![vec!["a".to_string()]], usize::MAX, 2),
+            Err(BoardError::TooLarge { rows: usize::MAX, cols: 2 })
+        );
+    }
+
+    #[test]
+    fn generate_with_targets() {
+        // Load the dictionary.
+        load_words();
+
+        let targets = BoardTargets { min_words: 40, min_longest: 5, ..Default::default() };
+        let board = super::generate_with_targets(
+            &DiceSet::classic(),
+            &targets,
+            &SolveOptions::default(),
+            &mut RandomRng::new()
+        ).unwrap();
+
+        let words = solve_board(&board);
+        assert!(words.len() >= 40);
+        assert!(words[0].len() >= 5);
+
+        // Impossible targets give up.
+        let targets = BoardTargets { min_words: 100_000, attempts: 2, repairs: 2, ..Default::default() };
+        let board = super::generate_with_targets(
+            &DiceSet::classic(),
+            &targets,
+            &SolveOptions::default(),
+            &mut RandomRng::new()
+        );
+        assert!(board.is_none());
+    }
+
+    #[test]
+    fn generate_seeded_board() {
+        // The generator matches the reference SplitMix64 sequence.
+        assert_eq!(SeededRng::new(0).next_u64(), 0xe220_a839_7b1d_cdaf);
+
+        let cells = |board: &Board| (0..board.len())
+            .map(|index| board.cell(index).to_string())
+            .collect::<Vec<_>>();
+
+        let first = super::generate_seeded_board(&DiceSet::classic(), 123).unwrap();
+        let second = super::generate_seeded_board(&DiceSet::classic(), 123).unwrap();
+        let other = super::generate_seeded_board(&DiceSet::classic(), 124).unwrap();
+        assert_eq!(cells(&first), cells(&second));
+        assert_ne!(cells(&first), cells(&other));
+    }
+
+    #[test]
+    fn difficulty() {
+        // Load the dictionary.
+        load_words();
+
+        let easy = Board::from_rows(&["oetw", "iars", "ytop", "rwbs"]).unwrap();
+        let hard = Board::from_rows(&["xqzj", "vkxq", "zjvk", "qxza"]).unwrap();
+
+        let easy = super::difficulty(&easy, &SolveOptions::default());
+        let hard = super::difficulty(&hard, &SolveOptions::default());
+        assert!(easy.rating < hard.rating);
+        assert!((0.0..=1.0).contains(&easy.rating) && (0.0..=1.0).contains(&hard.rating));
+        assert_eq!(hard.level, DifficultyLevel::Hard);
+        assert_eq!(hard.word_count, 0);
+    }
+
+    #[test]
+    fn optimize_board() {
+        // Load the dictionary.
+        load_words();
+
+        let start = Board::from_rows(&["xxx", "xxx", "xxx"]).unwrap();
+        let anneal = AnnealOptions { iterations: 200, ..Default::default() };
+        let (board, score) = super::optimize_board(
+            &start,
+            &["a", "e", "s", "t", "r"],
+            &SolveOptions::default(),
+            &anneal,
+            &mut SeededRng::new(7)
+        );
+
+        // The best board beats the empty one, and its score is accurate.
+        assert!(score > 0);
+        assert_eq!(solve_scored(&board, &SolveOptions::default()).total_score, score);
+    }
+
+    #[test]
+    fn grade_answers() {
+        // Load the dictionary.
+        load_words();
+
+        let board = Board::from_rows(&["ca", "ts"]).unwrap();
+        let graded = super::grade_answers(
+            &board,
+            &["CAT", "cat", "at", "xyzzy", "dog", "cats"],
+            &SolveOptions::default()
+        );
+
+        let verdicts: Vec<Verdict> = graded.answers.iter().map(|answer| answer.verdict).collect();
+        assert_eq!(verdicts, vec![
+            Verdict::Accepted,
+            Verdict::Duplicate,
+            Verdict::TooShort,
+            Verdict::NotAWord,
+            Verdict::NotOnBoard,
+            Verdict::Accepted,
+        ]);
+
+        assert_eq!(graded.total_score, 500);
+        assert!(graded.percentage > 0.0 && graded.percentage <= 100.0);
+        assert!(graded.missed.iter().all(|missed| missed.word != "cat" && missed.word != "cats"));
+        assert_eq!(
+            graded.total_score + graded.missed.iter().map(|missed| missed.score).sum::<u32>(),
+            graded.optimal_score
+        );
+    }
+
+    #[test]
+    fn hints() {
+        // Load the dictionary.
+        load_words();
+
+        let board = Board::from_rows(&["cat", "xsx"]).unwrap();
+        let hints = super::hints(&board, &["cat"], &SolveOptions::default());
+
+        // Found words don't get hints.
+        assert!(hints.iter().all(|word| word.word != "cat"));
+
+        let cats = hints.iter().find(|word| word.word == "cats").unwrap();
+        assert_eq!(cats.hints, vec![
+            Hint::Length(4),
+            Hint::FirstLetter('c'),
+            Hint::StartingTile(0),
+            Hint::PartialPath(vec![0, 1]),
+        ]);
+    }
+
+    #[test]
+    fn estimate_scores() {
+        // Load the dictionary.
+        load_words();
+
+        let distribution = super::estimate_scores(
+            &DiceSet::classic(),
+            20,
+            &SolveOptions::default(),
+            &mut SeededRng::new(1)
+        );
+
+        assert_eq!(distribution.scores.len(), 20);
+        assert!(distribution.scores.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(distribution.score_percentile(0.0), distribution.scores.first().copied());
+        assert_eq!(distribution.score_percentile(100.0), distribution.scores.last().copied());
+        assert!(distribution.mean_words > 0.0);
+    }
+
+    #[test]
+    fn generate_pack() {
+        // Load the dictionary.
+        load_words();
+
+        let pack = super::generate_pack(&DiceSet::classic(), [1, 2], &SolveOptions::default()).unwrap();
+        assert_eq!(pack.puzzles.len(), 2);
+        assert_eq!(pack.puzzles[0].tiles.len(), 16);
+        assert_eq!(pack.puzzles[0].total_score, pack.puzzles[0].words.iter().map(|found| found.score).sum::<u32>());
+
+        // The difficulty matches rating the board on its own.
+        let board = crate::generate::generate_seeded_board(&DiceSet::classic(), 1).unwrap();
+        assert_eq!(pack.puzzles[0].difficulty, Solver::new().difficulty(&board, &SolveOptions::default()));
+
+        // The pack is the same every time, and the seeds survive serialization.
+        let json = pack.to_json();
+        assert_eq!(json, super::generate_pack(&DiceSet::classic(), [1, 2], &SolveOptions::default()).unwrap().to_json());
+        assert!(json.starts_with("{\"puzzles\":[{\"seed\":\"1\",\"rows\":4,\"cols\":4,\"tiles\":["));
+    }
+
+    #[test]
+    fn is_valid_word() {
+        // Load the dictionary.
+        load_words();
+
+        assert!(super::is_valid_word("cat"));
+        assert!(super::is_valid_word(" CAT "));
+        assert!(!super::is_valid_word("xyzzy"));
+        assert!(!super::is_valid_word(""));
+    }
+
+    #[test]
+    fn dictionary_info() {
+        // Load the dictionary.
+        load_words();
+
+        let info = super::dictionary_info();
+        let lines = std::fs::read_to_string("words.txt").unwrap().lines().count();
+        assert_eq!(info.word_count, lines);
+        assert!(info.sources.contains(&"words.txt".to_string()));
+        assert!(!info.has_frequencies);
+        assert!(info.to_string().starts_with(&format!("{lines} words from ")));
+    }
+
+    #[test]
+    fn reload_missing_dictionary() {
+        // Load the dictionary.
+        load_words();
+
+        // A failed reload keeps the current dictionary.
+        assert!(reload_dictionary("missing.txt".to_string()).is_err());
+        assert!(super::is_valid_word("cat"));
+    }
+
+    #[test]
+    fn json_options() {
+        use crate::json::{parse, read_options, Value};
+
+        let value = parse(r#"{"min_length": 4, "scoring": "boggle", "topology": "hex", "prefix": "c\u0061"}"#).unwrap();
+        let Value::Object(entries) = &value else { panic!("Expected an object.") };
+        assert_eq!(entries[3], ("prefix".to_string(), Value::String("ca".to_string())));
+
+        let (options, topology) = read_options(&value).unwrap();
+        assert_eq!(options.min_length, 4);
+        assert_eq!(options.prefix.as_deref(), Some("ca"));
+        assert!(matches!(options.scoring, ScoringMode::Boggle));
+        assert!(matches!(topology, Topology::Hex));
+
+        assert!(parse("[1, 2").is_err());
+        assert_eq!(parse(r#""\ud83d\ude00""#), Ok(Value::String("\u{1f600}".to_string())));
+        assert!(parse(r#""\ud800\u0041""#).is_err());
+        assert!(parse(r#""\udc00""#).is_err());
+        assert!(parse(r#""\u+041""#).is_err());
+        assert!(parse(&"[".repeat(100_000)).unwrap_err().contains("nested too deeply"));
+        assert!(parse(&format!("{}{}", "[".repeat(64), "]".repeat(64))).is_ok());
+        assert!(read_options(&parse(r#"{"colour": "red"}"#).unwrap()).is_err());
+    }
+
+    #[test]
+    fn compiled_dictionary() {
+        let bytes = super::compile_dictionary("cat 5\ncar\nCART 2\n");
+        assert_eq!(bytes, super::compile_dictionary("cart 2\ncat 5\ncar\n"));
+
+        let trie = TrieNode::from_bytes(&bytes).unwrap();
+        assert_eq!(trie.word_count(), 3);
+        assert!(trie.is_word("cart") && !trie.is_word("ca"));
+        assert_eq!(trie.longest_suffix(), 4);
+        assert_eq!(trie.descend("cat").map(TrieNode::frequency), Some(5));
+        assert_eq!(trie.total_frequency(), 7);
+        assert_eq!(trie.words(), ["car", "cart", "cat"]);
+
+        assert!(TrieNode::from_bytes(&bytes[..bytes.len() - 1]).is_none());
+        assert!(TrieNode::from_bytes(b"cat\ncar\n").is_none());
+    }
+
+    #[test]
+    fn named_dictionaries() {
+        load_words();
+        super::load_named_dictionary("named-test", "words.txt".to_string()).unwrap();
+        assert!(super::load_named_dictionary("named-test-missing", "missing.txt".to_string()).is_err());
+        assert!(super::dictionary_names().contains(&"named-test".to_string()));
+        assert!(!super::dictionary_names().contains(&"named-test-missing".to_string()));
+
+        let board = Board::from_rows(&["oetw", "rsae", "tnrh", "iuob"]).unwrap();
+        let solver = Solver::with_named_dictionary("named-test").unwrap();
+        assert_eq!(solver.find_all_words(&board, &SolveOptions::default()), super::solve_board(&board));
+
+        let matches = solver.find_matches(&board, &SolveOptions::default());
+        assert!(matches.iter().all(|found| found.dictionary_tag.as_deref() == Some("named-test")));
+        assert!(Solver::with_named_dictionary("named-test-missing").is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "capi")]
+    fn c_api() {
+        use std::ffi::{CStr, CString};
+        use crate::capi::*;
+
+        unsafe {
+            let dictionary = wh_dictionary_new();
+            let contents = CString::new("cat\nact\ntac 3\ndog\n").unwrap();
+            assert!(wh_dictionary_load_contents(dictionary, contents.as_ptr()));
+            assert!(!wh_dictionary_load(dictionary, c"missing.txt".as_ptr()));
+
+            let results = wh_solve(dictionary, c"catx".as_ptr(), 2, 2);
+            wh_dictionary_free(dictionary);
+
+            let found = &*results;
+            let words: Vec<&str> = (0..found.count)
+                .map(|index| CStr::from_ptr(*found.words.add(index)).to_str().unwrap())
+                .collect();
+            assert_eq!(words.len(), 3);
+            assert!(words.contains(&"cat") && words.contains(&"act") && words.contains(&"tac"));
+            assert_eq!(*found.scores, 100);
+            wh_results_free(results);
+
+            let dictionary = wh_dictionary_new();
+            assert!(wh_dictionary_load(dictionary, c"words.txt".as_ptr()));
+            let mut analysis = std::mem::zeroed::<WhAnalysis>();
+            assert!(wh_analyze(dictionary, c"oetwrsaetnrhiuob".as_ptr(), 4, 4, &mut analysis));
+            assert_eq!(analysis.word_count, 359);
+            assert!(analysis.difficulty_level <= 2);
+
+            let mut counts = [0; 16];
+            assert!(wh_heatmap(dictionary, c"oetwrsaetnrhiuob".as_ptr(), 4, 4, counts.as_mut_ptr()));
+            assert!(counts.iter().all(|&count| count > 0));
+            assert!(!wh_heatmap(dictionary, c"oetw".as_ptr(), 4, 4, counts.as_mut_ptr()));
+            assert!(wh_solve(dictionary, c"catx".as_ptr(), usize::MAX, 2).is_null());
+            wh_dictionary_free(dictionary);
+
+            assert!(wh_solve(std::ptr::null(), c"catx".as_ptr(), 2, 2).is_null());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "wasm")]
+    fn wasm_solve() {
+        use crate::wasm::*;
+
+        load_words();
+        let solve = |board: &str, options: &str| unsafe {
+            let result = word_hunt_solve(board.as_ptr(), board.len(), options.as_ptr(), options.len());
+            let json = std::slice::from_raw_parts(result.add(4), word_hunt_result_length(result));
+            let json = String::from_utf8(json.to_vec()).unwrap();
+            word_hunt_free_result(result);
+            json
+        };
+
+        let json = solve(r#"["oetw", "rsae", "tnrh", ["i", "u", "o", "b"]]"#, r#"{"min_length": 8}"#);
+        assert!(json.starts_with(r#"{"words":[{"word":"intreats","score":2200,"path":["#));
+        assert!(solve("[1]", "").starts_with(r#"{"error":"#));
+    }
+
+    #[test]
+    #[cfg(feature = "server")]
+    fn http_server() {
+        use std::io::{Read, Write};
+        use std::net::{TcpListener, TcpStream};
+
+        load_words();
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        std::thread::spawn(move || super::serve_listener(listener));
+
+        let request = |request: &str| {
+            let mut stream = TcpStream::connect(address).unwrap();
+            stream.write_all(request.as_bytes()).unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            response
+        };
+
+        let body = r#"{"board":["oetw","rsae"],"options":{"min_length":7}}"#;
+        let response = request(&format!("POST /solve HTTP/1.1\r\nContent-Length: {}\r\n\r\n{body}", body.len()));
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains(r#"{"words":[{"word":"roseate","score":1800,"path":["#));
+        assert!(response.ends_with(r#"],"total_score":1800}"#));
+
+        let body = r#"{"words":["rose","cat"],"board":["oetw","rsae"]}"#;
+        let response = request(&format!("POST /validate HTTP/1.1\r\nContent-Length: {}\r\n\r\n{body}", body.len()));
+        assert!(response.contains(r#"{"results":[{"word":"rose","valid":true,"path":["#));
+        assert!(response.ends_with(r#"{"word":"cat","valid":false}]}"#));
+
+        assert!(request("GET /dictionary/stats HTTP/1.1\r\n\r\n").contains(r#""word_count":"#));
+        assert!(request("GET /solve HTTP/1.1\r\n\r\n").starts_with("HTTP/1.1 405"));
+        assert!(request("POST /solve HTTP/1.1\r\nContent-Length: 2\r\n\r\n{}").starts_with("HTTP/1.1 400"));
+        assert!(request("GET /missing HTTP/1.1\r\n\r\n").starts_with("HTTP/1.1 404"));
+        assert!(request(&format!("GET /{} HTTP/1.1\r\n\r\n", "a".repeat(20_000))).starts_with("HTTP/1.1 431"));
+    }
+
+    #[test]
+    fn json_rpc() {
+        load_words();
+
+        let input = [
+            r#"{"jsonrpc":"2.0","id":1,"method":"solve","params":{"board":["oetw","rsae"],"options":{"min_length":7}}}"#,
+            r#"{"jsonrpc":"2.0","id":"two","method":"validate","params":{"words":["rose","xyzzy"]}}"#,
+            r#"{"jsonrpc":"2.0","method":"stats"}"#,
+            r#"{"jsonrpc":"2.0","id":3,"method":"load","params":{"path":"missing.txt"}}"#,
+            r#"{"jsonrpc":"2.0","id":4,"method":"shuffle"}"#,
+            "{",
+        ].join("\n");
+
+        let mut output = Vec::new();
+        super::serve_rpc(input.as_bytes(), &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        let responses: Vec<&str> = output.lines().collect();
+
+        assert_eq!(responses.len(), 5);
+        assert!(responses[0].starts_with(r#"{"jsonrpc":"2.0","id":1,"result":{"words":[{"word":"roseate","#));
+        assert_eq!(
+            responses[1],
+            r#"{"jsonrpc":"2.0","id":"two","result":{"results":[{"word":"rose","valid":true},{"word":"xyzzy","valid":false}]}}"#
+        );
+        assert!(responses[2].starts_with(r#"{"jsonrpc":"2.0","id":3,"error":{"code":-32602,"#));
+        assert!(responses[3].starts_with(r#"{"jsonrpc":"2.0","id":4,"error":{"code":-32601,"#));
+        assert!(responses[4].starts_with(r#"{"jsonrpc":"2.0","id":null,"error":{"code":-32700,"#));
+    }
+
+    #[test]
+    #[cfg(feature = "tokio")]
+    fn async_solve() {
+        use std::future::Future;
+        use std::sync::Arc;
+        use std::task::{Context, Poll, Wake, Waker};
+        use std::thread::Thread;
+
+        struct ThreadWaker(Thread);
+
+        impl Wake for ThreadWaker {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+
+        load_words();
+        let board = Board::from_rows(&["oetw", "rsae", "tnrh", "iuob"]).unwrap();
+
+        let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+        let mut context = Context::from_waker(&waker);
+        let mut future = std::pin::pin!(super::solve_words_async(board.clone(), SolveOptions::default()));
+        let words = loop {
+            match future.as_mut().poll(&mut context) {
+                Poll::Ready(words) => break words,
+                Poll::Pending => std::thread::park(),
+            }
+        };
+        assert_eq!(words, super::solve_board(&board));
+
+        // Dropping an unfinished task cancels its search.
+        let cancel = CancelToken::new();
+        drop(super::SolveTask::spawn(board, SolveOptions { cancel: Some(cancel.clone()), ..Default::default() }));
+        assert!(cancel.is_cancelled());
+    }
+
+    #[test]
+    fn thread_pool() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+
+        let started = Arc::new(AtomicBool::new(false));
+        let pool = {
+            let started = Arc::clone(&started);
+            crate::pool::ThreadPool::with_start("test-pool", 1, Some(1), move || started.store(true, Ordering::SeqCst))
+                .unwrap()
+        };
+
+        // The thread runs its start hook first, and outlives a panicking job.
+        let (sender, receiver) = std::sync::mpsc::channel();
+        pool.execute(|| panic!("A job failed."));
+        pool.execute(move || sender.send(started.load(Ordering::SeqCst)).unwrap());
+        assert_eq!(receiver.recv(), Ok(true));
+    }
+
+    #[test]
+    fn java_scoring_modes() {
+        use crate::java::read_scoring_mode;
+
+        assert!(matches!(read_scoring_mode(0).unwrap(), ScoringMode::WordHunt));
+        assert!(matches!(read_scoring_mode(1).unwrap(), ScoringMode::Boggle));
+        assert!(matches!(read_scoring_mode(2).unwrap(), ScoringMode::Letters(_)));
+        assert!(read_scoring_mode(3).is_err());
+        assert!(read_scoring_mode(-1).is_err());
+    }
+
+    #[test]
+    fn java_board_layout() {
+        use crate::java::{to_coordinates, to_rows};
+
+        let board = Board::from_rows(&["abc", "def"]).unwrap();
+        assert_eq!(to_rows(&board), [["a", "b", "c"], ["d", "e", "f"]]);
+        assert_eq!(to_coordinates(&board, &[0, 4, 2]), [0, 0, 1, 1, 0, 2]);
+        assert!(to_coordinates(&board, &[]).is_empty());
+    }
+
+    #[test]
+    fn java_buffer_encoding() {
+        let found = [WordPaths { word: "cat".to_string(), paths: vec![vec![0, 1, 2], vec![3, 4, 5]] }];
+        assert_eq!(crate::java::encode_word_paths(&found).unwrap(), [
+            0, 0, 0, 1,
+            0, 0, 0, 3, b'c', b'a', b't',
+            0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2,
+        ]);
+        assert_eq!(crate::java::encode_word_paths(&[]).unwrap(), [0, 0, 0, 0]);
+
+        // Tile indices past the range of a short are kept whole.
+        let found = [WordPaths { word: "a".to_string(), paths: vec![vec![70_000]] }];
+        assert_eq!(crate::java::encode_word_paths(&found).unwrap()[13..], 70_000i32.to_be_bytes());
+    }
+
+    #[test]
+    fn java_hint_masking() {
+        use crate::java::mask_hint;
+
+        assert_eq!(mask_hint("water", 0), "_____");
+        assert_eq!(mask_hint("water", 1), "w____");
+        assert_eq!(mask_hint("water", 2), "water");
+        assert_eq!(mask_hint("", 1), "");
+    }
+
+    #[test]
+    fn java_grades() {
+        load_words();
+
+        let board = Board::from_rows(&["oetw", "rsae", "tnrh", "iuob"]).unwrap();
+        let options = SolveOptions::default();
+        let first = super::grade_answers(&board, &["rose", "water", "xyzzy"], &options);
+        let second = super::grade_answers(&board, &["rose"], &options);
+
+        let json = crate::java::grades_json(&[first.clone(), second], first.optimal_score);
+        assert!(json.starts_with(&format!("{{\"optimal_score\":{},\"players\":[{{", first.optimal_score)));
+        assert!(json.contains(r#"{"word":"rose","verdict":"accepted","score":400,"shared":true}"#));
+        assert!(json.contains(r#"{"word":"water","verdict":"accepted","score":800,"shared":false}"#));
+        assert!(json.contains(r#"{"word":"xyzzy","verdict":"not_a_word","score":0,"shared":false}"#));
+        assert!(crate::json::parse(&json).is_ok());
+    }
+
+    #[test]
+    fn java_capabilities() {
+        let capabilities = crate::java::capabilities();
+        let value = crate::json::parse(&capabilities).unwrap();
+        assert_eq!(value.get("version").and_then(crate::json::Value::as_str), Some(env!("CARGO_PKG_VERSION")));
+        assert!(capabilities.contains(r#""topologies":["square","hex","knight"],"dice_sets":["classic","big""#));
+        assert!(!capabilities.contains(' '));
+
+        let Some(crate::json::Value::Array(features)) = value.get("features") else { panic!("Expected features.") };
+        assert_eq!(features.contains(&crate::json::Value::String("parallel".to_string())), cfg!(feature = "parallel"));
+        assert_eq!(features.contains(&crate::json::Value::String("server".to_string())), cfg!(feature = "server"));
+    }
+}
@@ -0,0 +1,149 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+use crate::cancel::CancelToken;
+use crate::pattern::Pattern;
+use crate::scoring::ScoringMode;
+
+/// The order solved words are returned in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortOrder {
+    /// Longest words first, then alphabetically.
+    #[default]
+    Length,
+
+    /// Most valuable words first, then by length.
+    Score,
+
+    /// Most frequently used words first, then by length.
+    /// Only differs from [`SortOrder::Length`] when the dictionary has frequencies.
+    Rarity,
+}
+
+impl SortOrder {
+    /// The names sort orders are parsed from, in the order of the variants.
+    pub const NAMES: [&'static str; 3] = ["length", "score", "rarity"];
+}
+
+impl FromStr for SortOrder {
+    type Err = ParseNameError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "length" => Ok(SortOrder::Length),
+            "score" => Ok(SortOrder::Score),
+            "rarity" => Ok(SortOrder::Rarity),
+            name => Err(ParseNameError { kind: "sort order", name: name.to_string() }),
+        }
+    }
+}
+
+/// A name which doesn't match any variant, such as an unknown scoring mode.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseNameError {
+    /// What was being named, such as "scoring mode".
+    pub kind: &'static str,
+
+    /// The name which didn't match.
+    pub name: String,
+}
+
+impl fmt::Display for ParseNameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unknown {} {:?}.", self.kind, self.name)
+    }
+}
+
+impl std::error::Error for ParseNameError {}
+
+/// Options which change how a board is solved.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SolveOptions {
+    /// The minimum length of a word, in letters.
+    pub min_length: usize,
+
+    /// Whether a tile may be used more than once in a word.
+    pub allow_reuse: bool,
+
+    /// Whether every distinct path of a word is reported, instead of only the first.
+    pub all_paths: bool,
+
+    /// The widest a word's path may spread, in tiles.
+    /// Every path must fit in a window this many tiles across, in every direction.
+    /// For example, `Some(3)` keeps each path inside a 3x3 window.
+    pub max_span: Option<usize>,
+
+    /// The tiles words may use, by tile index.
+    /// Words are only found entirely inside the region; tiles past the end of the mask are left out.
+    pub region: Option<Vec<bool>>,
+
+    /// The indices of tiles that every word's path must pass through.
+    pub must_include: Vec<usize>,
+
+    /// The letters every word must start with.
+    /// Paths which can't spell the prefix are never explored.
+    pub prefix: Option<String>,
+
+    /// Letters every word must contain at least once, in lowercase.
+    /// Paths which can't fit the missing letters before the end of any word are never explored.
+    pub required_letters: Vec<char>,
+
+    /// A pattern every word must match.
+    pub pattern: Option<Pattern>,
+
+    /// Words to leave out of the results, in lowercase.
+    pub exclude: HashSet<String>,
+
+    /// The maximum number of words to return.
+    /// The best words, by the result order, are kept.
+    pub max_results: Option<usize>,
+
+    /// How words are scored.
+    pub scoring: ScoringMode,
+
+    /// The order scored words are returned in.
+    pub order: SortOrder,
+
+    /// A token which stops the search once cancelled.
+    /// Words found before the cancellation are still returned.
+    pub cancel: Option<CancelToken>,
+
+    /// The longest a search may run for.
+    /// Words found before the time runs out are still returned.
+    pub time_limit: Option<Duration>,
+}
+
+impl Default for SolveOptions {
+    fn default() -> Self {
+        Self {
+            min_length: 3,
+            allow_reuse: false,
+            all_paths: false,
+            max_span: None,
+            region: None,
+            must_include: Vec::new(),
+            prefix: None,
+            required_letters: Vec::new(),
+            pattern: None,
+            exclude: HashSet::new(),
+            max_results: None,
+            scoring: ScoringMode::default(),
+            order: SortOrder::default(),
+            cancel: None,
+            time_limit: None,
+        }
+    }
+}
+
+impl SolveOptions {
+    /// Checks if a word passes the options which don't depend on its path.
+    /// word: The word, in lowercase.
+    pub(crate) fn accepts_word(&self, word: &str) -> bool {
+        word.chars().count() >= self.min_length
+            && self.prefix.as_ref().is_none_or(|prefix| word.starts_with(prefix.as_str()))
+            && self.required_letters.iter().all(|&letter| word.contains(letter))
+            && self.pattern.as_ref().is_none_or(|pattern| pattern.matches(word))
+            && !self.exclude.contains(word)
+    }
+}
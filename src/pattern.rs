@@ -0,0 +1,96 @@
+/// A pattern that words must match.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Pattern {
+    /// A glob matched against the whole word.
+    /// `*` matches any run of letters and `?` matches any single letter.
+    Glob(String),
+
+    /// A simple regular expression, matched anywhere in the word.
+    /// Supports `.` (any letter), `*` (any repeat of the previous letter),
+    /// and the `^` and `$` anchors.
+    Regex(String),
+}
+
+impl Pattern {
+    /// Creates a glob pattern, such as `*ing`.
+    /// pattern: The glob.
+    pub fn glob(pattern: &str) -> Self {
+        Pattern::Glob(pattern.to_string())
+    }
+
+    /// Creates a regular expression pattern, such as `^..a`.
+    /// pattern: The regular expression.
+    pub fn regex(pattern: &str) -> Self {
+        Pattern::Regex(pattern.to_string())
+    }
+
+    /// Checks if a word matches the pattern.
+    /// word: The word to check.
+    pub fn matches(&self, word: &str) -> bool {
+        let word: Vec<char> = word.chars().collect();
+        match self {
+            Pattern::Glob(glob) => {
+                let glob: Vec<char> = glob.chars().collect();
+                match_glob(&glob, &word)
+            }
+            Pattern::Regex(regex) => {
+                let regex: Vec<char> = regex.chars().collect();
+                match_regex(&regex, &word)
+            }
+        }
+    }
+}
+
+/// Matches a glob against a whole word.
+/// glob: The glob.
+/// word: The word.
+fn match_glob(glob: &[char], word: &[char]) -> bool {
+    match glob.split_first() {
+        None => word.is_empty(),
+        Some(('*', rest)) => (0..=word.len()).any(|skip| match_glob(rest, &word[skip..])),
+        Some((&ch, rest)) => word.split_first()
+            .is_some_and(|(&letter, word)| (ch == '?' || ch == letter) && match_glob(rest, word)),
+    }
+}
+
+/// Matches a regular expression anywhere in a word.
+/// regex: The regular expression.
+/// word: The word.
+fn match_regex(regex: &[char], word: &[char]) -> bool {
+    if let Some(('^', rest)) = regex.split_first() {
+        return match_here(rest, word);
+    }
+
+    (0..=word.len()).any(|start| match_here(regex, &word[start..]))
+}
+
+/// Matches a regular expression at the start of a word.
+/// regex: The regular expression.
+/// word: The word.
+fn match_here(regex: &[char], word: &[char]) -> bool {
+    match regex {
+        [] => true,
+        ['$'] => word.is_empty(),
+        [ch, '*', rest @ ..] => match_star(*ch, rest, word),
+        [ch, rest @ ..] => word.split_first()
+            .is_some_and(|(&letter, word)| (*ch == '.' || *ch == letter) && match_here(rest, word)),
+    }
+}
+
+/// Matches any repeat of a letter, followed by the rest of a regular expression.
+/// ch: The repeated letter, or `.` for any letter.
+/// regex: The rest of the regular expression.
+/// word: The word.
+fn match_star(ch: char, regex: &[char], word: &[char]) -> bool {
+    let mut word = word;
+    loop {
+        if match_here(regex, word) {
+            return true;
+        }
+
+        match word.split_first() {
+            Some((&letter, rest)) if ch == '.' || ch == letter => word = rest,
+            _ => return false,
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+
+/// A job run on a thread pool.
+type Job = Box<dyn FnOnce() + Send>;
+
+/// A fixed number of threads which run jobs from a queue, so a burst of work waits its
+/// turn rather than starting a thread per job.
+pub(crate) struct ThreadPool {
+    jobs: Queue,
+}
+
+/// The sending end of a pool's queue.
+enum Queue {
+    /// Blocks senders once the queue is full, pushing back on whoever produces the jobs.
+    Bounded(mpsc::SyncSender<Job>),
+
+    /// Never blocks senders, for callers which mustn't wait, such as async tasks.
+    Unbounded(mpsc::Sender<Job>),
+}
+
+impl ThreadPool {
+    /// Starts the pool's threads.
+    /// The threads stop once the pool is dropped and every queued job has run.
+    /// name: The name of the pool's threads, followed by their index.
+    /// threads: The number of threads.
+    /// queue: How many jobs may wait for a thread before `execute` blocks, or `None` for no limit.
+    #[cfg(any(feature = "server", feature = "tokio"))]
+    pub(crate) fn new(name: &str, threads: usize, queue: Option<usize>) -> std::io::Result<Self> {
+        Self::with_start(name, threads, queue, || {})
+    }
+
+    /// Starts the pool's threads, running a hook on each thread before it takes any jobs,
+    /// such as attaching it to a JVM.
+    /// The threads stop once the pool is dropped and every queued job has run.
+    /// name: The name of the pool's threads, followed by their index.
+    /// threads: The number of threads.
+    /// queue: How many jobs may wait for a thread before `execute` blocks, or `None` for no limit.
+    /// start: The hook run on each thread when it starts.
+    pub(crate) fn with_start(
+        name: &str,
+        threads: usize,
+        queue: Option<usize>,
+        start: impl Fn() + Send + Sync + 'static
+    ) -> std::io::Result<Self> {
+        let (jobs, receiver) = match queue {
+            Some(queue) => {
+                let (jobs, receiver) = mpsc::sync_channel::<Job>(queue);
+                (Queue::Bounded(jobs), receiver)
+            }
+            None => {
+                let (jobs, receiver) = mpsc::channel::<Job>();
+                (Queue::Unbounded(jobs), receiver)
+            }
+        };
+        let receiver = Arc::new(Mutex::new(receiver));
+        let start = Arc::new(start);
+
+        for index in 0..threads.max(1) {
+            let receiver = Arc::clone(&receiver);
+            let start = Arc::clone(&start);
+            thread::Builder::new()
+                .name(format!("{name}-{index}"))
+                .spawn(move || {
+                    start();
+
+                    loop {
+                        // Release the queue before running the job, so other threads can take jobs.
+                        let job = receiver.lock().unwrap().recv();
+                        let Ok(job) = job else {
+                            break;
+                        };
+
+                        // A panicking job mustn't take its thread down with it.
+                        let _ = panic::catch_unwind(AssertUnwindSafe(job));
+                    }
+                })?;
+        }
+
+        Ok(Self { jobs })
+    }
+
+    /// Returns the number of threads to use for work that keeps every core busy.
+    pub(crate) fn default_threads() -> usize {
+        thread::available_parallelism().map_or(1, |threads| threads.get())
+    }
+
+    /// Queues a job, waiting for room in the queue if it is bounded and full.
+    /// job: The job to run.
+    pub(crate) fn execute(&self, job: impl FnOnce() + Send + 'static) {
+        // The threads only stop once the pool is dropped, so the queue is always open.
+        let _ = match &self.jobs {
+            Queue::Bounded(jobs) => jobs.send(Box::new(job)),
+            Queue::Unbounded(jobs) => jobs.send(Box::new(job)),
+        };
+    }
+}
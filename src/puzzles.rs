@@ -0,0 +1,113 @@
+use crate::analysis::{Difficulty, DifficultyLevel};
+use crate::board::{Board, BoardError};
+use crate::generate::{generate_seeded_board, DiceSet};
+use crate::json::{array, quote, word_match};
+use crate::options::SolveOptions;
+use crate::solver::{Solver, WordMatch};
+
+/// A pre-generated board, along with its solution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Puzzle {
+    /// The seed the board was generated from.
+    pub seed: u64,
+
+    /// The number of rows of the board.
+    pub rows: usize,
+
+    /// The number of columns of the board.
+    pub cols: usize,
+
+    /// The tiles of the board, row after row.
+    pub tiles: Vec<String>,
+
+    /// Every word on the board, in the configured sort order.
+    pub words: Vec<WordMatch>,
+
+    /// The points earned by finding every word.
+    pub total_score: u32,
+
+    /// How hard the board is.
+    pub difficulty: Difficulty,
+}
+
+/// A batch of pre-generated puzzles for a game client.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PuzzlePack {
+    pub puzzles: Vec<Puzzle>,
+}
+
+impl Solver {
+    /// Generates and solves a puzzle for every seed.
+    /// dice: The dice to roll.
+    /// seeds: The seeds of the puzzles, such as the numbers of daily puzzles.
+    /// options: The options to solve with.
+    pub fn generate_pack(
+        &self,
+        dice: &DiceSet,
+        seeds: impl IntoIterator<Item = u64>,
+        options: &SolveOptions
+    ) -> Result<PuzzlePack, BoardError> {
+        let options = SolveOptions { max_results: None, ..options.clone() };
+        let puzzles = seeds.into_iter()
+            .map(|seed| {
+                let board = generate_seeded_board(dice, seed)?;
+                Ok(self.to_puzzle(&board, seed, &options))
+            })
+            .collect::<Result<_, BoardError>>()?;
+
+        Ok(PuzzlePack { puzzles })
+    }
+
+    /// Solves a board as a puzzle.
+    /// board: The game board.
+    /// seed: The seed the board was generated from.
+    /// options: The options to solve with.
+    fn to_puzzle(&self, board: &Board, seed: u64, options: &SolveOptions) -> Puzzle {
+        let words = self.find_matches(board, options);
+        let lengths_and_scores: Vec<(usize, u32)> = words.iter()
+            .map(|found| (found.length, found.score))
+            .collect();
+
+        Puzzle {
+            seed,
+            rows: board.rows(),
+            cols: board.cols(),
+            tiles: (0..board.len()).map(|index| board.cell(index).to_string()).collect(),
+            total_score: words.iter().map(|found| found.score).fold(0, u32::saturating_add),
+            words,
+            difficulty: Self::rate_difficulty(board, &lengths_and_scores),
+        }
+    }
+}
+
+impl PuzzlePack {
+    /// Serializes the pack as JSON for the game client.
+    /// Seeds are written as strings, since JavaScript numbers can't hold every 64-bit seed.
+    pub fn to_json(&self) -> String {
+        format!("{{\"puzzles\":{}}}", array(&self.puzzles, Puzzle::to_json))
+    }
+}
+
+impl Puzzle {
+    /// Serializes the puzzle as JSON.
+    pub fn to_json(&self) -> String {
+        let level = match self.difficulty.level {
+            DifficultyLevel::Easy => "easy",
+            DifficultyLevel::Medium => "medium",
+            DifficultyLevel::Hard => "hard",
+        };
+
+        format!(
+            "{{\"seed\":{},\"rows\":{},\"cols\":{},\"tiles\":{},\"total_score\":{},\
+            \"difficulty\":{{\"rating\":{:.3},\"level\":{}}},\"words\":{}}}",
+            quote(&self.seed.to_string()),
+            self.rows,
+            self.cols,
+            array(&self.tiles, |tile| quote(tile)),
+            self.total_score,
+            self.difficulty.rating,
+            quote(level),
+            array(&self.words, word_match)
+        )
+    }
+}
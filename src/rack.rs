@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+use crate::options::SolveOptions;
+use crate::solver::{by_length, rank, Solver};
+use crate::trie_node::TrieNode;
+
+/// The state of a search over a rack of letters.
+struct RackSearch<'a> {
+    options: &'a SolveOptions,
+    letters: HashMap<char, usize>,
+    blanks: usize,
+    current_word: String,
+    words: Vec<String>,
+}
+
+impl Solver {
+    /// Finds all words which can be spelled from a rack of letters, in any order.
+    /// Each letter may only be used once; "*" and "?" are blanks which stand for any letter.
+    /// Tiles and paths don't apply to racks, so the tile-based options are ignored.
+    /// letters: The letters of the rack, such as "retains?".
+    /// options: The options to solve with.
+    pub fn solve_rack(&self, letters: &str, options: &SolveOptions) -> Vec<String> {
+        let mut search = RackSearch {
+            options,
+            letters: HashMap::new(),
+            blanks: 0,
+            current_word: String::new(),
+            words: Vec::new(),
+        };
+
+        for letter in letters.to_lowercase().chars().filter(|letter| !letter.is_whitespace()) {
+            match letter {
+                '*' | '?' => search.blanks += 1,
+                letter => *search.letters.entry(letter).or_default() += 1,
+            }
+        }
+
+        search.visit(self.dictionary());
+        rank(&mut search.words, options.max_results, |a, b| by_length(a, b));
+
+        search.words
+    }
+}
+
+impl RackSearch<'_> {
+    /// Tries every letter left on the rack after the current word.
+    /// Every word is reached by exactly one path through the trie, so words are never repeated.
+    /// node: The trie node of the current word.
+    fn visit(&mut self, node: &TrieNode) {
+        if node.is_end_of_word() {
+            self.record();
+        }
+
+        for (letter, next) in node.children() {
+            // Prefer real letters, saving the blanks for letters the rack doesn't have.
+            let from_rack = self.letters.get(&letter).is_some_and(|&count| count > 0);
+            if from_rack {
+                *self.letters.get_mut(&letter).unwrap() -= 1;
+            } else if self.blanks > 0 {
+                self.blanks -= 1;
+            } else {
+                continue;
+            }
+
+            self.current_word.push(letter);
+            self.visit(next);
+            self.current_word.pop();
+
+            if from_rack {
+                *self.letters.get_mut(&letter).unwrap() += 1;
+            } else {
+                self.blanks += 1;
+            }
+        }
+    }
+
+    /// Records the current word if it passes the options.
+    fn record(&mut self) {
+        if self.options.accepts_word(&self.current_word) {
+            self.words.push(self.current_word.clone());
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use crate::trie_node::TrieNode;
+
+/// Words used at least this many times per million words are common.
+const COMMON_PER_MILLION: u128 = 10;
+
+/// Words used at least this many times per million words are uncommon.
+const UNCOMMON_PER_MILLION: u128 = 1;
+
+/// How often a word is used, according to a frequency-weighted dictionary.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Rarity {
+    Common,
+    Uncommon,
+    Rare,
+}
+
+impl Rarity {
+    /// Buckets a word by how often it is used.
+    /// Words without a frequency in a weighted dictionary are rare.
+    /// Returns nothing if the dictionary has no frequencies.
+    /// dictionary: The dictionary the word was found in.
+    /// word: The word.
+    pub(crate) fn of(dictionary: &TrieNode, word: &str) -> Option<Self> {
+        let total = u128::from(dictionary.total_frequency());
+        if total == 0 {
+            return None;
+        }
+
+        let frequency = dictionary.descend(word).map_or(0, TrieNode::frequency);
+        let per_million = u128::from(frequency) * 1_000_000 / total;
+        Some(if per_million >= COMMON_PER_MILLION {
+            Rarity::Common
+        } else if per_million >= UNCOMMON_PER_MILLION {
+            Rarity::Uncommon
+        } else {
+            Rarity::Rare
+        })
+    }
+}
@@ -0,0 +1,106 @@
+use std::io::{BufRead, Write};
+use crate::json::{self, Value};
+use crate::{load_compiled_dictionary_bytes, load_dictionary_contents};
+
+// Requests and responses are JSON-RPC 2.0 objects, one per line. Requests without an
+// id are notifications, and get no response.
+//
+//   {"jsonrpc":"2.0","id":1,"method":"load","params":{"path":"words.txt"}}
+//   {"jsonrpc":"2.0","id":2,"method":"solve","params":{"board":["oetw","rsae"],"options":{"min_length":4}}}
+//   {"jsonrpc":"2.0","id":3,"method":"validate","params":{"words":["cat"],"board":["cat","dog"]}}
+//   {"jsonrpc":"2.0","id":4,"method":"stats"}
+
+const PARSE_ERROR: i32 = -32700;
+const INVALID_REQUEST: i32 = -32600;
+const METHOD_NOT_FOUND: i32 = -32601;
+const INVALID_PARAMS: i32 = -32602;
+
+/// Answers newline-delimited JSON-RPC requests until the input ends.
+/// Every request shares the loaded dictionary, so a host can start the solver as a
+/// subprocess and load the dictionary once.
+/// input: Where requests are read from, such as stdin.
+/// output: Where responses are written to, such as stdout.
+pub fn serve_rpc(input: impl BufRead, mut output: impl Write) -> std::io::Result<()> {
+    for line in input.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        if let Some(response) = respond(&line) {
+            writeln!(output, "{response}")?;
+            output.flush()?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Answers a single request.
+/// Returns nothing for notifications.
+/// line: The request.
+fn respond(line: &str) -> Option<String> {
+    let request = match json::parse(line) {
+        Ok(request) => request,
+        Err(message) => return Some(error(&Value::Null, PARSE_ERROR, &message)),
+    };
+
+    let id = request.get("id").cloned();
+    let id_json = id.clone().unwrap_or(Value::Null);
+    let Some(method) = request.get("method").and_then(Value::as_str) else {
+        return Some(error(&id_json, INVALID_REQUEST, "The request needs a method."));
+    };
+
+    let params = request.get("params").unwrap_or(&Value::Null);
+    let result = match method {
+        "load" => load(params),
+        "solve" => json::solve_request(params, None),
+        "validate" => json::validate_request(params),
+        "stats" => Ok(json::dictionary_stats()),
+        method => return id.map(|id| error(&id, METHOD_NOT_FOUND, &format!("Unknown method {method}."))),
+    };
+
+    let id = id?;
+    Some(match result {
+        Ok(result) => format!("{{\"jsonrpc\":\"2.0\",\"id\":{},\"result\":{result}}}", write_id(&id)),
+        Err(message) => error(&id, INVALID_PARAMS, &message),
+    })
+}
+
+/// Loads a dictionary file or compiled dictionary into the dictionary.
+/// Returns the dictionary stats once it is loaded.
+/// params: The parameters, holding the `path` of the dictionary.
+fn load(params: &Value) -> Result<String, String> {
+    let path = params.get("path").and_then(Value::as_str).ok_or("The request needs a path.")?;
+    let bytes = std::fs::read(path).map_err(|error| format!("Couldn't read {path}: {error}"))?;
+
+    if load_compiled_dictionary_bytes(&bytes, path.to_string()).is_err() {
+        let contents = String::from_utf8(bytes).map_err(|_| format!("{path} isn't UTF-8."))?;
+        load_dictionary_contents(&contents, path.to_string());
+    }
+
+    Ok(json::dictionary_stats())
+}
+
+/// Writes an error response.
+/// id: The id of the request.
+/// code: The JSON-RPC error code.
+/// message: The description of the error.
+fn error(id: &Value, code: i32, message: &str) -> String {
+    format!(
+        "{{\"jsonrpc\":\"2.0\",\"id\":{},\"error\":{{\"code\":{code},\"message\":{}}}}}",
+        write_id(id),
+        json::quote(message)
+    )
+}
+
+/// Writes the id of a request back as JSON.
+/// Ids are numbers or strings; anything else is written as `null`.
+/// id: The id of the request.
+fn write_id(id: &Value) -> String {
+    match id {
+        Value::Number(number) => number.to_string(),
+        Value::String(id) => json::quote(id),
+        _ => "null".to_string(),
+    }
+}
@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use crate::board::Board;
+use crate::options::ParseNameError;
+
+/// The ways words can be scored.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum ScoringMode {
+    /// GamePigeon Word Hunt points.
+    /// 3 letters are worth 100, 4 are worth 400, 5 are worth 800, and so on.
+    #[default]
+    WordHunt,
+
+    /// Official Boggle points.
+    /// 3 and 4 letters are worth 1, 5 are worth 2, 6 are worth 3, 7 are worth 5,
+    /// and 8 or more are worth 11.
+    Boggle,
+
+    /// Scrabble-style points.
+    /// A word is worth the sum of its letters' values, plus a length bonus.
+    Letters(LetterValues),
+}
+
+impl FromStr for ScoringMode {
+    type Err = ParseNameError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "wordhunt" => Ok(ScoringMode::WordHunt),
+            "boggle" => Ok(ScoringMode::Boggle),
+            "scrabble" => Ok(ScoringMode::Letters(LetterValues::scrabble())),
+            name => Err(ParseNameError { kind: "scoring mode", name: name.to_string() }),
+        }
+    }
+}
+
+/// The points each letter is worth.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LetterValues {
+    /// The value of each letter.
+    /// Letters without a value are worth nothing.
+    pub values: HashMap<char, u32>,
+
+    /// The bonus awarded for a word's length, indexed by length.
+    /// Words longer than the list receive the last bonus.
+    pub length_bonuses: Vec<u32>,
+}
+
+impl LetterValues {
+    /// Creates letter values without length bonuses.
+    /// values: The value of each letter.
+    pub fn new(values: HashMap<char, u32>) -> Self {
+        Self { values, length_bonuses: Vec::new() }
+    }
+
+    /// Creates the letter values of English Scrabble.
+    pub fn scrabble() -> Self {
+        let groups: [(&str, u32); 7] = [
+            ("aeilnorstu", 1),
+            ("dg", 2),
+            ("bcmp", 3),
+            ("fhvwy", 4),
+            ("k", 5),
+            ("jx", 8),
+            ("qz", 10),
+        ];
+
+        let values = groups.iter()
+            .flat_map(|&(letters, value)| letters.chars().map(move |ch| (ch, value)))
+            .collect();
+
+        Self::new(values)
+    }
+
+    /// Scores a word.
+    /// word: The word to score.
+    pub fn score(&self, word: &str) -> u32 {
+        let letters = word.chars().map(|ch| self.value(ch)).fold(0, u32::saturating_add);
+        letters.saturating_add(self.length_bonus(word.chars().count()))
+    }
+
+    /// Returns the value of a letter.
+    /// letter: The letter.
+    pub fn value(&self, letter: char) -> u32 {
+        self.values.get(&letter).copied().unwrap_or(0)
+    }
+
+    /// Returns the bonus awarded for a word's length.
+    /// length: The length of the word, in letters.
+    pub fn length_bonus(&self, length: usize) -> u32 {
+        match self.length_bonuses.get(length) {
+            Some(&bonus) => bonus,
+            None => self.length_bonuses.last().copied().unwrap_or(0),
+        }
+    }
+}
+
+impl ScoringMode {
+    /// The names scoring modes are parsed from, in the order of the variants.
+    /// "scrabble" uses Scrabble letter values.
+    pub const NAMES: [&'static str; 3] = ["wordhunt", "boggle", "scrabble"];
+
+    /// Scores a word.
+    /// word: The word to score.
+    pub fn score(&self, word: &str) -> u32 {
+        let length = word.chars().count();
+        match self {
+            ScoringMode::WordHunt => match length {
+                0..=2 => 0,
+                3 => 100,
+                4 => 400,
+                5 => 800,
+                6 => 1400,
+                7 => 1800,
+                _ => 2200 + 400 * (length as u32 - 8),
+            },
+            ScoringMode::Boggle => match length {
+                0..=2 => 0,
+                3 | 4 => 1,
+                5 => 2,
+                6 => 3,
+                7 => 5,
+                _ => 11,
+            },
+            ScoringMode::Letters(values) => values.score(word),
+        }
+    }
+
+    /// Scores a word traced on a board, applying the bonuses of its tiles.
+    /// Letter bonuses only matter when scoring by letter values.
+    /// Length bonuses are never multiplied.
+    /// Scores too large for a `u32`, such as from long paths over many word bonuses,
+    /// are capped at `u32::MAX`.
+    /// board: The game board.
+    /// word: The word to score.
+    /// path: The tile path of the word.
+    pub fn score_path(&self, board: &Board, word: &str, path: &[usize]) -> u32 {
+        let word_factor: u32 = path.iter()
+            .map(|&index| board.multiplier(index).word_factor())
+            .fold(1, u32::saturating_mul);
+
+        let ScoringMode::Letters(values) = self else {
+            return self.score(word).saturating_mul(word_factor);
+        };
+
+        // Walk the word alongside its path to find which letters each tile provided.
+        let mut letters = word.chars();
+        let mut points = 0;
+        for &index in path {
+            let count = if board.is_wildcard(index) {
+                1
+            } else {
+                board.cell(index).chars().count()
+            };
+
+            let tile_points = letters.by_ref()
+                .take(count)
+                .map(|ch| values.value(ch))
+                .fold(0, u32::saturating_add);
+            points = tile_points
+                .saturating_mul(board.multiplier(index).letter_factor())
+                .saturating_add(points);
+        }
+
+        points
+            .saturating_mul(word_factor)
+            .saturating_add(values.length_bonus(word.chars().count()))
+    }
+}
+
+/// A word and the points it is worth.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScoredWord {
+    pub word: String,
+    pub score: u32,
+}
+
+/// The scored words of a board.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ScoredBoard {
+    /// The scored words, in result order.
+    pub words: Vec<ScoredWord>,
+
+    /// The points earned by finding every word on the board.
+    pub total_score: u32,
+
+    /// Whether the search stopped before covering the whole board.
+    /// The words and total score only cover what was found in time.
+    pub truncated: bool,
+}
@@ -0,0 +1,181 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{Shutdown, TcpListener, TcpStream, ToSocketAddrs};
+use std::time::Duration;
+use crate::json;
+use crate::pool::ThreadPool;
+
+// Each connection carries one request, handled by a fixed pool of threads. Every request
+// shares the loaded dictionary, so it only has to be loaded once for the whole server.
+//
+//   POST /solve             {"board":["oetw","rsae"],"options":{"min_length":4}}
+//   POST /validate          {"words":["cat","xyzzy"],"board":["cat","dog"]}
+//   GET  /dictionary/stats
+
+/// Request lines and headers longer than this in total are rejected.
+const MAX_HEADER_LENGTH: u64 = 16 * 1024;
+
+/// Bodies larger than this are rejected, so a client can't exhaust the server's memory.
+const MAX_BODY_LENGTH: usize = 1024 * 1024;
+
+/// How long a client may take to send its request or receive the response.
+const IO_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The longest a single solve may run for.
+const SOLVE_TIME_LIMIT: Duration = Duration::from_secs(5);
+
+/// How many accepted connections may wait for a thread before new ones stop being accepted.
+const QUEUED_CONNECTIONS: usize = 64;
+
+/// Serves the solver over HTTP until the listener fails.
+/// address: The address to listen on, such as "127.0.0.1:8080".
+pub fn serve(address: impl ToSocketAddrs) -> std::io::Result<()> {
+    serve_listener(TcpListener::bind(address)?)
+}
+
+/// Serves the solver over HTTP on a listener which is already bound.
+/// Requests are answered by one thread per core.
+/// listener: The listener to accept connections from.
+pub fn serve_listener(listener: TcpListener) -> std::io::Result<()> {
+    log::info!("Serving the solver on {}.", listener.local_addr()?);
+    let pool = ThreadPool::new("word-hunt-server", ThreadPool::default_threads(), Some(QUEUED_CONNECTIONS))?;
+
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => pool.execute(move || {
+                if let Err(error) = handle_connection(stream) {
+                    log::warn!("Couldn't answer a request: {error}");
+                }
+            }),
+            Err(error) => log::warn!("Couldn't accept a connection: {error}"),
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads a request from a connection and writes the response.
+/// stream: The connection.
+fn handle_connection(stream: TcpStream) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(IO_TIMEOUT))?;
+    stream.set_write_timeout(Some(IO_TIMEOUT))?;
+
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let request = read_request(&mut reader)?;
+    let (status, body) = match &request {
+        Ok((method, path, body)) => route(method, path, body),
+        Err(status) => (*status, error(status_text(*status))),
+    };
+
+    let mut stream = stream;
+    write!(
+        stream,
+        "HTTP/1.1 {status} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        status_text(status),
+        body.len()
+    )?;
+    stream.flush()?;
+
+    // Closing with part of a rejected request unread would reset the connection and
+    // could lose the response, so a bounded amount of the rest is read and discarded.
+    if request.is_err() {
+        stream.shutdown(Shutdown::Write)?;
+        let _ = std::io::copy(&mut reader.take(MAX_BODY_LENGTH as u64), &mut std::io::sink());
+    }
+
+    Ok(())
+}
+
+/// Reads the method, path and body of a request.
+/// Returns the status to respond with if the request is malformed or too large.
+/// reader: The connection.
+fn read_request(reader: &mut impl BufRead) -> std::io::Result<Result<(String, String, String), u16>> {
+    // The request line and headers share one budget, so an endless line can't exhaust memory.
+    let mut head = reader.take(MAX_HEADER_LENGTH);
+    let mut read_line = |line: &mut String| -> std::io::Result<Result<(), u16>> {
+        line.clear();
+        head.read_line(line)?;
+        Ok(if line.ends_with('\n') { Ok(()) } else if head.limit() == 0 { Err(431) } else { Err(400) })
+    };
+
+    let mut line = String::new();
+    if let Err(status) = read_line(&mut line)? {
+        return Ok(Err(status));
+    }
+
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+        return Ok(Err(400));
+    };
+    let (method, path) = (method.to_string(), path.to_string());
+
+    let mut content_length = 0;
+    loop {
+        if let Err(status) = read_line(&mut line)? {
+            return Ok(Err(status));
+        }
+
+        if line.trim().is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                let Ok(length) = value.trim().parse() else {
+                    return Ok(Err(400));
+                };
+                content_length = length;
+            }
+        }
+    }
+
+    if content_length > MAX_BODY_LENGTH {
+        return Ok(Err(413));
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+    match String::from_utf8(body) {
+        Ok(body) => Ok(Ok((method, path, body))),
+        Err(_) => Ok(Err(400)),
+    }
+}
+
+/// Answers a request.
+/// Returns the status and body of the response.
+/// method: The method of the request.
+/// path: The path of the request.
+/// body: The body of the request.
+fn route(method: &str, path: &str, body: &str) -> (u16, String) {
+    let handler: fn(&json::Value) -> Result<String, String> = match (method, path) {
+        ("POST", "/solve") => |request| json::solve_request(request, Some(SOLVE_TIME_LIMIT)),
+        ("POST", "/validate") => json::validate_request,
+        ("GET", "/dictionary/stats") => return (200, json::dictionary_stats()),
+        (_, "/solve" | "/validate" | "/dictionary/stats") => return (405, error(status_text(405))),
+        _ => return (404, error(status_text(404))),
+    };
+
+    match json::parse(body).and_then(|request| handler(&request)) {
+        Ok(response) => (200, response),
+        Err(message) => (400, error(&message)),
+    }
+}
+
+/// Writes an error as a JSON object.
+/// message: The description of the error.
+fn error(message: &str) -> String {
+    format!("{{\"error\":{}}}", json::quote(message))
+}
+
+/// Returns the reason phrase of a status code.
+/// status: The status code.
+fn status_text(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Payload Too Large",
+        431 => "Request Header Fields Too Large",
+        _ => "Internal Server Error",
+    }
+}
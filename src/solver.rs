@@ -1,16 +1,117 @@
-use std::collections::HashSet;
-use crate::DICTIONARY;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Instant;
+use crate::{DICTIONARY, NAMED_DICTIONARIES};
+use crate::board::Board;
+use crate::cancel::CancelToken;
+use crate::json;
+use crate::options::{SolveOptions, SortOrder};
+use crate::rarity::Rarity;
+use crate::scoring::{ScoredBoard, ScoredWord};
 use crate::trie_node::TrieNode;
 
-/// All valid directions for locating adjacent characters.
-const DIRECTIONS: [(i32, i32); 8] = [
-    (-1, -1), (-1, 0), (-1, 1),
-    (0, -1),           (0, 1),
-    (1, -1),  (1, 0),  (1, 1),
-];
+/// A word found on a board, along with the letters assumed for its wildcard tiles.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WildcardWord {
+    /// The word, with wildcards replaced by their letters.
+    pub word: String,
+
+    /// The letter assumed for each wildcard tile, by tile index.
+    /// Empty when the word doesn't use any wildcards.
+    pub assumed: Vec<(usize, char)>,
+}
+
+/// A word found on a board, along with the ways it can be traced.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WordPaths {
+    pub word: String,
+
+    /// The tile indices of each distinct path spelling the word.
+    pub paths: Vec<Vec<usize>>,
+}
+
+/// A word found on a board, with everything known about it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WordMatch {
+    pub word: String,
+
+    /// The tile indices spelling the word.
+    pub path: Vec<usize>,
+
+    /// The points the word is worth, under the configured scoring mode.
+    pub score: u32,
+
+    /// The length of the word, in letters.
+    pub length: usize,
+
+    /// The tag of the dictionary the word was found in, if it has one.
+    pub dictionary_tag: Option<String>,
+
+    /// How often the word is used.
+    /// Only known when the dictionary has frequencies.
+    pub rarity: Option<Rarity>,
+}
+
+impl WordMatch {
+    /// Serializes the word as JSON, with its score and path.
+    pub fn to_json(&self) -> String {
+        json::word_match(self)
+    }
+}
+
+/// A single way of tracing a word on a board.
+struct Trace {
+    path: Vec<usize>,
+    assumed: Vec<(usize, char)>,
+}
+
+/// A word found by a search, along with how it was traced.
+struct Found {
+    word: String,
+    traces: Vec<Trace>,
+}
+
+/// A pending step of a search.
+enum Step<'t> {
+    /// Tries to move onto a tile.
+    Visit { node: &'t TrieNode, index: usize },
+
+    /// Moves onto a tile, consuming its letters.
+    /// Wildcard tiles consume the given letter instead.
+    Enter { node: &'t TrieNode, index: usize, wildcard: Option<char> },
+
+    /// Moves back off of a tile, restoring the search to how it was before.
+    Leave { index: usize, was_visited: bool, word_length: usize, wildcard: bool },
+}
+
+/// Called with each word found by a streaming search, and its path.
+type WordCallback<'a> = dyn FnMut(&str, &[usize]) + 'a;
+
+/// The mutable state of a single search over a board.
+struct Search<'a> {
+    options: &'a SolveOptions,
+    visited: Vec<bool>,
+    current_word: String,
+    path: Vec<usize>,
+    assumed: Vec<(usize, char)>,
+    words: HashMap<String, Vec<Trace>>,
+    on_word: Option<&'a mut WordCallback<'a>>,
+    counted: Option<HashSet<*const TrieNode>>,
+    deadline: Option<Instant>,
+    steps: usize,
+    stopped: bool,
+}
 
 pub struct Solver {
-    word_trie: TrieNode
+    word_trie: Arc<TrieNode>,
+    dictionary_tag: Option<String>
+}
+
+impl Default for Solver {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Solver {
@@ -18,96 +119,906 @@ impl Solver {
     /// Resolves words from the global dictionary.
     pub fn new() -> Self {
         let dictionary = DICTIONARY.read().unwrap();
-        Self { word_trie: dictionary.clone() }
+        Self::with_dictionary(Arc::clone(&dictionary))
+    }
+
+    /// Creates a new solver instance.
+    /// dictionary: The dictionary to resolve words from.
+    pub fn with_dictionary(dictionary: Arc<TrieNode>) -> Self {
+        Self { word_trie: dictionary, dictionary_tag: None }
     }
 
-    /// Finds all valid words in a 2D board.
+    /// Creates a new solver instance.
+    /// Resolves words from a named dictionary, and tags every match with its name.
+    /// Returns `None` if there is no dictionary with the name.
+    /// name: The name of the dictionary.
+    pub fn with_named_dictionary(name: &str) -> Option<Self> {
+        let dictionaries = NAMED_DICTIONARIES.read().unwrap();
+        let dictionary = dictionaries.get(name)?;
+        Some(Self::with_dictionary(Arc::clone(dictionary)).with_tag(name))
+    }
+
+    /// Tags every match found by this solver with the name of its dictionary.
+    /// tag: The name of the dictionary.
+    pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
+        self.dictionary_tag = Some(tag.into());
+        self
+    }
+
+    /// Returns the dictionary words are resolved from.
+    pub(crate) fn dictionary(&self) -> &TrieNode {
+        &self.word_trie
+    }
+
+    /// Describes a word found on a board.
     /// board: The game board.
-    pub fn find_all_words(&self, board: &[Vec<String>]) -> Vec<String> {
-        let mut words = HashSet::new();
-        let rows = board.len();
-        let cols = board[0].len();
-        let mut visited = vec![vec![false; cols]; rows];
-        let mut current_word = String::new();
+    /// options: The options to solve with.
+    /// word: The word.
+    /// path: The tile path of the word.
+    fn to_match(&self, board: &Board, options: &SolveOptions, word: &str, path: &[usize]) -> WordMatch {
+        WordMatch {
+            word: word.to_string(),
+            path: path.to_vec(),
+            score: options.scoring.score_path(board, word, path),
+            length: word.chars().count(),
+            dictionary_tag: self.dictionary_tag.clone(),
+            rarity: Rarity::of(&self.word_trie, word),
+        }
+    }
 
-        for row in 0..rows {
-            for col in 0..cols {
-                self.visit(
-                    board,
-                    row,
-                    col,
-                    &mut visited,
-                    &mut current_word,
-                    &mut words
-                );
-            }
+    /// Returns how often a word is used, or 0 if the dictionary doesn't know.
+    /// word: The word.
+    fn frequency(&self, word: &str) -> u64 {
+        self.word_trie.descend(word).map_or(0, TrieNode::frequency)
+    }
+
+    /// Finds all valid words on a board.
+    /// board: The game board.
+    /// options: The options to solve with.
+    pub fn find_all_words(&self, board: &Board, options: &SolveOptions) -> Vec<String> {
+        let (found, _) = self.search(board, options);
+        Self::rank_words(found, options)
+    }
+
+    /// Orders found words from longest to shortest, keeping only the best of them.
+    /// found: The words found by a search.
+    /// options: The options the search ran with.
+    fn rank_words(found: Vec<Found>, options: &SolveOptions) -> Vec<String> {
+        let mut words: Vec<String> = found.into_iter().map(|found| found.word).collect();
+        rank(&mut words, options.max_results, |a, b| by_length(a, b));
+
+        words
+    }
+
+    /// Finds all valid words on several boards.
+    /// Results are in the same order as the boards.
+    /// With the parallel feature, the boards are spread across threads, each searching its
+    /// boards one starting tile at a time so the threads aren't multiplied.
+    /// boards: The game boards.
+    /// options: The options to solve with.
+    pub fn solve_many(&self, boards: &[Board], options: &SolveOptions) -> Vec<Vec<String>> {
+        #[cfg(feature = "parallel")]
+        if boards.len() > 1 {
+            return self.solve_many_parallel(boards, options);
         }
 
-        let mut result: Vec<String> = words.into_iter()
-            .filter(|word| word.len() >= 3)
+        boards.iter()
+            .map(|board| self.find_all_words(board, options))
+            .collect()
+    }
+
+    /// Finds all valid words on several boards, spreading the boards across threads.
+    /// Results are in the same order as the boards.
+    /// boards: The game boards.
+    /// options: The options to solve with.
+    #[cfg(feature = "parallel")]
+    fn solve_many_parallel(&self, boards: &[Board], options: &SolveOptions) -> Vec<Vec<String>> {
+        let threads = std::thread::available_parallelism()
+            .map_or(1, std::num::NonZeroUsize::get)
+            .min(boards.len())
+            .max(1);
+
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = boards.chunks(boards.len().div_ceil(threads))
+                .map(|chunk| scope.spawn(move || {
+                    chunk.iter()
+                        .map(|board| {
+                            let (words, stopped) = self.search_from(board, options, 0..board.len());
+                            let (found, _) = Self::finish_search(board, options, words, stopped);
+                            Self::rank_words(found, options)
+                        })
+                        .collect::<Vec<_>>()
+                }))
+                .collect();
+
+            handles.into_iter()
+                .flat_map(|handle| handle.join().expect("A search thread panicked."))
+                .collect()
+        })
+    }
+
+    /// Finds all valid words on a board.
+    /// Reports which letter was assumed for every wildcard tile in a word.
+    /// board: The game board.
+    /// options: The options to solve with.
+    pub fn find_wildcard_words(&self, board: &Board, options: &SolveOptions) -> Vec<WildcardWord> {
+        let (found, _) = self.search(board, options);
+        let mut result: Vec<WildcardWord> = found.into_iter()
+            .map(|mut found| WildcardWord {
+                word: found.word,
+                assumed: found.traces.swap_remove(0).assumed,
+            })
             .collect();
+        rank(&mut result, options.max_results, |a, b| by_length(&a.word, &b.word));
 
-        result.sort_by(|a, b| {
-            b.len().cmp(&a.len()).then(a.cmp(b))
-        });
+        result
+    }
+
+    /// Finds all valid words on a board, along with their paths.
+    /// Every distinct path is reported when the `all_paths` option is set.
+    /// board: The game board.
+    /// options: The options to solve with.
+    pub fn find_word_paths(&self, board: &Board, options: &SolveOptions) -> Vec<WordPaths> {
+        let (found, _) = self.search(board, options);
+        let mut result: Vec<WordPaths> = found.into_iter()
+            .map(|found| WordPaths {
+                word: found.word,
+                paths: found.traces.into_iter().map(|trace| trace.path).collect(),
+            })
+            .collect();
+        rank(&mut result, options.max_results, |a, b| by_length(&a.word, &b.word));
 
         result
     }
 
-    /// Visits a position on the game board.
+    /// Finds and scores all valid words on a board.
+    /// Words are returned in the configured sort order.
+    /// The total score counts every word, even when the results are limited.
+    /// Reports whether the search was cut short by a cancellation or time limit.
+    /// Words are scored by their best path when the board has bonus tiles.
+    /// board: The game board.
+    /// options: The options to solve with.
+    pub fn score_words(&self, board: &Board, options: &SolveOptions) -> ScoredBoard {
+        // Every path is needed to find the one which makes the best use of the bonus tiles.
+        let search_options = SolveOptions {
+            all_paths: options.all_paths || board.has_multipliers(),
+            ..options.clone()
+        };
+
+        let (found, truncated) = self.search(board, &search_options);
+        let mut words: Vec<ScoredWord> = found.into_iter()
+            .map(|found| ScoredWord {
+                score: found.traces.iter()
+                    .map(|trace| options.scoring.score_path(board, &found.word, &trace.path))
+                    .max()
+                    .unwrap_or(0),
+                word: found.word,
+            })
+            .collect();
+
+        let total_score = words.iter().map(|found| found.score).fold(0, u32::saturating_add);
+        match options.order {
+            SortOrder::Length => rank(&mut words, options.max_results, |a, b| {
+                by_length(&a.word, &b.word)
+            }),
+            SortOrder::Score => rank(&mut words, options.max_results, |a, b| {
+                b.score.cmp(&a.score).then(by_length(&a.word, &b.word))
+            }),
+            SortOrder::Rarity => rank(&mut words, options.max_results, |a, b| {
+                self.frequency(&b.word).cmp(&self.frequency(&a.word)).then(by_length(&a.word, &b.word))
+            }),
+        }
+
+        ScoredBoard { words, total_score, truncated }
+    }
+
+    /// Finds all valid words on a board, describing each word fully.
+    /// Words are returned in the configured sort order, each with its best path.
+    /// board: The game board.
+    /// options: The options to solve with.
+    pub fn find_matches(&self, board: &Board, options: &SolveOptions) -> Vec<WordMatch> {
+        let search_options = SolveOptions {
+            all_paths: options.all_paths || board.has_multipliers(),
+            ..options.clone()
+        };
+
+        let (found, _) = self.search(board, &search_options);
+        let mut matches: Vec<WordMatch> = found.into_iter()
+            .filter_map(|found| found.traces.iter()
+                .map(|trace| self.to_match(board, options, &found.word, &trace.path))
+                .max_by_key(|found| found.score))
+            .collect();
+
+        match options.order {
+            SortOrder::Length => rank(&mut matches, options.max_results, |a, b| {
+                by_length(&a.word, &b.word)
+            }),
+            SortOrder::Score => rank(&mut matches, options.max_results, |a, b| {
+                b.score.cmp(&a.score).then(by_length(&a.word, &b.word))
+            }),
+            SortOrder::Rarity => rank(&mut matches, options.max_results, |a, b| {
+                self.frequency(&b.word).cmp(&self.frequency(&a.word)).then(by_length(&a.word, &b.word))
+            }),
+        }
+
+        matches
+    }
+
+    /// Finds all valid words on a board, reporting each word as soon as it is found.
+    /// Words are reported in the order they are found, along with their path.
+    /// Every distinct path is reported when the `all_paths` option is set.
+    /// board: The game board.
+    /// options: The options to solve with.
+    /// on_match: Called with each word.
+    pub fn find_all_words_streaming(
+        &self,
+        board: &Board,
+        options: &SolveOptions,
+        mut on_match: impl FnMut(&WordMatch)
+    ) {
+        let mut on_word = |word: &str, path: &[usize]| {
+            on_match(&self.to_match(board, options, word, path));
+        };
+
+        let mut search = Search::new(board, options);
+        search.on_word = Some(&mut on_word);
+
+        for index in 0..board.len() {
+            if search.should_stop() {
+                break;
+            }
+
+            self.walk(board, index, &mut search);
+        }
+    }
+
+    /// Checks if a word is in the dictionary and can be traced on a board.
+    /// Returns the tile path of the word, if it can be played.
+    /// board: The game board.
+    /// word: The word to look for.
+    pub fn find_word(&self, board: &Board, word: &str) -> Option<Vec<usize>> {
+        let word = word.to_lowercase();
+        if !self.word_trie.is_word(&word) {
+            return None;
+        }
+
+        let mut visited = vec![false; board.len()];
+        let mut path = Vec::new();
+        (0..board.len())
+            .any(|index| Self::trace(board, &word, index, &mut visited, &mut path))
+            .then_some(path)
+    }
+
+    /// Tries to trace the rest of a word starting from a tile.
+    /// Leaves the path of the word in `path` when successful.
+    /// board: The game board.
+    /// remaining: The letters of the word left to trace.
+    /// index: The index of the tile.
+    /// visited: The visited tiles.
+    /// path: The path traced so far.
+    fn trace(
+        board: &Board,
+        remaining: &str,
+        index: usize,
+        visited: &mut [bool],
+        path: &mut Vec<usize>
+    ) -> bool {
+        if visited[index] || board.is_blocked(index) {
+            return false;
+        }
+
+        // Find what's left of the word after this tile.
+        let rest = if board.is_wildcard(index) {
+            let mut chars = remaining.chars();
+            chars.next().map(|_| chars.as_str())
+        } else {
+            remaining.strip_prefix(board.cell(index))
+        };
+
+        let Some(rest) = rest else {
+            return false;
+        };
+
+        visited[index] = true;
+        path.push(index);
+
+        let traced = rest.is_empty() || board.neighbors(index)
+            .iter()
+            .any(|&neighbor| Self::trace(board, rest, neighbor, visited, path));
+
+        if !traced {
+            path.pop();
+            visited[index] = false;
+        }
+
+        traced
+    }
+
+    /// Finds the longest valid word on a board.
+    /// Ties are broken alphabetically, matching the result order.
+    /// Paths which can't beat the best word so far are never explored, and starting tiles,
+    /// neighbors and wildcard letters which lead to the longest words are explored first.
+    /// Respects every filter of the options, as a full search would.
+    /// Stops early, returning the longest word found so far, when cancelled or out of time.
+    /// board: The game board.
+    /// options: The options to solve with.
+    pub fn find_longest_word(&self, board: &Board, options: &SolveOptions) -> Option<(String, Vec<usize>)> {
+        let root = self.word_trie.as_ref();
+        let mut search = Search::new(board, options);
+        let mut best: Option<(String, Vec<usize>)> = None;
+        let mut best_length = 0;
+
+        // Steps are popped from the end, so the most promising are pushed last.
+        let mut stack: Vec<Step> = Self::by_reach(board, root, (0..board.len()).collect())
+            .into_iter()
+            .rev()
+            .map(|index| Step::Visit { node: root, index })
+            .collect();
+
+        while let Some(step) = stack.pop() {
+            if search.should_stop() {
+                break;
+            }
+
+            match step {
+                Step::Visit { node, index } => {
+                    if !search.can_visit(board, index) {
+                        continue;
+                    }
+
+                    let branches = stack.len();
+                    search.push_branches(board, node, index, &mut stack);
+                    stack[branches..].sort_by_key(|step| match step {
+                        Step::Enter { node, .. } => node.longest_suffix(),
+                        _ => 0,
+                    });
+                }
+                Step::Enter { node, index, wildcard } => {
+                    let leave = search.enter(board, index, wildcard);
+                    stack.push(leave);
+
+                    // Skip paths that can't reach the best word's length.
+                    let length = search.current_word.chars().count();
+                    if length + node.longest_suffix() < best_length {
+                        continue;
+                    }
+
+                    let is_better = length > best_length || best.as_ref()
+                        .is_some_and(|(word, _)| length == best_length && search.current_word < *word);
+                    if node.is_end_of_word() && length >= options.min_length && is_better && search.accepts_word() {
+                        best = Some((search.current_word.clone(), search.path.clone()));
+                        best_length = length;
+                    }
+
+                    let neighbors = Self::by_reach(board, node, board.neighbors(index).to_vec());
+                    for &neighbor in neighbors.iter().rev() {
+                        stack.push(Step::Visit { node, index: neighbor });
+                    }
+                }
+                Step::Leave { index, was_visited, word_length, wildcard } => {
+                    search.leave(index, was_visited, word_length, wildcard);
+                }
+            }
+        }
+
+        best
+    }
+
+    /// Orders tiles by the length of the longest word they could continue, longest first.
+    /// Tiles which can't continue any word are dropped.
+    /// board: The game board.
+    /// node: The trie node of the current word.
+    /// tiles: The indices of the tiles.
+    fn by_reach(board: &Board, node: &TrieNode, mut tiles: Vec<usize>) -> Vec<usize> {
+        let reach = |index: usize| if board.is_blocked(index) {
+            0
+        } else if board.is_wildcard(index) {
+            node.longest_suffix()
+        } else {
+            node.descend(board.cell(index)).map_or(0, |next| next.longest_suffix() + 1)
+        };
+
+        tiles.retain(|&index| reach(index) > 0);
+        tiles.sort_by_key(|&index| std::cmp::Reverse(reach(index)));
+        tiles
+    }
+
+    /// Counts the valid words on a board, without collecting them.
+    /// board: The game board.
+    /// options: The options to solve with.
+    pub fn count_words(&self, board: &Board, options: &SolveOptions) -> usize {
+        let mut search = Search::new(board, options);
+        search.counted = Some(HashSet::new());
+
+        for index in 0..board.len() {
+            if search.should_stop() {
+                break;
+            }
+
+            self.walk(board, index, &mut search);
+        }
+
+        search.counted.map_or(0, |counted| counted.len())
+    }
+
+    /// Searches a board for every valid word, in no particular order.
+    /// board: The game board.
+    /// options: The options to solve with.
+    /// Also returns whether the search stopped before covering the whole board.
+    fn search(&self, board: &Board, options: &SolveOptions) -> (Vec<Found>, bool) {
+        #[cfg(feature = "parallel")]
+        let (words, stopped) = self.search_parallel(board, options);
+        #[cfg(not(feature = "parallel"))]
+        let (words, stopped) = self.search_from(board, options, 0..board.len());
+
+        Self::finish_search(board, options, words, stopped)
+    }
+
+    /// Turns the words a search reached into the words it found.
+    /// Also returns whether the search stopped before covering the whole board.
+    /// board: The game board.
+    /// options: The options the search ran with.
+    /// words: The words the search reached, with their traces.
+    /// stopped: Whether the search stopped early.
+    fn finish_search(
+        board: &Board,
+        options: &SolveOptions,
+        words: HashMap<String, Vec<Trace>>,
+        stopped: bool
+    ) -> (Vec<Found>, bool) {
+        let found = words.into_iter()
+            .filter(|(word, _)| word.chars().count() >= options.min_length)
+            .map(|(word, traces)| Found { word, traces })
+            .collect::<Vec<_>>();
+
+        log::debug!(
+            "Found {} words on a board of {} tiles{}.",
+            found.len(),
+            board.len(),
+            if stopped { ", stopping early" } else { "" }
+        );
+        (found, stopped)
+    }
+
+    /// Searches a board for every valid word, starting from the given tiles.
+    /// Also returns whether the search stopped early.
+    /// board: The game board.
+    /// options: The options to solve with.
+    /// starts: The indices of the tiles to start from.
+    fn search_from(
+        &self,
+        board: &Board,
+        options: &SolveOptions,
+        starts: impl Iterator<Item = usize>
+    ) -> (HashMap<String, Vec<Trace>>, bool) {
+        let mut search = Search::new(board, options);
+        for index in starts {
+            if search.should_stop() {
+                break;
+            }
+
+            self.walk(board, index, &mut search);
+        }
+
+        (search.words, search.stopped)
+    }
+
+    /// Searches a board for every valid word, spreading the starting tiles across threads.
+    /// Also returns whether any thread stopped early.
     /// board: The game board.
-    /// row: The row index.
-    /// col: The column index.
-    /// visited: The visited positions.
-    /// current_word: The current word.
-    /// words: The set of valid words.
-    fn visit(
+    /// options: The options to solve with.
+    #[cfg(feature = "parallel")]
+    fn search_parallel(
         &self,
-        board: &[Vec<String>],
-        row: usize,
-        col: usize,
-        visited: &mut Vec<Vec<bool>>,
-        current_word: &mut String,
-        words: &mut HashSet<String>
+        board: &Board,
+        options: &SolveOptions
+    ) -> (HashMap<String, Vec<Trace>>, bool) {
+        let threads = std::thread::available_parallelism()
+            .map_or(1, std::num::NonZeroUsize::get)
+            .min(board.len())
+            .max(1);
+
+        std::thread::scope(|scope| {
+            // Interleave the starting tiles so every thread gets a similar share of the board.
+            let handles: Vec<_> = (0..threads)
+                .map(|offset| scope.spawn(move || {
+                    self.search_from(board, options, (offset..board.len()).step_by(threads))
+                }))
+                .collect();
+
+            let mut words: HashMap<String, Vec<Trace>> = HashMap::new();
+            let mut stopped = false;
+            for handle in handles {
+                let (found, thread_stopped) = handle.join().expect("A search thread panicked.");
+                stopped |= thread_stopped;
+                for (word, traces) in found {
+                    let existing = words.entry(word).or_default();
+                    if existing.is_empty() || options.all_paths {
+                        existing.extend(traces);
+                    }
+                }
+            }
+
+            (words, stopped)
+        })
+    }
+
+    /// Walks every path starting from a tile.
+    /// Uses an explicit stack instead of recursion, so deep searches can't overflow
+    /// small native thread stacks.
+    /// Tiles may hold several letters (such as "qu"), which are all consumed at once.
+    /// Wildcard tiles are tried as every letter that continues the current word.
+    /// board: The game board.
+    /// start: The index of the first tile.
+    /// search: The state of the search.
+    fn walk(
+        &self,
+        board: &Board,
+        start: usize,
+        search: &mut Search
     ) {
-        if !self.in_bounds(board, row, col) || visited[row][col] {
-            return;
+        let mut stack = vec![Step::Visit { node: self.word_trie.as_ref(), index: start }];
+        while let Some(step) = stack.pop() {
+            if search.should_stop() {
+                return;
+            }
+
+            search.step(board, step, &mut stack);
         }
+    }
 
-        visited[row][col] = true;
-        current_word.push_str(&board[row][col]);
+    /// Lazily finds all valid words on a board.
+    /// Words are produced in the order they are found.
+    /// Only as much of the board is searched as is needed for the words taken.
+    /// board: The game board.
+    /// options: The options to solve with.
+    pub fn iter_words<'a>(&'a self, board: &'a Board, options: &'a SolveOptions) -> WordIter<'a> {
+        WordIter {
+            solver: self,
+            board,
+            search: Search::new(board, options),
+            stack: Vec::new(),
+            next_start: 0,
+        }
+    }
+}
 
-        if self.word_trie.has_prefix(current_word) {
-            if self.word_trie.is_word(current_word) {
-                words.insert(current_word.clone());
+/// A lazy search over a board.
+/// Created by [`Solver::iter_words`].
+pub struct WordIter<'a> {
+    solver: &'a Solver,
+    board: &'a Board,
+    search: Search<'a>,
+    stack: Vec<Step<'a>>,
+    next_start: usize,
+}
+
+impl Iterator for WordIter<'_> {
+    type Item = WordMatch;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.search.should_stop() {
+                return None;
             }
 
-            for &(dx, dy) in &DIRECTIONS {
-                let new_row = row as i32 + dx;
-                let new_col = col as i32 + dy;
+            match self.stack.pop() {
+                Some(step) => {
+                    if self.search.step(self.board, step, &mut self.stack) {
+                        return Some(self.solver.to_match(
+                            self.board,
+                            self.search.options,
+                            &self.search.current_word,
+                            &self.search.path
+                        ));
+                    }
+                }
+                None => {
+                    // Move on to the next starting tile.
+                    if self.next_start >= self.board.len() {
+                        return None;
+                    }
 
-                if new_row >= 0 && new_col >= 0 {
-                    self.visit(
-                        board,
-                        new_row as usize,
-                        new_col as usize,
-                        visited,
-                        current_word,
-                        words
-                    );
+                    let root = self.solver.word_trie.as_ref();
+                    self.stack.push(Step::Visit { node: root, index: self.next_start });
+                    self.next_start += 1;
                 }
             }
         }
+    }
+}
 
-        visited[row][col] = false;
-        current_word.truncate(current_word.len() - board[row][col].len());
+impl<'a> Search<'a> {
+    /// Creates the state for a new search.
+    /// board: The game board.
+    /// options: The options to solve with.
+    fn new(board: &Board, options: &'a SolveOptions) -> Self {
+        Self {
+            options,
+            visited: vec![false; board.len()],
+            current_word: String::new(),
+            path: Vec::new(),
+            assumed: Vec::new(),
+            words: HashMap::new(),
+            on_word: None,
+            counted: None,
+            deadline: options.time_limit.map(|limit| Instant::now() + limit),
+            steps: 0,
+            stopped: false,
+        }
     }
 
-    /// Checks if a position is within the boundaries of a game board.
+    /// Checks if the search has been cancelled or has run out of time.
+    /// Once stopped, a search stays stopped.
+    fn should_stop(&mut self) -> bool {
+        if self.stopped {
+            return true;
+        }
+
+        // Reading the clock is slow, so the deadline is only checked every so often.
+        self.steps += 1;
+        let timed_out = self.steps.is_multiple_of(256)
+            && self.deadline.is_some_and(|deadline| Instant::now() >= deadline);
+
+        self.stopped = timed_out
+            || self.options.cancel.as_ref().is_some_and(CancelToken::is_cancelled);
+        self.stopped
+    }
+
+    /// Checks if the current word would still agree with the required prefix.
+    /// letters: The letters about to be added to the current word.
+    fn fits_prefix(&self, letters: &str) -> bool {
+        let Some(prefix) = self.options.prefix.as_deref() else {
+            return true;
+        };
+
+        // The current word already matches the prefix as far as it goes.
+        let matched = self.current_word.len();
+        if matched >= prefix.len() {
+            return true;
+        }
+
+        let wanted = &prefix[matched..];
+        if letters.len() >= wanted.len() {
+            letters.starts_with(wanted)
+        } else {
+            wanted.starts_with(letters)
+        }
+    }
+
+    /// Checks if the letters the current word is missing could still appear in it.
+    /// letters: The letters about to be added to the current word.
+    /// next: The trie node the word reaches after adding the letters.
+    fn fits_letters(&self, letters: &str, next: &TrieNode) -> bool {
+        if self.options.required_letters.is_empty() {
+            return true;
+        }
+
+        let mut missing: Vec<char> = self.options.required_letters.iter()
+            .copied()
+            .filter(|&letter| !self.current_word.contains(letter) && !letters.contains(letter))
+            .collect();
+        missing.sort_unstable();
+        missing.dedup();
+
+        missing.len() <= next.longest_suffix()
+    }
+
+    /// Checks if the current path would still fit in the maximum span after adding a tile.
     /// board: The game board.
-    /// row: The row index.
-    /// col: The column index.
-    fn in_bounds(&self, board: &[Vec<String>], row: usize, col: usize) -> bool {
-        row < board.len() && col < board[0].len()
+    /// index: The index of the tile about to be added.
+    fn fits_span(&self, board: &Board, index: usize) -> bool {
+        let Some(max_span) = self.options.max_span else {
+            return true;
+        };
+
+        let (layer, row, col) = board.position(index);
+        let (mut min, mut max) = ([layer, row, col], [layer, row, col]);
+        for &tile in &self.path {
+            let (layer, row, col) = board.position(tile);
+            for (axis, value) in [layer, row, col].into_iter().enumerate() {
+                min[axis] = min[axis].min(value);
+                max[axis] = max[axis].max(value);
+            }
+        }
+
+        (0..3).all(|axis| max[axis] - min[axis] < max_span)
     }
-}
\ No newline at end of file
+
+    /// Checks if a tile may be added to the current path.
+    /// board: The game board.
+    /// index: The index of the tile.
+    fn can_visit(&self, board: &Board, index: usize) -> bool {
+        if board.is_blocked(index) {
+            return false;
+        }
+
+        if self.visited[index] && !self.options.allow_reuse {
+            return false;
+        }
+
+        if self.options.region.as_ref().is_some_and(|region| region.get(index) != Some(&true)) {
+            return false;
+        }
+
+        self.fits_span(board, index)
+    }
+
+    /// Pushes a step onto the stack for every way a tile continues the current word.
+    /// Wildcard tiles continue it with every letter which fits.
+    /// board: The game board.
+    /// node: The trie node of the current word.
+    /// index: The index of the tile.
+    /// stack: The pending steps.
+    fn push_branches<'t>(&self, board: &Board, node: &'t TrieNode, index: usize, stack: &mut Vec<Step<'t>>) {
+        if board.is_wildcard(index) {
+            for (letter, next) in node.children() {
+                let mut buffer = [0; 4];
+                let letters = letter.encode_utf8(&mut buffer);
+                if self.fits_prefix(letters) && self.fits_letters(letters, next) {
+                    stack.push(Step::Enter { node: next, index, wildcard: Some(letter) });
+                }
+            }
+        } else if self.fits_prefix(board.cell(index)) {
+            if let Some(next) = node.descend(board.cell(index)) {
+                if self.fits_letters(board.cell(index), next) {
+                    stack.push(Step::Enter { node: next, index, wildcard: None });
+                }
+            }
+        }
+    }
+
+    /// Moves onto a tile, adding its letters to the current word.
+    /// Returns the step which moves back off of it.
+    /// board: The game board.
+    /// index: The index of the tile.
+    /// wildcard: The letter assumed for a wildcard tile.
+    fn enter<'t>(&mut self, board: &Board, index: usize, wildcard: Option<char>) -> Step<'t> {
+        let leave = Step::Leave {
+            index,
+            was_visited: self.visited[index],
+            word_length: self.current_word.len(),
+            wildcard: wildcard.is_some(),
+        };
+
+        self.visited[index] = true;
+        self.path.push(index);
+        match wildcard {
+            Some(letter) => {
+                self.current_word.push(letter);
+                self.assumed.push((index, letter));
+            }
+            None => self.current_word.push_str(board.cell(index)),
+        }
+
+        leave
+    }
+
+    /// Moves back off of a tile, restoring the search to how it was before entering it.
+    /// index: The index of the tile.
+    /// was_visited: Whether the tile was already visited before entering it.
+    /// word_length: The length of the current word before entering it, in bytes.
+    /// wildcard: Whether a letter was assumed for the tile.
+    fn leave(&mut self, index: usize, was_visited: bool, word_length: usize, wildcard: bool) {
+        if wildcard {
+            self.assumed.pop();
+        }
+        self.current_word.truncate(word_length);
+        self.path.pop();
+        self.visited[index] = was_visited;
+    }
+
+    /// Performs a single step of a search.
+    /// Returns whether a new word (or path, with `all_paths`) was found.
+    /// board: The game board.
+    /// step: The step to perform.
+    /// stack: The pending steps, which may be added to.
+    fn step<'t>(&mut self, board: &Board, step: Step<'t>, stack: &mut Vec<Step<'t>>) -> bool {
+        match step {
+            Step::Visit { node, index } => {
+                if self.can_visit(board, index) {
+                    self.push_branches(board, node, index, stack);
+                }
+
+                false
+            }
+            Step::Enter { node, index, wildcard } => {
+                let leave = self.enter(board, index, wildcard);
+                stack.push(leave);
+
+                for &neighbor in board.neighbors(index).iter().rev() {
+                    stack.push(Step::Visit { node, index: neighbor });
+                }
+
+                node.is_end_of_word() && self.record(node)
+            }
+            Step::Leave { index, was_visited, word_length, wildcard } => {
+                self.leave(index, was_visited, word_length, wildcard);
+                false
+            }
+        }
+    }
+
+    /// Checks if the current word passes the filters of the options, other than its length.
+    fn accepts_word(&self) -> bool {
+        // Paths missing a required tile don't count.
+        if !self.options.must_include.iter().all(|index| self.path.contains(index)) {
+            return false;
+        }
+
+        if !self.options.required_letters.iter().all(|&letter| self.current_word.contains(letter)) {
+            return false;
+        }
+
+        if self.options.pattern.as_ref().is_some_and(|pattern| !pattern.matches(&self.current_word)) {
+            return false;
+        }
+
+        !self.options.exclude.contains(&self.current_word)
+    }
+
+    /// Records the current word and how it was traced.
+    /// Returns whether the word was reported.
+    /// node: The trie node of the current word.
+    fn record(&mut self, node: &TrieNode) -> bool {
+        if !self.accepts_word() {
+            return false;
+        }
+
+        // Every word ends on its own trie node, so counting doesn't need the word itself.
+        if let Some(counted) = self.counted.as_mut() {
+            return self.current_word.chars().count() >= self.options.min_length
+                && counted.insert(node as *const TrieNode);
+        }
+
+        let traces = self.words.entry(self.current_word.clone()).or_default();
+        if !traces.is_empty() && !self.options.all_paths {
+            return false;
+        }
+
+        traces.push(Trace {
+            path: self.path.clone(),
+            assumed: self.assumed.clone(),
+        });
+
+        if self.current_word.chars().count() < self.options.min_length {
+            return false;
+        }
+
+        // Report the word right away when streaming.
+        if let Some(on_word) = self.on_word.as_mut() {
+            on_word(&self.current_word, &self.path);
+        }
+
+        true
+    }
+}
+
+/// Orders words from longest to shortest, then alphabetically.
+/// a: The first word.
+/// b: The second word.
+pub(crate) fn by_length(a: &str, b: &str) -> Ordering {
+    b.len().cmp(&a.len()).then(a.cmp(b))
+}
+
+/// Sorts results, keeping only the best of them.
+/// results: The results to sort.
+/// max_results: The maximum number of results to keep.
+/// order: The order of the results.
+pub(crate) fn rank<T>(
+    results: &mut Vec<T>,
+    max_results: Option<usize>,
+    order: impl Fn(&T, &T) -> Ordering
+) {
+    // Only the best results need to be sorted when the results are limited.
+    if let Some(max_results) = max_results {
+        if max_results < results.len() {
+            if max_results > 0 {
+                results.select_nth_unstable_by(max_results - 1, &order);
+            }
+            results.truncate(max_results);
+        }
+    }
+
+    results.sort_by(order);
+}
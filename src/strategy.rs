@@ -0,0 +1,130 @@
+use std::time::Duration;
+use crate::board::Board;
+use crate::options::SolveOptions;
+use crate::solver::Solver;
+
+/// The granularity used when budgeting time, in milliseconds.
+const TIME_STEP_MS: u128 = 10;
+
+/// The most steps a round is split into when budgeting time.
+/// Longer rounds are budgeted in coarser steps, so planning never needs more than this
+/// many steps for every word.
+const MAX_TIME_STEPS: u128 = 10_000;
+
+/// How long it takes a player to enter a word.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SwipeCost {
+    /// The time spent on every word, such as finding its start.
+    pub base: Duration,
+
+    /// The time spent swiping across each tile.
+    pub per_tile: Duration,
+}
+
+impl SwipeCost {
+    /// Returns the time it takes to enter a word.
+    /// tiles: The number of tiles in the word's path.
+    pub fn time(&self, tiles: usize) -> Duration {
+        self.base + self.per_tile * tiles as u32
+    }
+}
+
+/// A word chosen for a round, along with how to play it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlannedWord {
+    pub word: String,
+    pub path: Vec<usize>,
+    pub score: u32,
+    pub time: Duration,
+}
+
+/// The words to play in a round, in the order to play them.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PlayPlan {
+    pub words: Vec<PlannedWord>,
+    pub total_score: u32,
+    pub total_time: Duration,
+}
+
+impl Solver {
+    /// Plans the words to play in a timed round to earn the most points.
+    /// The words are ordered by points per second, so the most efficient words
+    /// are played first.
+    /// Long rounds are budgeted in coarser steps, so any duration can be planned for.
+    /// board: The game board.
+    /// options: The options to solve with.
+    /// cost: How long it takes to enter a word.
+    /// duration: The length of the round.
+    pub fn plan_play(
+        &self,
+        board: &Board,
+        options: &SolveOptions,
+        cost: SwipeCost,
+        duration: Duration
+    ) -> PlayPlan {
+        let search_options = SolveOptions {
+            all_paths: options.all_paths || board.has_multipliers(),
+            max_results: None,
+            ..options.clone()
+        };
+
+        // Play every word by its most valuable path, preferring quicker paths.
+        let candidates: Vec<PlannedWord> = self.find_word_paths(board, &search_options)
+            .into_iter()
+            .filter_map(|found| {
+                let word = found.word;
+                found.paths.into_iter()
+                    .map(|path| PlannedWord {
+                        score: options.scoring.score_path(board, &word, &path),
+                        time: cost.time(path.len()),
+                        word: word.clone(),
+                        path,
+                    })
+                    .max_by_key(|planned| (planned.score, std::cmp::Reverse(planned.time)))
+            })
+            .collect();
+
+        let step_ms = TIME_STEP_MS.max(duration.as_millis().div_ceil(MAX_TIME_STEPS));
+        let steps = |time: Duration| usize::try_from(time.as_millis().div_ceil(step_ms)).unwrap_or(usize::MAX);
+
+        // No more time is budgeted than it takes to play every word.
+        let needed = candidates.iter()
+            .map(|candidate| steps(candidate.time))
+            .fold(0, usize::saturating_add);
+        let budget = usize::try_from(duration.as_millis() / step_ms).unwrap_or(usize::MAX).min(needed);
+
+        // Solve the knapsack of words, remembering which words were taken.
+        let mut best = vec![0u32; budget + 1];
+        let mut taken = vec![vec![false; budget + 1]; candidates.len()];
+        for (i, candidate) in candidates.iter().enumerate() {
+            let weight = steps(candidate.time);
+            for capacity in (weight..=budget).rev() {
+                let score = best[capacity - weight].saturating_add(candidate.score);
+                if score > best[capacity] {
+                    best[capacity] = score;
+                    taken[i][capacity] = true;
+                }
+            }
+        }
+
+        let mut words = Vec::new();
+        let mut capacity = budget;
+        for (i, candidate) in candidates.iter().enumerate().rev() {
+            if taken[i][capacity] {
+                capacity -= steps(candidate.time);
+                words.push(candidate.clone());
+            }
+        }
+
+        let rate = |planned: &PlannedWord| {
+            planned.score as f64 / planned.time.as_secs_f64().max(f64::EPSILON)
+        };
+        words.sort_by(|a, b| rate(b).total_cmp(&rate(a)).then(a.word.cmp(&b.word)));
+
+        PlayPlan {
+            total_score: words.iter().map(|planned| planned.score).fold(0, u32::saturating_add),
+            total_time: words.iter().map(|planned| planned.time).sum(),
+            words,
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use lazy_static::lazy_static;
+use crate::board::Board;
+use crate::cancel::CancelToken;
+use crate::options::SolveOptions;
+use crate::pool::ThreadPool;
+use crate::solver::Solver;
+
+lazy_static! {
+    /// The threads searches run on, one per core.
+    /// With the parallel feature every search already spreads across the cores, so
+    /// searches run one at a time instead. Tasks queue for a thread, so the executor
+    /// never waits.
+    static ref POOL: ThreadPool = ThreadPool::new(
+        "word-hunt-async",
+        if cfg!(feature = "parallel") { 1 } else { ThreadPool::default_threads() },
+        None
+    ).expect("Couldn't start the async solve threads.");
+}
+
+/// The state shared between a solve task and the thread running its search.
+#[derive(Default)]
+struct Shared {
+    words: Option<Vec<String>>,
+    waker: Option<Waker>,
+}
+
+/// A solve running on a shared pool of threads, which can be awaited from any executor.
+/// Dropping the task before it finishes cancels the search, so it can be raced
+/// against a timeout or abandoned along with the request it was serving.
+pub struct SolveTask {
+    shared: Arc<Mutex<Shared>>,
+    cancel: CancelToken,
+    finished: bool,
+}
+
+impl SolveTask {
+    /// Starts finding all words on a board, on the shared pool of solve threads.
+    /// The search waits for a free thread if every thread is busy, and uses the dictionary
+    /// loaded when the task is started.
+    /// board: The game board.
+    /// options: The options to solve with. Their cancel token, if any, is cancelled if the task is dropped early.
+    pub fn spawn(board: Board, mut options: SolveOptions) -> Self {
+        let cancel = options.cancel.get_or_insert_with(CancelToken::new).clone();
+        let shared = Arc::new(Mutex::new(Shared::default()));
+        let solver = Solver::new();
+
+        let thread_shared = Arc::clone(&shared);
+        POOL.execute(move || {
+            let words = solver.find_all_words(&board, &options);
+
+            let mut shared = thread_shared.lock().unwrap();
+            shared.words = Some(words);
+            if let Some(waker) = shared.waker.take() {
+                waker.wake();
+            }
+        });
+
+        Self { shared, cancel, finished: false }
+    }
+}
+
+impl Future for SolveTask {
+    type Output = Vec<String>;
+
+    fn poll(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut shared = self.shared.lock().unwrap();
+        match shared.words.take() {
+            Some(words) => {
+                drop(shared);
+                self.finished = true;
+                Poll::Ready(words)
+            }
+            None => {
+                shared.waker = Some(context.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl Drop for SolveTask {
+    fn drop(&mut self) {
+        if !self.finished {
+            self.cancel.cancel();
+        }
+    }
+}
+
+/// Finds all words on a board without blocking the executor.
+/// The search runs on a shared pool of threads, bounded by the number of cores; dropping the future, such as
+/// when a timeout elapses, cancels it, even if it is still waiting for a thread.
+/// board: The game board.
+/// options: The options to solve with.
+pub async fn solve_words_async(board: Board, options: SolveOptions) -> Vec<String> {
+    SolveTask::spawn(board, options).await
+}
@@ -1,9 +1,21 @@
 use std::collections::HashMap;
 
+// Compiled tries start with this, followed by the root node. Each node is a byte which
+// is 1 if it ends a word, the word's frequency as a little-endian u64 if it does, the
+// number of children as a little-endian u32, then each child's character as a
+// little-endian u32 followed by the child node.
+const COMPILED_HEADER: &[u8; 8] = b"WHTRIE\0\x01";
+
+// Deeper tries are rejected, so a corrupt file can't overflow the stack.
+const MAX_COMPILED_DEPTH: usize = 1024;
+
 #[derive(Default, Clone)]
 pub struct TrieNode {
     children: HashMap<char, TrieNode>,
     is_end_of_word: bool,
+    longest_suffix: usize,
+    frequency: u64,
+    total_frequency: u64,
 }
 
 impl TrieNode {
@@ -12,32 +24,157 @@ impl TrieNode {
     }
 
     pub fn insert<S: AsRef<str>>(&mut self, word: S) {
+        self.insert_with_frequency(word, 0);
+    }
+
+    pub fn insert_with_frequency<S: AsRef<str>>(&mut self, word: S, frequency: u64) {
+        self.total_frequency += frequency;
+        let length = word.as_ref().chars().count();
         let mut current = self;
-        for ch in word.as_ref().chars() {
+        for (depth, ch) in word.as_ref().chars().enumerate() {
+            current.longest_suffix = current.longest_suffix.max(length - depth);
             current = current.children.entry(ch).or_default();
         }
         current.is_end_of_word = true;
+        current.frequency += frequency;
     }
 
-    pub fn has_prefix(&self, prefix: &str) -> bool {
+    pub fn descend(&self, path: &str) -> Option<&TrieNode> {
         let mut node = self;
-        for ch in prefix.chars() {
-            match node.children.get(&ch) {
-                Some(next) => node = next,
-                None => return false,
-            }
+        for ch in path.chars() {
+            node = node.children.get(&ch)?;
         }
-        true
+        Some(node)
+    }
+
+    pub fn has_prefix(&self, prefix: &str) -> bool {
+        self.descend(prefix).is_some()
     }
 
     pub fn is_word(&self, word: &str) -> bool {
-        let mut node = self;
-        for ch in word.chars() {
-            match node.children.get(&ch) {
-                Some(next) => node = next,
-                None => return false,
-            }
+        self.descend(word).is_some_and(TrieNode::is_end_of_word)
+    }
+
+    pub fn children(&self) -> impl Iterator<Item = (char, &TrieNode)> {
+        self.children.iter().map(|(&ch, node)| (ch, node))
+    }
+
+    pub fn is_end_of_word(&self) -> bool {
+        self.is_end_of_word
+    }
+
+    pub fn longest_suffix(&self) -> usize {
+        self.longest_suffix
+    }
+
+    pub fn frequency(&self) -> u64 {
+        self.frequency
+    }
+
+    pub fn total_frequency(&self) -> u64 {
+        self.total_frequency
+    }
+
+    pub fn word_count(&self) -> usize {
+        self.is_end_of_word as usize + self.children.values().map(TrieNode::word_count).sum::<usize>()
+    }
+
+    pub fn words(&self) -> Vec<String> {
+        let mut words = Vec::new();
+        self.collect_words(&mut String::new(), &mut words);
+        words.sort_unstable();
+        words
+    }
+
+    fn collect_words(&self, prefix: &mut String, words: &mut Vec<String>) {
+        if self.is_end_of_word {
+            words.push(prefix.clone());
+        }
+
+        for (&ch, child) in &self.children {
+            prefix.push(ch);
+            child.collect_words(prefix, words);
+            prefix.pop();
         }
-        node.is_end_of_word
     }
-}
\ No newline at end of file
+
+    pub fn merge(&mut self, other: TrieNode) {
+        self.is_end_of_word |= other.is_end_of_word;
+        self.longest_suffix = self.longest_suffix.max(other.longest_suffix);
+        self.frequency += other.frequency;
+        self.total_frequency += other.total_frequency;
+        for (ch, child) in other.children {
+            self.children.entry(ch).or_default().merge(child);
+        }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = COMPILED_HEADER.to_vec();
+        self.write(&mut bytes);
+        bytes
+    }
+
+    fn write(&self, bytes: &mut Vec<u8>) {
+        bytes.push(self.is_end_of_word as u8);
+        if self.is_end_of_word {
+            bytes.extend_from_slice(&self.frequency.to_le_bytes());
+        }
+
+        // Children are sorted, so compiling the same words always gives the same bytes.
+        let mut children: Vec<_> = self.children.iter().collect();
+        children.sort_unstable_by_key(|(&ch, _)| ch);
+
+        bytes.extend_from_slice(&(children.len() as u32).to_le_bytes());
+        for (&ch, child) in children {
+            bytes.extend_from_slice(&(ch as u32).to_le_bytes());
+            child.write(bytes);
+        }
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut rest = bytes.strip_prefix(COMPILED_HEADER)?;
+        let mut total_frequency = 0;
+        let mut root = Self::read(&mut rest, 0, &mut total_frequency)?;
+        if !rest.is_empty() {
+            return None;
+        }
+
+        root.total_frequency = total_frequency;
+        Some(root)
+    }
+
+    fn read(bytes: &mut &[u8], depth: usize, total_frequency: &mut u64) -> Option<Self> {
+        fn take<const N: usize>(bytes: &mut &[u8]) -> Option<[u8; N]> {
+            let (taken, rest) = bytes.split_first_chunk::<N>()?;
+            *bytes = rest;
+            Some(*taken)
+        }
+
+        if depth > MAX_COMPILED_DEPTH {
+            return None;
+        }
+
+        let mut node = Self::new();
+        node.is_end_of_word = match take::<1>(bytes)? {
+            [0] => false,
+            [1] => true,
+            _ => return None,
+        };
+        if node.is_end_of_word {
+            node.frequency = u64::from_le_bytes(take(bytes)?);
+            *total_frequency += node.frequency;
+        }
+
+        let count = u32::from_le_bytes(take(bytes)?) as usize;
+        // Each child takes at least 9 bytes, which bounds the allocation for corrupt counts.
+        node.children.reserve(count.min(bytes.len() / 9));
+        for _ in 0..count {
+            let ch = char::from_u32(u32::from_le_bytes(take(bytes)?))?;
+            let child = Self::read(bytes, depth + 1, total_frequency)?;
+            node.longest_suffix = node.longest_suffix.max(child.longest_suffix + 1);
+            node.children.insert(ch, child);
+        }
+
+        Some(node)
+    }
+}
@@ -0,0 +1,138 @@
+use std::{ptr, slice};
+use crate::board::Board;
+use crate::json;
+use crate::load_dictionary_contents;
+use crate::solver::Solver;
+
+// These exports only pass numbers, so they work in a browser without any bindings
+// generator. Strings are passed as UTF-8 in memory allocated with `word_hunt_alloc`.
+// `wasm/word_hunt.js` wraps them as `loadDictionary(text)` and `solve(board, options)`.
+
+/// Allocates memory for JavaScript to write a string into.
+/// Must be freed with `word_hunt_free`.
+/// length: The number of bytes to allocate.
+#[no_mangle]
+pub extern "C" fn word_hunt_alloc(length: usize) -> *mut u8 {
+    Box::into_raw(vec![0u8; length].into_boxed_slice()).cast()
+}
+
+/// Frees memory allocated with `word_hunt_alloc`.
+/// pointer: The memory.
+/// length: The number of bytes which were allocated.
+///
+/// # Safety
+/// The memory must come from `word_hunt_alloc` with the same length, and mustn't be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn word_hunt_free(pointer: *mut u8, length: usize) {
+    if !pointer.is_null() {
+        drop(Box::from_raw(ptr::slice_from_raw_parts_mut(pointer, length)));
+    }
+}
+
+/// Adds the words of a dictionary file to the dictionary.
+/// Each line holds a word, optionally followed by how often it is used.
+/// Returns whether the contents were valid UTF-8.
+/// pointer: The contents of the dictionary file.
+/// length: The length of the contents, in bytes.
+///
+/// # Safety
+/// The pointer must point to `length` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn word_hunt_load_dictionary(pointer: *const u8, length: usize) -> bool {
+    match read_str(pointer, length) {
+        Some(contents) => {
+            load_dictionary_contents(contents, "wasm".to_string());
+            true
+        }
+        None => false,
+    }
+}
+
+/// Finds every word on a board.
+/// The board is a JSON array of rows, each either a string of single-letter tiles or an
+/// array of tiles. The options are a JSON object, as accepted by `findWordsJson`, or empty.
+/// Returns a result to read with `word_hunt_result_length` and free with
+/// `word_hunt_free_result`, holding `{"words":[...],"total_score":...}` or `{"error":...}`.
+/// board: The board, as JSON.
+/// board_length: The length of the board, in bytes.
+/// options: The options, as JSON.
+/// options_length: The length of the options, in bytes.
+///
+/// # Safety
+/// Each pointer must point to as many readable bytes as its length.
+#[no_mangle]
+pub unsafe extern "C" fn word_hunt_solve(
+    board: *const u8,
+    board_length: usize,
+    options: *const u8,
+    options_length: usize
+) -> *mut u8 {
+    let board = read_str(board, board_length);
+    let options = read_str(options, options_length);
+    let result = match (board, options) {
+        (Some(board), Some(options)) => solve(board, options),
+        _ => Err("The board and options must be UTF-8.".to_string()),
+    };
+
+    let result = result.unwrap_or_else(|error| format!("{{\"error\":{}}}", json::quote(&error)));
+
+    // The result is prefixed with its length, so it can be freed without being told it.
+    let mut bytes = (result.len() as u32).to_le_bytes().to_vec();
+    bytes.extend_from_slice(result.as_bytes());
+    Box::into_raw(bytes.into_boxed_slice()).cast()
+}
+
+/// Returns the length of a result, in bytes.
+/// Its UTF-8 bytes start 4 bytes after the pointer.
+/// result: The result, from `word_hunt_solve`.
+///
+/// # Safety
+/// The result must come from `word_hunt_solve`, and not have been freed.
+#[no_mangle]
+pub unsafe extern "C" fn word_hunt_result_length(result: *const u8) -> usize {
+    u32::from_le_bytes(*result.cast::<[u8; 4]>()) as usize
+}
+
+/// Frees a result.
+/// result: The result, from `word_hunt_solve`.
+///
+/// # Safety
+/// The result must come from `word_hunt_solve`, and mustn't be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn word_hunt_free_result(result: *mut u8) {
+    if !result.is_null() {
+        let length = word_hunt_result_length(result) + 4;
+        drop(Box::from_raw(ptr::slice_from_raw_parts_mut(result, length)));
+    }
+}
+
+/// Reads a UTF-8 string from memory.
+/// Returns `None` if it isn't UTF-8. A null pointer is read as an empty string.
+/// pointer: The string.
+/// length: The length of the string, in bytes.
+///
+/// # Safety
+/// The pointer must be null or point to `length` readable bytes.
+unsafe fn read_str<'a>(pointer: *const u8, length: usize) -> Option<&'a str> {
+    if pointer.is_null() {
+        return Some("");
+    }
+
+    std::str::from_utf8(slice::from_raw_parts(pointer, length)).ok()
+}
+
+/// Solves a board described by JSON.
+/// board: The board, as JSON.
+/// options: The options, as JSON, or empty for the defaults.
+fn solve(board: &str, options: &str) -> Result<String, String> {
+    let options = match options.trim() {
+        "" => json::Value::Null,
+        options => json::parse(options)?,
+    };
+
+    let (options, topology) = json::read_options(&options)?;
+    let board = Board::new(json::read_board(&json::parse(board)?)?, topology).map_err(|error| error.to_string())?;
+
+    let found = Solver::new().find_matches(&board, &options);
+    Ok(json::word_matches(&found))
+}
@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+use crate::options::SolveOptions;
+use crate::solver::{by_length, rank, Solver};
+use crate::trie_node::TrieNode;
+
+/// A piece of a Word Bites board.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Bite {
+    /// A piece with a single letter.
+    Single(char),
+
+    /// A piece with two letters side by side, read left to right.
+    Horizontal(char, char),
+
+    /// A piece with two letters stacked on top of each other, read top to bottom.
+    Vertical(char, char),
+}
+
+/// The way pieces are lined up to spell a word.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// Pieces side by side, read left to right.
+    Horizontal,
+
+    /// Pieces stacked on top of each other, read top to bottom.
+    Vertical,
+}
+
+/// A word spelled by lining up Word Bites pieces.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BiteWord {
+    pub word: String,
+
+    /// The way the pieces are lined up.
+    pub direction: Direction,
+
+    /// The indices of the pieces spelling the word, in order.
+    pub pieces: Vec<usize>,
+
+    /// The points the word is worth, under the configured scoring mode.
+    pub score: u32,
+}
+
+impl Bite {
+    /// Returns the ways a piece can contribute to a word.
+    /// Two-letter pieces lined up across the word only contribute one of their letters.
+    /// direction: The way the word is lined up.
+    fn spellings(&self, direction: Direction) -> Vec<String> {
+        match (*self, direction) {
+            (Bite::Single(letter), _) => vec![letter.to_string()],
+            (Bite::Horizontal(first, second), Direction::Horizontal)
+            | (Bite::Vertical(first, second), Direction::Vertical) => vec![format!("{first}{second}")],
+            (Bite::Horizontal(first, second), Direction::Vertical)
+            | (Bite::Vertical(first, second), Direction::Horizontal) => if first == second {
+                vec![first.to_string()]
+            } else {
+                vec![first.to_string(), second.to_string()]
+            },
+        }
+    }
+}
+
+/// The state of a search over Word Bites pieces.
+struct BiteSearch<'a> {
+    options: &'a SolveOptions,
+    direction: Direction,
+    max_length: usize,
+    spellings: Vec<Vec<String>>,
+    used: Vec<bool>,
+    current_word: String,
+    pieces: Vec<usize>,
+    words: HashMap<String, BiteWord>,
+}
+
+impl Solver {
+    /// Finds all words which can be spelled by lining up Word Bites pieces.
+    /// Words are found in both directions, but each word is only reported once.
+    /// Tiles and paths don't apply to Word Bites, so the tile-based options are ignored.
+    /// pieces: The pieces on the board.
+    /// max_length: The longest a word may be, in letters.
+    /// options: The options to solve with.
+    pub fn solve_word_bites(&self, pieces: &[Bite], max_length: usize, options: &SolveOptions) -> Vec<BiteWord> {
+        let pieces: Vec<Bite> = pieces.iter()
+            .map(|piece| match *piece {
+                Bite::Single(letter) => Bite::Single(lowercase(letter)),
+                Bite::Horizontal(first, second) => Bite::Horizontal(lowercase(first), lowercase(second)),
+                Bite::Vertical(first, second) => Bite::Vertical(lowercase(first), lowercase(second)),
+            })
+            .collect();
+
+        let mut words = HashMap::new();
+        for direction in [Direction::Horizontal, Direction::Vertical] {
+            let mut search = BiteSearch {
+                options,
+                direction,
+                max_length,
+                spellings: pieces.iter().map(|piece| piece.spellings(direction)).collect(),
+                used: vec![false; pieces.len()],
+                current_word: String::new(),
+                pieces: Vec::new(),
+                words,
+            };
+
+            search.visit(self.dictionary());
+            words = search.words;
+        }
+
+        let mut words: Vec<BiteWord> = words.into_values().collect();
+        rank(&mut words, options.max_results, |a, b| by_length(&a.word, &b.word));
+
+        words
+    }
+}
+
+impl BiteSearch<'_> {
+    /// Tries every unused piece after the current word.
+    /// node: The trie node of the current word.
+    fn visit(&mut self, node: &TrieNode) {
+        if node.is_end_of_word() {
+            self.record();
+        }
+
+        for index in 0..self.spellings.len() {
+            if self.used[index] {
+                continue;
+            }
+
+            for spelling in self.spellings[index].clone() {
+                if self.current_word.chars().count() + spelling.chars().count() > self.max_length {
+                    continue;
+                }
+
+                let Some(next) = node.descend(&spelling) else {
+                    continue;
+                };
+
+                self.used[index] = true;
+                self.pieces.push(index);
+                self.current_word.push_str(&spelling);
+
+                self.visit(next);
+
+                self.current_word.truncate(self.current_word.len() - spelling.len());
+                self.pieces.pop();
+                self.used[index] = false;
+            }
+        }
+    }
+
+    /// Records the current word if it passes the options and hasn't been found yet.
+    fn record(&mut self) {
+        if self.words.contains_key(&self.current_word) || !self.options.accepts_word(&self.current_word) {
+            return;
+        }
+
+        self.words.insert(self.current_word.clone(), BiteWord {
+            word: self.current_word.clone(),
+            direction: self.direction,
+            pieces: self.pieces.clone(),
+            score: self.options.scoring.score(&self.current_word),
+        });
+    }
+}
+
+/// Lowercases a letter to match the dictionary.
+/// letter: The letter.
+fn lowercase(letter: char) -> char {
+    letter.to_lowercase().next().unwrap_or(letter)
+}
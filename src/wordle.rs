@@ -0,0 +1,214 @@
+use std::collections::HashMap;
+use crate::scoring::ScoredWord;
+use crate::solver::Solver;
+use crate::trie_node::TrieNode;
+
+/// The color a letter of a Wordle guess is marked with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Feedback {
+    /// The letter is in the answer, in this spot.
+    Green,
+
+    /// The letter is in the answer, but not in this spot.
+    Yellow,
+
+    /// The letter isn't in the answer, or not as many times as it was guessed.
+    Gray,
+}
+
+/// A Wordle guess, along with the colors its letters were marked with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WordleGuess {
+    pub word: String,
+
+    /// The color of each letter of the guess.
+    pub feedback: Vec<Feedback>,
+}
+
+impl WordleGuess {
+    /// Creates a guess from its colors written out as letters.
+    /// 'g' is green, 'y' is yellow, and anything else is gray.
+    /// Returns nothing if the colors don't line up with the word.
+    /// word: The guessed word.
+    /// colors: The color of each letter, such as "gyxxg".
+    pub fn from_colors(word: &str, colors: &str) -> Option<Self> {
+        let feedback: Vec<Feedback> = colors.chars()
+            .map(|color| match color.to_ascii_lowercase() {
+                'g' => Feedback::Green,
+                'y' => Feedback::Yellow,
+                _ => Feedback::Gray,
+            })
+            .collect();
+
+        let word = word.to_lowercase();
+        (word.chars().count() == feedback.len()).then_some(Self { word, feedback })
+    }
+}
+
+/// Everything the guesses so far reveal about the answer.
+struct Constraints {
+    /// The letter known to be in each spot.
+    fixed: Vec<Option<char>>,
+
+    /// The letters known not to be in each spot.
+    banned: Vec<Vec<char>>,
+
+    /// The fewest times each letter appears.
+    at_least: HashMap<char, usize>,
+
+    /// The most times each letter appears, if known.
+    at_most: HashMap<char, usize>,
+}
+
+impl Constraints {
+    /// Works out the constraints revealed by a set of guesses.
+    /// length: The length of the answer, in letters.
+    /// guesses: The guesses so far.
+    fn new(length: usize, guesses: &[WordleGuess]) -> Self {
+        let mut constraints = Self {
+            fixed: vec![None; length],
+            banned: vec![Vec::new(); length],
+            at_least: HashMap::new(),
+            at_most: HashMap::new(),
+        };
+
+        for guess in guesses {
+            let mut found: HashMap<char, usize> = HashMap::new();
+            let mut grayed = Vec::new();
+
+            for (spot, (letter, &feedback)) in guess.word.chars().zip(&guess.feedback).enumerate() {
+                if spot >= length {
+                    break;
+                }
+
+                match feedback {
+                    Feedback::Green => constraints.fixed[spot] = Some(letter),
+                    Feedback::Yellow | Feedback::Gray => constraints.banned[spot].push(letter),
+                }
+
+                match feedback {
+                    Feedback::Green | Feedback::Yellow => *found.entry(letter).or_default() += 1,
+                    Feedback::Gray => grayed.push(letter),
+                }
+            }
+
+            for (&letter, &count) in &found {
+                let at_least = constraints.at_least.entry(letter).or_default();
+                *at_least = (*at_least).max(count);
+            }
+
+            // A gray letter appears exactly as many times as it was marked in color.
+            for letter in grayed {
+                let count = found.get(&letter).copied().unwrap_or(0);
+                let at_most = constraints.at_most.entry(letter).or_insert(count);
+                *at_most = (*at_most).min(count);
+            }
+        }
+
+        constraints
+    }
+
+    /// Checks if a letter can go in a spot.
+    /// spot: The spot, from 0.
+    /// letter: The letter.
+    fn allows(&self, spot: usize, letter: char) -> bool {
+        match self.fixed[spot] {
+            Some(fixed) => fixed == letter,
+            None => !self.banned[spot].contains(&letter)
+                && self.at_most.get(&letter).is_none_or(|&count| count > 0),
+        }
+    }
+
+    /// Checks if a complete word has the right number of each letter.
+    /// word: The word.
+    fn counts_match(&self, word: &str) -> bool {
+        let mut counts: HashMap<char, usize> = HashMap::new();
+        for letter in word.chars() {
+            *counts.entry(letter).or_default() += 1;
+        }
+
+        self.at_least.iter().all(|(letter, &count)| counts.get(letter).copied().unwrap_or(0) >= count)
+            && self.at_most.iter().all(|(letter, &count)| counts.get(letter).copied().unwrap_or(0) <= count)
+    }
+}
+
+impl Solver {
+    /// Finds every dictionary word which could still be the answer to a Wordle puzzle.
+    /// Words are returned alphabetically.
+    /// length: The length of the answer, in letters.
+    /// guesses: The guesses so far.
+    pub fn wordle_candidates(&self, length: usize, guesses: &[WordleGuess]) -> Vec<String> {
+        let constraints = Constraints::new(length, guesses);
+        let mut words = Vec::new();
+        let mut current_word = String::new();
+        visit(self.dictionary(), &constraints, length, &mut current_word, &mut words);
+
+        words.sort_unstable();
+        words
+    }
+}
+
+/// Tries every letter allowed in the next spot of the current word.
+/// node: The trie node of the current word.
+/// constraints: What is known about the answer.
+/// length: The length of the answer, in letters.
+/// current_word: The current word.
+/// words: The words found so far.
+fn visit(
+    node: &TrieNode,
+    constraints: &Constraints,
+    length: usize,
+    current_word: &mut String,
+    words: &mut Vec<String>
+) {
+    let spot = current_word.chars().count();
+    if spot == length {
+        if node.is_end_of_word() && constraints.counts_match(current_word) {
+            words.push(current_word.clone());
+        }
+
+        return;
+    }
+
+    for (letter, next) in node.children() {
+        if next.longest_suffix() + spot + 1 < length || !constraints.allows(spot, letter) {
+            continue;
+        }
+
+        current_word.push(letter);
+        visit(next, constraints, length, current_word, words);
+        current_word.pop();
+    }
+}
+
+/// Ranks possible answers as guesses, by how common their letters are among the answers.
+/// Each distinct letter of a word scores one point for every answer containing it.
+/// Guesses are returned from best to worst, then alphabetically.
+/// candidates: The possible answers.
+pub fn rank_guesses(candidates: &[String]) -> Vec<ScoredWord> {
+    let mut letter_counts: HashMap<char, u32> = HashMap::new();
+    for word in candidates {
+        let mut letters: Vec<char> = word.chars().collect();
+        letters.sort_unstable();
+        letters.dedup();
+        for letter in letters {
+            *letter_counts.entry(letter).or_default() += 1;
+        }
+    }
+
+    let mut ranked: Vec<ScoredWord> = candidates.iter()
+        .map(|word| {
+            let mut letters: Vec<char> = word.chars().collect();
+            letters.sort_unstable();
+            letters.dedup();
+
+            ScoredWord {
+                word: word.clone(),
+                score: letters.iter().map(|letter| letter_counts[letter]).sum(),
+            }
+        })
+        .collect();
+
+    ranked.sort_by(|a, b| b.score.cmp(&a.score).then(a.word.cmp(&b.word)));
+    ranked
+}
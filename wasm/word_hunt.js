@@ -0,0 +1,50 @@
+// Loads the solver, built with
+// `cargo rustc --release --target wasm32-unknown-unknown --features wasm --crate-type cdylib`.
+// Strings are copied into the module's memory, since its exports only pass numbers.
+
+const encoder = new TextEncoder();
+const decoder = new TextDecoder();
+
+export async function init(source) {
+  const { instance } = await WebAssembly.instantiateStreaming(fetch(source), {});
+  const exports = instance.exports;
+
+  const withString = (text, body) => {
+    const bytes = encoder.encode(text);
+    const pointer = exports.word_hunt_alloc(bytes.length);
+    new Uint8Array(exports.memory.buffer, pointer, bytes.length).set(bytes);
+    try {
+      return body(pointer, bytes.length);
+    } finally {
+      exports.word_hunt_free(pointer, bytes.length);
+    }
+  };
+
+  return {
+    // Adds the words of a dictionary file, one per line.
+    loadDictionary(text) {
+      if (!withString(text, exports.word_hunt_load_dictionary)) {
+        throw new Error("The dictionary isn't valid UTF-8.");
+      }
+    },
+
+    // Finds every word on a board, given as an array of rows.
+    // Resolves to { words: [{ word, score, path }], total_score }.
+    solve(board, options = {}) {
+      const result = withString(JSON.stringify(board), (board, boardLength) =>
+        withString(JSON.stringify(options), (options, optionsLength) =>
+          exports.word_hunt_solve(board, boardLength, options, optionsLength)));
+
+      try {
+        const length = exports.word_hunt_result_length(result);
+        const json = JSON.parse(decoder.decode(new Uint8Array(exports.memory.buffer, result + 4, length)));
+        if (json.error) {
+          throw new Error(json.error);
+        }
+        return json;
+      } finally {
+        exports.word_hunt_free_result(result);
+      }
+    },
+  };
+}